@@ -0,0 +1,284 @@
+mod make;
+mod validate;
+
+use ndarray::{Array, ArrayView, ArrayView1, AsArray, Axis, Dimension};
+
+use crate::{ndarrayext::reshape_evaluated, NdSpline, Real, RealRef, Result};
+
+/// N-dimensional (univariate/multivariate) errors-in-variables smoothing spline calculator/evaluator
+///
+/// The struct computes the smoothing spline for the data with noise in both `x` and `y`.
+/// The spline `f` and the estimated true data sites `u` minimize the orthogonal-distance
+/// (total least squares) criterion
+///
+/// ```text
+/// p * sum(wy * |y - f(u)|^2 + wx * (x - u)^2) + (1 - p) * integral(|f''(t)|^2 dt)
+/// ```
+///
+/// The minimization alternates two steps: the cubic smoothing spline is computed for the
+/// current sites by `CubicSmoothingSpline` and every site is moved to the closest point of
+/// the spline curve in the weighted metric (Gauss-Newton step). The order of the sites is
+/// kept during the iterations. The convergence of the alternating iterations is linear and
+/// it can be slow if `x` noise is large relative to `y` noise.
+///
+/// The weights can be set directly or as standard deviations of the noise
+/// (`w = 1 / std^2`). Unit weights are used by default for both `x` and `y`.
+///
+/// If the smoothing parameter is not set, it is computed automatically for the original
+/// data sites and is fixed during the iterations.
+///
+/// The computed spline is represented as `NdSpline` with the estimated data sites as breaks.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use csaps::ErrorsInVariablesSmoothingSpline;
+///
+/// let x = array![1.02, 1.97, 3.05, 3.96, 5.01, 6.03];
+/// let y = array![1.1, 3.9, 9.2, 15.8, 25.3, 35.9];
+/// let x_std = array![0.05, 0.05, 0.05, 0.05, 0.05, 0.05];
+/// let y_std = array![0.2, 0.2, 0.2, 0.2, 0.2, 0.2];
+///
+/// let s = ErrorsInVariablesSmoothingSpline::new(&x, &y)
+///     .with_x_std(&x_std)
+///     .with_std(&y_std)
+///     .with_smooth(0.9)
+///     .make().unwrap();
+///
+/// let sites = s.estimated_sites().unwrap();
+/// let yi = s.evaluate(&x).unwrap();
+/// ```
+///
+pub struct ErrorsInVariablesSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// X data sites (noisy)
+    x: ArrayView1<'a, T>,
+
+    /// Y data values
+    y: ArrayView<'a, T, D>,
+
+    /// The axis parameter defines axis of Y data for spline computing
+    axis: Option<Axis>,
+
+    /// The optional Y data weights
+    weights: Option<ArrayView1<'a, T>>,
+
+    /// The optional standard deviations of Y data noise
+    std: Option<ArrayView1<'a, T>>,
+
+    /// The optional X data weights
+    x_weights: Option<ArrayView1<'a, T>>,
+
+    /// The optional standard deviations of X data noise
+    x_std: Option<ArrayView1<'a, T>>,
+
+    /// The optional smoothing parameter
+    smooth: Option<T>,
+
+    /// The maximum number of alternating iterations
+    max_iter: usize,
+
+    /// The tolerance of data sites change (relative to `x` range) for convergence
+    tolerance: T,
+
+    /// The number of iterations which have been made
+    iterations: usize,
+
+    /// The flag which is true if the iterations have converged
+    converged: bool,
+
+    /// `NdSpline` struct with computed spline, the breaks are the estimated data sites
+    spline: Option<NdSpline<'a, T>>,
+}
+
+impl<'a, T, D> ErrorsInVariablesSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// Creates `ErrorsInVariablesSmoothingSpline` struct from the given `X` data sites and `Y` data values
+    ///
+    /// # Arguments
+    ///
+    /// - `x` -- the X-data sites 1-d array-like. Must strictly increasing: `x1 < x2 < x3 < ... < xN`
+    /// - `y` -- The Y-data values n-d array-like. By default the axis parameter is
+    ///   equal to the last axis of Y data.
+    ///
+    pub fn new<X, Y>(x: X, y: Y) -> Self
+    where
+        X: AsArray<'a, T>,
+        Y: AsArray<'a, T, D>,
+    {
+        ErrorsInVariablesSmoothingSpline {
+            x: x.into(),
+            y: y.into(),
+            axis: None,
+            weights: None,
+            std: None,
+            x_weights: None,
+            x_std: None,
+            smooth: None,
+            max_iter: 500,
+            tolerance: T::from(1e-8).unwrap(),
+            iterations: 0,
+            converged: false,
+            spline: None,
+        }
+    }
+
+    /// Sets the axis parameter
+    ///
+    /// `y.shape()[axis]` must be equal to `x.len()`
+    ///
+    pub fn with_axis(mut self, axis: Axis) -> Self {
+        self.invalidate();
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets the Y data weights vector
+    ///
+    /// `weights.len()` must be equal to `x.len()`. Cannot be used together with `with_std`.
+    ///
+    pub fn with_weights<W>(mut self, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.weights = Some(weights.into());
+        self
+    }
+
+    /// Sets the standard deviations of Y data noise, the weights are `1 / std^2`
+    ///
+    /// `std.len()` must be equal to `x.len()`. Cannot be used together with `with_weights`.
+    ///
+    pub fn with_std<W>(mut self, std: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.std = Some(std.into());
+        self
+    }
+
+    /// Sets the X data weights vector
+    ///
+    /// `x_weights.len()` must be equal to `x.len()`. Cannot be used together with `with_x_std`.
+    ///
+    pub fn with_x_weights<W>(mut self, x_weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.x_weights = Some(x_weights.into());
+        self
+    }
+
+    /// Sets the standard deviations of X data noise, the weights are `1 / x_std^2`
+    ///
+    /// `x_std.len()` must be equal to `x.len()`. Cannot be used together with `with_x_weights`.
+    ///
+    pub fn with_x_std<W>(mut self, x_std: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.x_std = Some(x_std.into());
+        self
+    }
+
+    /// Sets the smoothing parameter
+    ///
+    /// The smoothing parameter should be in range `[0, 1]`. It has the same meaning as for
+    /// `CubicSmoothingSpline`.
+    ///
+    pub fn with_smooth(mut self, smooth: T) -> Self {
+        self.invalidate();
+        self.smooth = Some(smooth);
+        self
+    }
+
+    /// Sets the maximum number of alternating iterations, 500 by default
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        self.invalidate();
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Sets the tolerance of data sites change relative to `x` range, `1e-8` by default
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        self.invalidate();
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Makes (computes) the spline and the estimated data sites for given data and parameters
+    ///
+    /// # Errors
+    ///
+    /// - If the data or parameters are invalid
+    /// - If reshaping Y data to 2-d view has failed
+    ///
+    pub fn make(mut self) -> Result<Self> {
+        self.make_validate()?;
+        self.make_spline()?;
+        Ok(self)
+    }
+
+    /// Evaluates the computed spline on the given data sites
+    ///
+    /// # Errors
+    ///
+    /// - If the `xi` data is invalid
+    /// - If the spline yet has not been computed
+    ///
+    pub fn evaluate<X>(&self, xi: X) -> Result<Array<T, D>>
+    where
+        X: AsArray<'a, T>,
+    {
+        let xi = xi.into();
+        self.evaluate_validate(xi)?;
+
+        let yi_2d = self.spline.as_ref().unwrap().evaluate(xi);
+        reshape_evaluated(yi_2d, self.y.view(), self.axis.unwrap())
+    }
+
+    /// Returns the view to the estimated true data sites or None
+    pub fn estimated_sites(&self) -> Option<ArrayView1<'_, T>> {
+        self.spline.as_ref().map(|s| s.breaks())
+    }
+
+    /// Returns the smoothing parameter or None
+    pub fn smooth(&self) -> Option<T> {
+        self.smooth
+    }
+
+    /// Returns the number of alternating iterations which have been made
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns true if the iterations have converged
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+
+    /// Returns the ref to `NdSpline` struct with data of computed spline or None
+    pub fn spline(&self) -> Option<&NdSpline<'a, T>> {
+        self.spline.as_ref()
+    }
+
+    /// Invalidate computed spline and estimated data sites
+    fn invalidate(&mut self) {
+        self.iterations = 0;
+        self.converged = false;
+        self.spline = None;
+    }
+}
@@ -0,0 +1,114 @@
+use ndarray::{prelude::*, Zip};
+
+use crate::{
+    ndarrayext::to_2d, validate::validate_data_sites, CubicSmoothingSpline, NdSpline, Real,
+    RealRef, Result,
+};
+
+use super::ErrorsInVariablesSmoothingSpline;
+
+impl<'a, T, D> ErrorsInVariablesSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn make_spline(&mut self) -> Result<()> {
+        let axis = self.axis.unwrap_or_else(|| Axis(self.y.ndim() - 1));
+        self.axis = Some(axis);
+
+        let x = self.x;
+        let y = to_2d(self.y.view(), axis)?.to_owned();
+
+        let weights = resolve_weights(self.weights, self.std, x.len());
+        let x_weights = resolve_weights(self.x_weights, self.x_std, x.len());
+
+        let x_range = x[x.len() - 1] - x[0];
+
+        let mut sites = x.to_owned();
+        let mut smooth = self.smooth;
+        let mut iterations = 0;
+        let mut converged = false;
+
+        for iteration in 1..=self.max_iter {
+            iterations = iteration;
+
+            let sp = CubicSmoothingSpline::new(&sites, &y)
+                .with_weights(&weights)
+                .with_optional_smooth(smooth)
+                .make()?;
+
+            // The smoothing parameter is computed once for the original data sites
+            smooth = sp.smooth();
+
+            let spline = sp.spline().unwrap();
+            let residuals = &y - &spline.evaluate(sites.view());
+            let slopes = spline.evaluate_derivative(sites.view(), 1);
+
+            // Gauss-Newton step to the closest point of the spline curve for every site
+            let step = Array1::from_shape_fn(sites.raw_dim(), |i| {
+                let (wx, wy) = (x_weights[i], weights[i]);
+                let (r, d) = (residuals.column(i), slopes.column(i));
+
+                let grad = wx * (x[i] - sites[i]) + wy * r.dot(&d);
+                let hess = wx + wy * d.dot(&d);
+
+                grad / hess
+            });
+
+            // The sites must remain strictly increasing, the step is shortened if needed
+            let mut alpha = T::one();
+            let half = T::from(0.5).unwrap();
+            let mut new_sites = &sites + &step;
+
+            while validate_data_sites(new_sites.view()).is_err() {
+                alpha *= half;
+
+                if alpha < T::epsilon() {
+                    new_sites = sites.clone();
+                    break;
+                }
+
+                new_sites = &sites + &(&step * alpha);
+            }
+
+            let change = Zip::from(&new_sites)
+                .and(&sites)
+                .fold(T::zero(), |acc, &a, &b| acc.max((a - b).abs()));
+
+            sites = new_sites;
+
+            if change <= self.tolerance * x_range {
+                converged = true;
+                break;
+            }
+        }
+
+        let sp = CubicSmoothingSpline::new(&sites, &y)
+            .with_weights(&weights)
+            .with_optional_smooth(smooth)
+            .make()?;
+
+        let coeffs = sp.spline().unwrap().coeffs().to_owned();
+
+        self.smooth = sp.smooth();
+        self.iterations = iterations;
+        self.converged = converged;
+        self.spline = Some(NdSpline::new(sites, coeffs));
+
+        Ok(())
+    }
+}
+
+/// Returns the weights given directly or computed from the standard deviations, or unit weights
+fn resolve_weights<T: Real<T>>(
+    weights: Option<ArrayView1<'_, T>>,
+    std: Option<ArrayView1<'_, T>>,
+    size: usize,
+) -> Array1<T> {
+    match (weights, std) {
+        (Some(weights), _) => weights.to_owned(),
+        (None, Some(std)) => std.mapv(|s| T::one() / (s * s)),
+        (None, None) => Array1::ones((size,)),
+    }
+}
@@ -0,0 +1,101 @@
+use ndarray::{ArrayView1, Dimension};
+
+use crate::{
+    validate::{validate_smooth_value, validate_xy_data},
+    CsapsError::InvalidInputData,
+    Real, RealRef, Result,
+};
+
+use super::ErrorsInVariablesSmoothingSpline;
+
+impl<'a, T, D> ErrorsInVariablesSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn make_validate(&self) -> Result<()> {
+        validate_xy_data(self.x, self.y.view(), self.axis)?;
+
+        let x_size = self.x.len();
+
+        validate_positive_vector("weights", self.weights, x_size)?;
+        validate_positive_vector("std", self.std, x_size)?;
+        validate_positive_vector("x_weights", self.x_weights, x_size)?;
+        validate_positive_vector("x_std", self.x_std, x_size)?;
+
+        if self.weights.is_some() && self.std.is_some() {
+            return Err(InvalidInputData(
+                "`weights` and `std` cannot be set together".to_string(),
+            ));
+        }
+
+        if self.x_weights.is_some() && self.x_std.is_some() {
+            return Err(InvalidInputData(
+                "`x_weights` and `x_std` cannot be set together".to_string(),
+            ));
+        }
+
+        if let Some(smooth) = self.smooth {
+            validate_smooth_value(smooth)?;
+        }
+
+        if self.max_iter == 0 {
+            return Err(InvalidInputData(
+                "`max_iter` must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self.tolerance <= T::zero() {
+            return Err(InvalidInputData(format!(
+                "`tolerance` must be positive, given {:?}",
+                self.tolerance
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn evaluate_validate(&self, xi: ArrayView1<'a, T>) -> Result<()> {
+        if xi.is_empty() {
+            return Err(InvalidInputData(
+                "The size of `xi` vector must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self.spline.is_none() {
+            return Err(InvalidInputData(
+                "The spline has not been computed, use `make` method before".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Validates the size and the values of the optional weights or standard deviations vector
+fn validate_positive_vector<T: Real<T>>(
+    name: &str,
+    values: Option<ArrayView1<'_, T>>,
+    x_size: usize,
+) -> Result<()> {
+    if let Some(values) = values {
+        if values.len() != x_size {
+            return Err(InvalidInputData(format!(
+                "`{}` size ({}) is not equal to `x` size ({})",
+                name,
+                values.len(),
+                x_size
+            )));
+        }
+
+        if values.iter().any(|&v| v <= T::zero() || !v.is_finite()) {
+            return Err(InvalidInputData(format!(
+                "`{}` values must be positive and finite",
+                name
+            )));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,169 @@
+mod make;
+
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2};
+
+use crate::{NdSpline, Real, RealRef, Result};
+
+/// Functional data analysis of the set of curves represented by the multivariate spline
+///
+/// Every dimension of the given `NdSpline` is considered as the curve (for example the smoothed
+/// repeated test run), all curves share the same breaks. The struct computes:
+///
+/// - the pointwise mean function (the spline with the same breaks and order)
+/// - the pointwise (sample) variance function (the piecewise polynomial of order `2 * order - 1`)
+/// - functional principal component analysis (FPCA): the eigenvalues of the covariance operator,
+///   the orthonormal eigenfunctions and the scores of every curve
+///
+/// The inner products of the curves are computed exactly as the integrals of the products of
+/// the spline pieces polynomials over the breaks range, so the eigenfunctions are orthonormal
+/// in `L2` sense and they are represented as `NdSpline` structs with the same breaks.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{array, Array1};
+/// use csaps::{CubicSmoothingSpline, FunctionalData};
+///
+/// let x = array![0., 1., 2., 3., 4., 5.];
+/// let y = array![[0.5, 1.2, 3.4, 2.5, 1.5, 0.7],
+///                [0.7, 1.5, 3.7, 2.9, 1.6, 0.6],
+///                [0.2, 0.9, 3.0, 2.4, 1.2, 0.5],
+///                [0.6, 1.1, 3.6, 2.6, 1.7, 0.9]];
+///
+/// let spline = CubicSmoothingSpline::new(&x, &y)
+///     .with_smooth(0.8)
+///     .make().unwrap();
+///
+/// let fda = FunctionalData::new(spline.spline().unwrap())
+///     .with_components(2)
+///     .make().unwrap();
+///
+/// let xi = Array1::linspace(0., 5., 20);
+/// let mean = fda.mean().unwrap().evaluate(xi.view());
+/// let scores = fda.scores().unwrap();
+///
+/// assert_eq!(scores.shape(), &[4, 2]);
+/// ```
+///
+pub struct FunctionalData<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// The multivariate spline, every dimension is the curve
+    spline: &'a NdSpline<'a, T>,
+
+    /// The optional number of the principal components
+    components: Option<usize>,
+
+    /// The mean function
+    mean: Option<NdSpline<'a, T>>,
+
+    /// The variance function
+    variance: Option<NdSpline<'a, T>>,
+
+    /// The eigenvalues of the covariance operator for the principal components
+    eigenvalues: Option<Array1<T>>,
+
+    /// The total variance (the sum of all eigenvalues)
+    total_variance: Option<T>,
+
+    /// The eigenfunctions of the principal components
+    eigenfunctions: Option<Vec<NdSpline<'a, T>>>,
+
+    /// `N x K` array of the scores of the curves
+    scores: Option<Array2<T>>,
+}
+
+impl<'a, T> FunctionalData<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// Creates `FunctionalData` struct for the given multivariate spline
+    ///
+    /// # Arguments
+    ///
+    /// - `spline` -- the multivariate spline, every dimension is the curve.
+    ///   The spline must have at least 2 dimensions.
+    ///
+    pub fn new(spline: &'a NdSpline<'a, T>) -> Self {
+        FunctionalData {
+            spline,
+            components: None,
+            mean: None,
+            variance: None,
+            eigenvalues: None,
+            total_variance: None,
+            eigenfunctions: None,
+            scores: None,
+        }
+    }
+
+    /// Sets the number of the principal components
+    ///
+    /// By default all principal components with non-zero eigenvalues are computed.
+    /// The number must not be greater than the rank of the centered curves
+    /// (at most the number of curves minus one).
+    ///
+    pub fn with_components(mut self, components: usize) -> Self {
+        self.invalidate();
+        self.components = Some(components);
+        self
+    }
+
+    /// Makes (computes) the mean and variance functions and the principal components
+    ///
+    /// # Errors
+    ///
+    /// - If the number of curves is less than 2
+    /// - If the number of components is invalid or greater than the rank of the curves
+    ///
+    pub fn make(mut self) -> Result<Self> {
+        self.make_fda()?;
+        Ok(self)
+    }
+
+    /// Returns the mean function or None
+    pub fn mean(&self) -> Option<&NdSpline<'a, T>> {
+        self.mean.as_ref()
+    }
+
+    /// Returns the variance function or None
+    pub fn variance(&self) -> Option<&NdSpline<'a, T>> {
+        self.variance.as_ref()
+    }
+
+    /// Returns the eigenvalues of the principal components in descending order or None
+    pub fn eigenvalues(&self) -> Option<ArrayView1<'_, T>> {
+        self.eigenvalues.as_ref().map(|v| v.view())
+    }
+
+    /// Returns the fractions of the total variance explained by the principal components or None
+    pub fn explained_variance_ratio(&self) -> Option<Array1<T>> {
+        let total = self.total_variance?;
+        self.eigenvalues.as_ref().map(|v| v / total)
+    }
+
+    /// Returns the eigenfunctions of the principal components or None
+    pub fn eigenfunctions(&self) -> Option<&[NdSpline<'a, T>]> {
+        self.eigenfunctions.as_deref()
+    }
+
+    /// Returns `N x K` array of the scores of the curves or None
+    ///
+    /// The score is the inner product of the centered curve and the eigenfunction.
+    pub fn scores(&self) -> Option<ArrayView2<'_, T>> {
+        self.scores.as_ref().map(|v| v.view())
+    }
+
+    /// Invalidate computed results
+    fn invalidate(&mut self) {
+        self.mean = None;
+        self.variance = None;
+        self.eigenvalues = None;
+        self.total_variance = None;
+        self.eigenfunctions = None;
+        self.scores = None;
+    }
+}
@@ -0,0 +1,130 @@
+use ndarray::prelude::*;
+
+use crate::{linalg, CsapsError::InvalidInputData, NdSpline, Real, RealRef, Result};
+
+use super::FunctionalData;
+
+impl<'a, T> FunctionalData<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    pub(super) fn make_fda(&mut self) -> Result<()> {
+        let spline = self.spline;
+        let n = spline.ndim();
+
+        if n < 2 {
+            return Err(InvalidInputData(format!(
+                "The number of curves (spline dimensions) must be greater or equal to 2, got {}",
+                n
+            )));
+        }
+
+        if self.components == Some(0) || self.components.is_some_and(|k| k >= n) {
+            return Err(InvalidInputData(format!(
+                "The number of components must be in range [1, {}]",
+                n - 1
+            )));
+        }
+
+        let order = spline.order();
+        let pieces = spline.pieces();
+        let breaks = spline.breaks();
+        let dof = T::from(n - 1).unwrap();
+
+        let mean = spline.coeffs().sum_axis(Axis(0)) / T::from(n).unwrap();
+        let centered = &spline.coeffs() - &mean;
+
+        // The coefficients of the piece `j` of the centered curves, the column `p` is the power `p`
+        let piece_coeffs = |j: usize| {
+            Array2::from_shape_fn((n, order), |(i, p)| {
+                centered[[i, (order - 1 - p) * pieces + j]]
+            })
+        };
+
+        // The variance function is the sum of the squared centered curves
+        let variance_order = 2 * order - 1;
+        let mut variance = Array2::<T>::zeros((1, variance_order * pieces));
+
+        // The matrix of the inner products of the centered curves
+        let mut products = Array2::<T>::zeros((n, n));
+
+        for j in 0..pieces {
+            let coeffs = piece_coeffs(j);
+
+            for curve in coeffs.rows() {
+                for p in 0..order {
+                    for q in 0..order {
+                        variance[[0, (variance_order - 1 - p - q) * pieces + j]] +=
+                            curve[p] * curve[q] / dof;
+                    }
+                }
+            }
+
+            let h = breaks[j + 1] - breaks[j];
+            let gram = Array2::from_shape_fn((order, order), |(p, q)| {
+                let power = T::from(p + q + 1).unwrap();
+                h.powf(power) / power
+            });
+
+            products += &coeffs.dot(&gram).dot(&coeffs.t());
+        }
+
+        let covariance = products / dof;
+        let (eigenvalues, eigenvectors) = linalg::symmetric_eigen(covariance.view());
+
+        let total_variance = eigenvalues
+            .iter()
+            .fold(T::zero(), |acc, &v| acc + v.max(T::zero()));
+        let tolerance = eigenvalues[0] * T::epsilon() * T::from(n).unwrap();
+        let rank = eigenvalues
+            .iter()
+            .take(n - 1)
+            .filter(|&&v| v > tolerance && v > T::zero())
+            .count();
+
+        let components = self.components.unwrap_or(rank);
+
+        if components == 0 || components > rank {
+            return Err(InvalidInputData(format!(
+                "The number of components ({}) is greater than the rank of the centered curves ({})",
+                components, rank
+            )));
+        }
+
+        let mut eigenfunctions = Vec::with_capacity(components);
+        let mut scores = Array2::<T>::zeros((n, components));
+
+        for m in 0..components {
+            let mut vector = eigenvectors.column(m).to_owned();
+
+            // The sign convention: the largest by absolute value component of the vector is positive
+            let largest = vector.iter().fold(
+                T::zero(),
+                |acc, &v| if v.abs() > acc.abs() { v } else { acc },
+            );
+
+            if largest < T::zero() {
+                vector.mapv_inplace(|v| -v);
+            }
+
+            let scale = (dof * eigenvalues[m]).sqrt();
+            let coeffs = vector.dot(&centered) / scale;
+
+            eigenfunctions.push(NdSpline::new(
+                breaks.to_owned(),
+                coeffs.insert_axis(Axis(0)),
+            ));
+            scores.column_mut(m).assign(&(vector * scale));
+        }
+
+        self.mean = Some(NdSpline::new(breaks.to_owned(), mean.insert_axis(Axis(0))));
+        self.variance = Some(NdSpline::new(breaks.to_owned(), variance));
+        self.eigenvalues = Some(eigenvalues.slice(s![..components]).to_owned());
+        self.total_variance = Some(total_variance);
+        self.eigenfunctions = Some(eigenfunctions);
+        self.scores = Some(scores);
+
+        Ok(())
+    }
+}
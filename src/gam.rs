@@ -0,0 +1,282 @@
+mod make;
+mod validate;
+
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2, AsArray, Axis, Ix2};
+
+use crate::{NdSpline, Real, RealRef, Result};
+
+/// Additive model with the cubic smoothing spline terms
+///
+/// The struct fits the generalized additive model
+///
+/// ```text
+/// y = alpha + f1(x1) + f2(x2) + ... + fK(xK) + noise
+/// ```
+///
+/// where `x1, ..., xK` are the columns of `N x K` covariates matrix, `alpha` is the intercept
+/// and every term `fj` is the cubic smoothing spline with its own smoothing parameter and
+/// optionally its own weights (see `with_term_weights` method).
+///
+/// The model is computed by backfitting: every term is computed by `CubicSmoothingSpline`
+/// for the partial residuals of the other terms in turn until the terms change is less than
+/// the tolerance. The terms are centered (the weighted mean of every term over the data is zero).
+///
+/// The covariates do not have to be sorted and can have the repeated values: the partial
+/// residuals of the equal covariate values are averaged with the summed weights before smoothing.
+///
+/// The computed terms are represented as `NdSpline` with the unique sorted covariate values as breaks.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use csaps::AdditiveModel;
+///
+/// let x = array![[20.1, 0.5], [22.4, 0.1], [25.3, 0.9], [21.0, 0.7], [23.8, 0.3], [26.2, 0.4],
+///                [24.1, 0.8], [22.9, 0.2], [20.5, 0.6], [25.9, 0.1]];
+/// let y = array![1.2, 1.1, 2.6, 1.7, 1.6, 2.3, 2.4, 1.3, 1.5, 2.0];
+///
+/// let model = AdditiveModel::new(&x, &y)
+///     .with_smooths(&[0.5, 0.9])
+///     .make().unwrap();
+///
+/// let yi = model.predict(&array![[23., 0.5]]).unwrap();
+/// ```
+///
+pub struct AdditiveModel<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// `N x K` matrix of the covariates
+    covariates: ArrayView2<'a, T>,
+
+    /// The data values
+    y: ArrayView1<'a, T>,
+
+    /// The optional data weights
+    weights: Option<ArrayView1<'a, T>>,
+
+    /// The optional data weights of the terms smoothers
+    term_weights: Vec<Option<ArrayView1<'a, T>>>,
+
+    /// The optional smoothing parameters of the terms
+    smooths: Option<Array1<T>>,
+
+    /// The maximum number of backfitting iterations
+    max_iter: usize,
+
+    /// The tolerance of the terms change (relative to `y` scale) for convergence
+    tolerance: T,
+
+    /// The number of iterations which have been made
+    iterations: usize,
+
+    /// The flag which is true if the iterations have converged
+    converged: bool,
+
+    /// The intercept
+    intercept: Option<T>,
+
+    /// `NdSpline` structs with computed splines of the terms
+    terms: Option<Vec<NdSpline<'a, T>>>,
+}
+
+impl<'a, T> AdditiveModel<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// Creates `AdditiveModel` struct from the given covariates and data values
+    ///
+    /// # Arguments
+    ///
+    /// - `covariates` -- `N x K` 2-d array-like of the covariates, the rows are the observations
+    ///   and the columns are the covariates of the terms
+    /// - `y` -- the data values 1-d array-like
+    ///
+    pub fn new<C, Y>(covariates: C, y: Y) -> Self
+    where
+        C: AsArray<'a, T, Ix2>,
+        Y: AsArray<'a, T>,
+    {
+        AdditiveModel {
+            covariates: covariates.into(),
+            y: y.into(),
+            weights: None,
+            term_weights: Vec::new(),
+            smooths: None,
+            max_iter: 100,
+            tolerance: T::from(1e-8).unwrap(),
+            iterations: 0,
+            converged: false,
+            intercept: None,
+            terms: None,
+        }
+    }
+
+    /// Sets the weights data vector
+    ///
+    /// `weights.len()` must be equal to `y.len()`
+    ///
+    pub fn with_weights<W>(mut self, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.weights = Some(weights.into());
+        self
+    }
+
+    /// Sets the weights data vector of the smoother of the term with the given index
+    ///
+    /// The term weights are used instead of the data weights for computing the smoothing spline
+    /// of the term from the partial residuals. The intercept and the centering of the terms use
+    /// the data weights. `weights.len()` must be equal to `y.len()` and the term index must be
+    /// less than the number of the terms (covariates columns).
+    ///
+    pub fn with_term_weights<W>(mut self, term: usize, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+
+        if self.term_weights.len() <= term {
+            self.term_weights.resize(term + 1, None);
+        }
+
+        self.term_weights[term] = Some(weights.into());
+        self
+    }
+
+    /// Sets the smoothing parameters of the terms
+    ///
+    /// `smooths.len()` must be equal to the number of the terms (covariates columns).
+    /// If the smoothing parameters are not set, they will be computed automatically
+    /// for every term as for `CubicSmoothingSpline`.
+    ///
+    pub fn with_smooths<'b, S>(mut self, smooths: S) -> Self
+    where
+        S: AsArray<'b, T>,
+        T: 'b,
+    {
+        self.invalidate();
+        self.smooths = Some(smooths.into().to_owned());
+        self
+    }
+
+    /// Sets the maximum number of backfitting iterations, 100 by default
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        self.invalidate();
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Sets the tolerance of the terms change relative to `y` scale, `1e-8` by default
+    ///
+    /// The tolerance must be finite and non-negative.
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        self.invalidate();
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Makes (computes) the model for given data and parameters
+    ///
+    /// # Errors
+    ///
+    /// - If the data or parameters are invalid
+    ///
+    pub fn make(mut self) -> Result<Self> {
+        self.make_validate()?;
+
+        let term_sites = self.make_term_sites();
+        self.term_sites_validate(&term_sites)?;
+
+        self.make_model(&term_sites)?;
+        Ok(self)
+    }
+
+    /// Predicts the data values for the given covariates rows
+    ///
+    /// # Errors
+    ///
+    /// - If the covariates data is invalid
+    /// - If the model yet has not been computed
+    ///
+    pub fn predict<'b, C>(&self, covariates: C) -> Result<Array1<T>>
+    where
+        C: AsArray<'b, T, Ix2>,
+        T: 'b,
+    {
+        let covariates = covariates.into();
+        self.predict_validate(covariates)?;
+
+        let values = self.evaluate_terms(covariates);
+        Ok(values.sum_axis(Axis(1)) + self.intercept.unwrap())
+    }
+
+    /// Evaluates the terms for the given covariates rows, returns `M x K` array
+    ///
+    /// # Errors
+    ///
+    /// - If the covariates data is invalid
+    /// - If the model yet has not been computed
+    ///
+    pub fn evaluate<'b, C>(&self, covariates: C) -> Result<Array2<T>>
+    where
+        C: AsArray<'b, T, Ix2>,
+        T: 'b,
+    {
+        let covariates = covariates.into();
+        self.predict_validate(covariates)?;
+
+        Ok(self.evaluate_terms(covariates))
+    }
+
+    /// Returns the intercept or None
+    pub fn intercept(&self) -> Option<T> {
+        self.intercept
+    }
+
+    /// Returns the splines of the terms or None
+    pub fn terms(&self) -> Option<&[NdSpline<'a, T>]> {
+        self.terms.as_deref()
+    }
+
+    /// Returns the smoothing parameters of the terms or None
+    pub fn smooths(&self) -> Option<ArrayView1<'_, T>> {
+        self.smooths.as_ref().map(|v| v.view())
+    }
+
+    /// Returns the number of backfitting iterations which have been made
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns true if the backfitting iterations have converged
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+
+    /// Evaluates the terms splines for the covariates rows
+    fn evaluate_terms(&self, covariates: ArrayView2<'_, T>) -> Array2<T> {
+        let terms = self.terms.as_ref().unwrap();
+        let mut values = Array2::zeros(covariates.raw_dim());
+
+        for (j, term) in terms.iter().enumerate() {
+            let column = term.evaluate(covariates.column(j));
+            values.column_mut(j).assign(&column.row(0));
+        }
+
+        values
+    }
+
+    /// Invalidate computed model
+    fn invalidate(&mut self) {
+        self.iterations = 0;
+        self.converged = false;
+        self.intercept = None;
+        self.terms = None;
+    }
+}
@@ -0,0 +1,185 @@
+use ndarray::prelude::*;
+
+use crate::{umv::auto_smooth, CubicSmoothingSpline, NdSpline, Real, RealRef, Result};
+
+use super::AdditiveModel;
+
+/// The unique sorted covariate values of the term and the groups of the observations
+pub(super) struct TermSites<T> {
+    /// The unique sorted covariate values
+    pub(super) sites: Array1<T>,
+
+    /// The index of the unique value for every observation
+    pub(super) groups: Vec<usize>,
+}
+
+impl<T: Real<T>> TermSites<T> {
+    pub(super) fn new(x: ArrayView1<'_, T>) -> Self {
+        let mut order: Vec<usize> = (0..x.len()).collect();
+        order.sort_by(|&i, &j| x[i].partial_cmp(&x[j]).unwrap());
+
+        let mut sites: Vec<T> = Vec::new();
+        let mut groups = vec![0; x.len()];
+
+        for i in order {
+            match sites.last() {
+                Some(last) if last.almost_equals(x[i]) => {}
+                _ => sites.push(x[i]),
+            }
+
+            groups[i] = sites.len() - 1;
+        }
+
+        TermSites {
+            sites: Array1::from(sites),
+            groups,
+        }
+    }
+
+    /// Sums the weights of the groups
+    fn group_weights(&self, weights: ArrayView1<'_, T>) -> Array1<T> {
+        let mut sums = Array1::zeros(self.sites.raw_dim());
+
+        for (i, &g) in self.groups.iter().enumerate() {
+            sums[g] += weights[i];
+        }
+
+        sums
+    }
+
+    /// Computes the weighted averages of the values of the groups
+    fn group_averages(
+        &self,
+        values: ArrayView1<'_, T>,
+        weights: ArrayView1<'_, T>,
+        group_weights: ArrayView1<'_, T>,
+    ) -> Array1<T> {
+        let mut sums = Array1::zeros(self.sites.raw_dim());
+
+        for (i, &g) in self.groups.iter().enumerate() {
+            sums[g] += weights[i] * values[i];
+        }
+
+        sums / group_weights
+    }
+}
+
+impl<'a, T> AdditiveModel<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// Computes the unique sorted values and the groups of the observations for every covariate
+    pub(super) fn make_term_sites(&self) -> Vec<TermSites<T>> {
+        self.covariates
+            .columns()
+            .into_iter()
+            .map(TermSites::new)
+            .collect()
+    }
+
+    pub(super) fn make_model(&mut self, term_sites: &[TermSites<T>]) -> Result<()> {
+        let (n, k) = self.covariates.dim();
+        let y = self.y;
+
+        let weights = match self.weights {
+            Some(weights) => weights.to_owned(),
+            None => Array1::ones((n,)),
+        };
+        let weights_sum = weights.sum();
+
+        // The weights of the terms smoothers
+        let term_weights: Vec<Array1<T>> = (0..k)
+            .map(|j| match self.term_weights.get(j).copied().flatten() {
+                Some(term_weights) => term_weights.to_owned(),
+                None => weights.clone(),
+            })
+            .collect();
+
+        let group_weights: Vec<Array1<T>> = term_sites
+            .iter()
+            .zip(&term_weights)
+            .map(|(term, w)| term.group_weights(w.view()))
+            .collect();
+
+        let smooths = match &self.smooths {
+            Some(smooths) => smooths.clone(),
+            None => term_sites
+                .iter()
+                .zip(&group_weights)
+                .map(|(term, w)| auto_smooth(term.sites.view(), w.view()))
+                .collect(),
+        };
+
+        let intercept = (&weights * &y).sum() / weights_sum;
+        let scale = y.fold(T::zero(), |acc, &v| acc.max((v - intercept).abs()));
+        let scale = scale.max(T::min_positive_value());
+
+        // The values of the terms at the observations
+        let mut values = Array2::<T>::zeros((n, k));
+        let mut terms: Vec<Option<NdSpline<'a, T>>> = (0..k).map(|_| None).collect();
+
+        let mut iterations = 0;
+        let mut converged = false;
+
+        while iterations < self.max_iter && !converged {
+            iterations += 1;
+            let mut change = T::zero();
+
+            for (j, term) in term_sites.iter().enumerate() {
+                let residuals = &y - intercept - values.sum_axis(Axis(1)) + values.column(j);
+                let averages = term.group_averages(
+                    residuals.view(),
+                    term_weights[j].view(),
+                    group_weights[j].view(),
+                );
+
+                let spline = CubicSmoothingSpline::new(&term.sites, &averages)
+                    .with_weights(&group_weights[j])
+                    .with_smooth(smooths[j])
+                    .make()?;
+
+                let site_values = spline.evaluate(&term.sites)?;
+                let term_values = term
+                    .groups
+                    .iter()
+                    .map(|&g| site_values[g])
+                    .collect::<Array1<T>>();
+
+                // Centering of the term
+                let mean = (&weights * &term_values).sum() / weights_sum;
+                let term_values = term_values - mean;
+
+                let term_change =
+                    (&term_values - &values.column(j)).fold(T::zero(), |acc, v| acc.max(v.abs()));
+                change = change.max(term_change);
+
+                values.column_mut(j).assign(&term_values);
+                terms[j] = Some(centered_spline(spline.spline().unwrap(), mean));
+            }
+
+            converged = change <= self.tolerance * scale;
+        }
+
+        self.smooths = Some(smooths);
+        self.intercept = Some(intercept);
+        self.terms = Some(terms.into_iter().map(Option::unwrap).collect());
+        self.iterations = iterations;
+        self.converged = converged;
+
+        Ok(())
+    }
+}
+
+/// Returns the owned copy of the spline shifted by the mean
+fn centered_spline<'a, T: Real<T>>(spline: &NdSpline<'_, T>, mean: T) -> NdSpline<'a, T> {
+    let pieces = spline.pieces();
+    let order = spline.order();
+
+    let mut coeffs = spline.coeffs().to_owned();
+    coeffs
+        .slice_mut(s![.., (order - 1) * pieces..])
+        .mapv_inplace(|c| c - mean);
+
+    NdSpline::new(spline.breaks().to_owned(), coeffs)
+}
@@ -0,0 +1,118 @@
+use ndarray::ArrayView2;
+
+use crate::{
+    validate::{validate_smooth_value, validate_weights_size},
+    CsapsError::InvalidInputData,
+    Real, RealRef, Result,
+};
+
+use super::{make::TermSites, AdditiveModel};
+
+impl<'a, T> AdditiveModel<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    pub(super) fn make_validate(&self) -> Result<()> {
+        let (rows, cols) = self.covariates.dim();
+        let n = self.y.len();
+
+        if rows != n {
+            return Err(InvalidInputData(format!(
+                "The number of rows of covariates ({}) is not equal to `y` size ({})",
+                rows, n
+            )));
+        }
+
+        if cols == 0 {
+            return Err(InvalidInputData(
+                "The number of covariates must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self
+            .covariates
+            .iter()
+            .chain(self.y.iter())
+            .any(|v| !v.is_finite())
+        {
+            return Err(InvalidInputData(
+                "Covariates and `y` values must be finite".to_string(),
+            ));
+        }
+
+        validate_weights_size(self.weights, n)?;
+
+        if self.term_weights.len() > cols {
+            return Err(InvalidInputData(format!(
+                "The term index of the weights ({}) is out of the number of covariates ({})",
+                self.term_weights.len() - 1,
+                cols
+            )));
+        }
+
+        for &weights in &self.term_weights {
+            validate_weights_size(weights, n)?;
+        }
+
+        if let Some(smooths) = &self.smooths {
+            if smooths.len() != cols {
+                return Err(InvalidInputData(format!(
+                    "The number of smoothing parameters ({}) is not equal to the number of covariates ({})",
+                    smooths.len(),
+                    cols
+                )));
+            }
+
+            for &smooth in smooths {
+                validate_smooth_value(smooth)?;
+            }
+        }
+
+        if !self.tolerance.is_finite() || self.tolerance < T::zero() {
+            return Err(InvalidInputData(format!(
+                "The tolerance must be finite and non-negative, given {:?}",
+                self.tolerance
+            )));
+        }
+
+        if self.max_iter == 0 {
+            return Err(InvalidInputData(
+                "The maximum number of iterations must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn term_sites_validate(&self, term_sites: &[TermSites<T>]) -> Result<()> {
+        for (j, term) in term_sites.iter().enumerate() {
+            if term.sites.len() < 2 {
+                return Err(InvalidInputData(format!(
+                    "The covariate {} must have at least 2 unique values",
+                    j
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn predict_validate(&self, covariates: ArrayView2<'_, T>) -> Result<()> {
+        if self.terms.is_none() {
+            return Err(InvalidInputData(
+                "The model has not been computed, use `make` method before".to_string(),
+            ));
+        }
+
+        if covariates.nrows() == 0 || covariates.ncols() != self.covariates.ncols() {
+            return Err(InvalidInputData(format!(
+                "The shape of covariates {:?} is not valid, the number of columns must be {}",
+                covariates.dim(),
+                self.covariates.ncols()
+            )));
+        }
+
+        Ok(())
+    }
+}
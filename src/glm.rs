@@ -0,0 +1,329 @@
+mod make;
+mod validate;
+
+use ndarray::{Array, ArrayView, ArrayView1, AsArray, Axis, Dimension};
+
+use crate::{ndarrayext::reshape_evaluated, NdSpline, Real, RealRef, Result};
+
+/// The distribution family of the response data for generalized smoothing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Family {
+    /// Poisson family for non-negative counts, the variance function is `V(mu) = mu`
+    Poisson,
+
+    /// Binomial family for proportions in range `[0, 1]`, the variance function is `V(mu) = mu (1 - mu)`
+    ///
+    /// The numbers of trials should be passed as weights.
+    Binomial,
+}
+
+/// The link function between the mean response and the spline (linear predictor)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Link {
+    /// `eta = log(mu)`, the canonical link for `Family::Poisson`
+    Log,
+
+    /// `eta = log(mu / (1 - mu))`, the canonical link for `Family::Binomial`
+    Logit,
+}
+
+impl Family {
+    /// Returns the canonical link function for the family
+    pub fn canonical_link(&self) -> Link {
+        match self {
+            Family::Poisson => Link::Log,
+            Family::Binomial => Link::Logit,
+        }
+    }
+}
+
+/// Generalized (penalized likelihood) smoothing spline calculator/evaluator
+///
+/// The struct computes the smoothing spline `eta(x)` on the link scale for non-Gaussian
+/// response data (counts or proportions) by penalized iteratively reweighted least squares (P-IRLS).
+/// Every iteration computes the weighted cubic smoothing spline for the working response
+/// with the working weights. The smoothing parameter is fixed during the iterations.
+///
+/// The computed spline is evaluated on the response scale `mu(x) = g^-1(eta(x))`.
+///
+/// Multivariate `y` data is supported, every component is fitted independently,
+/// but with the same smoothing parameter.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use csaps::{Family, GeneralizedSmoothingSpline};
+///
+/// let x = array![1., 2., 3., 4., 5., 6.];
+/// let counts = array![0., 2., 3., 7., 4., 1.];
+///
+/// let s = GeneralizedSmoothingSpline::new(&x, &counts)
+///     .with_family(Family::Poisson)
+///     .with_smooth(0.8)
+///     .make().unwrap();
+///
+/// let rates = s.evaluate(&x).unwrap();
+/// assert!(rates.iter().all(|&r| r > 0.));
+/// ```
+///
+pub struct GeneralizedSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// X data sites (also breaks)
+    x: ArrayView1<'a, T>,
+
+    /// Y data values (counts or proportions)
+    y: ArrayView<'a, T, D>,
+
+    /// The axis parameter defines axis of Y data for spline computing
+    axis: Option<Axis>,
+
+    /// The optional prior weights (the numbers of trials for binomial family)
+    weights: Option<ArrayView1<'a, T>>,
+
+    /// The optional smoothing parameter
+    smooth: Option<T>,
+
+    /// The response distribution family
+    family: Family,
+
+    /// The optional link function, the canonical link for the family is used by default
+    link: Option<Link>,
+
+    /// The maximum number of P-IRLS iterations
+    max_iter: usize,
+
+    /// The relative deviance change tolerance for P-IRLS convergence
+    tolerance: T,
+
+    /// The number of P-IRLS iterations which have been made (the maximum for all components)
+    iterations: usize,
+
+    /// The flag which is true if P-IRLS has converged for all components
+    converged: bool,
+
+    /// `NdSpline` struct with computed spline on the link scale
+    spline: Option<NdSpline<'a, T>>,
+}
+
+impl<'a, T, D> GeneralizedSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// Creates `GeneralizedSmoothingSpline` struct from the given `X` data sites and `Y` data values
+    ///
+    /// # Arguments
+    ///
+    /// - `x` -- the X-data sites 1-d array-like. Must strictly increasing: `x1 < x2 < x3 < ... < xN`
+    /// - `y` -- The Y-data values n-d array-like: non-negative counts for `Family::Poisson` or
+    ///   proportions in range `[0, 1]` for `Family::Binomial`. By default the axis parameter is
+    ///   equal to the last axis of Y data.
+    ///
+    pub fn new<X, Y>(x: X, y: Y) -> Self
+    where
+        X: AsArray<'a, T>,
+        Y: AsArray<'a, T, D>,
+    {
+        GeneralizedSmoothingSpline {
+            x: x.into(),
+            y: y.into(),
+            axis: None,
+            weights: None,
+            smooth: None,
+            family: Family::Poisson,
+            link: None,
+            max_iter: 50,
+            tolerance: T::from(1e-8).unwrap(),
+            iterations: 0,
+            converged: false,
+            spline: None,
+        }
+    }
+
+    /// Sets the axis parameter
+    ///
+    /// `y.shape()[axis]` must be equal to `x.len()`
+    ///
+    pub fn with_axis(mut self, axis: Axis) -> Self {
+        self.invalidate();
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets the prior weights data vector
+    ///
+    /// For `Family::Binomial` the weights are the numbers of trials for every proportion.
+    ///
+    /// `weights.len()` must be equal to `x.len()`
+    ///
+    pub fn with_weights<W>(mut self, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.weights = Some(weights.into());
+        self
+    }
+
+    /// Sets the smoothing parameter
+    ///
+    /// The smoothing parameter should be in range `[0, 1]`. It has the same meaning as for
+    /// `CubicSmoothingSpline` and is applied to the working (linearized) problem on every iteration.
+    ///
+    /// If the smoothing parameter is not set, it will be computed automatically
+    /// from the data sites and the prior weights.
+    ///
+    pub fn with_smooth(mut self, smooth: T) -> Self {
+        self.invalidate();
+        self.smooth = Some(smooth);
+        self
+    }
+
+    /// Sets the response distribution family, `Family::Poisson` by default
+    pub fn with_family(mut self, family: Family) -> Self {
+        self.invalidate();
+        self.family = family;
+        self
+    }
+
+    /// Sets the link function, the canonical link for the family is used by default
+    ///
+    /// `Link::Logit` can only be used with `Family::Binomial`.
+    ///
+    pub fn with_link(mut self, link: Link) -> Self {
+        self.invalidate();
+        self.link = Some(link);
+        self
+    }
+
+    /// Sets the maximum number of P-IRLS iterations, 50 by default
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        self.invalidate();
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Sets the relative deviance change tolerance for P-IRLS convergence, `1e-8` by default
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        self.invalidate();
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Makes (computes) the spline for given data and parameters
+    ///
+    /// # Errors
+    ///
+    /// - If the data or parameters are invalid
+    /// - If reshaping Y data to 2-d view has failed
+    ///
+    pub fn make(mut self) -> Result<Self> {
+        self.make_validate()?;
+        self.make_spline()?;
+        Ok(self)
+    }
+
+    /// Evaluates the computed spline on the given data sites on the response scale
+    ///
+    /// # Errors
+    ///
+    /// - If the `xi` data is invalid
+    /// - If the spline yet has not been computed
+    ///
+    pub fn evaluate<X>(&self, xi: X) -> Result<Array<T, D>>
+    where
+        X: AsArray<'a, T>,
+    {
+        let link = self.link();
+        let eta = self.evaluate_link(xi)?;
+
+        Ok(eta.mapv(|v| link.inverse(v)))
+    }
+
+    /// Evaluates the computed spline on the given data sites on the link scale (linear predictor)
+    ///
+    /// # Errors
+    ///
+    /// - If the `xi` data is invalid
+    /// - If the spline yet has not been computed
+    ///
+    pub fn evaluate_link<X>(&self, xi: X) -> Result<Array<T, D>>
+    where
+        X: AsArray<'a, T>,
+    {
+        let xi = xi.into();
+        self.evaluate_validate(xi)?;
+
+        let eta_2d = self.spline.as_ref().unwrap().evaluate(xi);
+        reshape_evaluated(eta_2d, self.y.view(), self.axis.unwrap())
+    }
+
+    /// Returns the smoothing parameter or None
+    pub fn smooth(&self) -> Option<T> {
+        self.smooth
+    }
+
+    /// Returns the response distribution family
+    pub fn family(&self) -> Family {
+        self.family
+    }
+
+    /// Returns the link function
+    pub fn link(&self) -> Link {
+        self.link.unwrap_or_else(|| self.family.canonical_link())
+    }
+
+    /// Returns the number of P-IRLS iterations which have been made
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns true if P-IRLS has converged for all components
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+
+    /// Returns the ref to `NdSpline` struct with data of computed spline on the link scale or None
+    pub fn spline(&self) -> Option<&NdSpline<'a, T>> {
+        self.spline.as_ref()
+    }
+
+    /// Invalidate computed spline
+    fn invalidate(&mut self) {
+        self.iterations = 0;
+        self.converged = false;
+        self.spline = None;
+    }
+}
+
+impl Link {
+    /// Computes the link function `eta = g(mu)`
+    pub fn apply<T: Real<T>>(&self, mu: T) -> T {
+        match self {
+            Link::Log => mu.ln(),
+            Link::Logit => (mu / (T::one() - mu)).ln(),
+        }
+    }
+
+    /// Computes the inverse link function `mu = g^-1(eta)`
+    pub fn inverse<T: Real<T>>(&self, eta: T) -> T {
+        match self {
+            Link::Log => eta.exp(),
+            Link::Logit => T::one() / (T::one() + (-eta).exp()),
+        }
+    }
+
+    /// Computes the link function derivative `d(eta)/d(mu)`
+    pub(crate) fn derivative<T: Real<T>>(&self, mu: T) -> T {
+        match self {
+            Link::Log => T::one() / mu,
+            Link::Logit => T::one() / (mu * (T::one() - mu)),
+        }
+    }
+}
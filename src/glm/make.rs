@@ -0,0 +1,169 @@
+use ndarray::{prelude::*, Zip};
+
+use crate::{
+    ndarrayext::to_2d, umv::auto_smooth, CubicSmoothingSpline, NdSpline, Real, RealRef, Result,
+};
+
+use super::{Family, GeneralizedSmoothingSpline};
+
+impl<'a, T, D> GeneralizedSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn make_spline(&mut self) -> Result<()> {
+        let axis = self.axis.unwrap_or_else(|| Axis(self.y.ndim() - 1));
+        self.axis = Some(axis);
+
+        let x = self.x;
+        let y = to_2d(self.y.view(), axis)?;
+
+        let weights_default = Array1::ones(x.raw_dim());
+        let weights = self
+            .weights
+            .map(|v| v.reborrow())
+            .unwrap_or_else(|| weights_default.view());
+
+        // The smoothing parameter is fixed during the iterations. If it is not given,
+        // it is computed from the data sites and the prior weights as for `CubicSmoothingSpline`.
+        let smooth = self.smooth.unwrap_or_else(|| auto_smooth(x, weights));
+
+        let mut coeffs: Option<Array2<T>> = None;
+        let mut iterations = 0;
+        let mut converged = true;
+
+        for (row, y_row) in y.outer_iter().enumerate() {
+            let (row_coeffs, row_iterations, row_converged) =
+                self.pirls(x, y_row, weights, smooth)?;
+
+            let coeffs = coeffs.get_or_insert_with(|| Array2::zeros((y.nrows(), row_coeffs.len())));
+            coeffs.row_mut(row).assign(&row_coeffs);
+
+            iterations = iterations.max(row_iterations);
+            converged &= row_converged;
+        }
+
+        self.smooth = Some(smooth);
+        self.iterations = iterations;
+        self.converged = converged;
+        self.spline = Some(NdSpline::new(x, coeffs.unwrap()));
+
+        Ok(())
+    }
+
+    /// Penalized iteratively reweighted least squares for one component of the data
+    ///
+    /// Returns the spline coefficients on the link scale, the number of iterations and
+    /// the convergence flag.
+    fn pirls(
+        &self,
+        x: ArrayView1<'a, T>,
+        y: ArrayView1<'_, T>,
+        weights: ArrayView1<'_, T>,
+        smooth: T,
+    ) -> Result<(Array1<T>, usize, bool)> {
+        let link = self.link();
+        let family = self.family;
+
+        let mut mu = match family {
+            Family::Poisson => y.mapv(|v| v + T::from(0.1).unwrap()),
+            Family::Binomial => {
+                let half = T::from(0.5).unwrap();
+                Zip::from(&y)
+                    .and(&weights)
+                    .map_collect(|&v, &w| (w * v + half) / (w + T::one()))
+            }
+        };
+
+        let mut eta = mu.mapv(|m| link.apply(m));
+        let mut dev_old = deviance(family, y, mu.view(), weights);
+
+        let mut coeffs = Array1::zeros((0,));
+
+        for iteration in 1..=self.max_iter {
+            let deta = mu.mapv(|m| link.derivative(m));
+
+            let work_weights = Zip::from(&weights)
+                .and(&mu)
+                .and(&deta)
+                .map_collect(|&w, &m, &d| {
+                    let ww = w / (variance(family, m) * d * d);
+                    ww.max(T::min_positive_value())
+                });
+
+            let work_y = Zip::from(&eta)
+                .and(&y)
+                .and(&mu)
+                .and(&deta)
+                .map_collect(|&e, &v, &m, &d| e + (v - m) * d);
+
+            let sp = CubicSmoothingSpline::new(x, &work_y)
+                .with_weights(&work_weights)
+                .with_smooth(smooth)
+                .make()?;
+
+            eta = sp.evaluate(x)?;
+            mu = eta.mapv(|e| clip_mean(family, link.inverse(e)));
+            coeffs = sp.spline().unwrap().coeffs().row(0).to_owned();
+
+            let dev = deviance(family, y, mu.view(), weights);
+
+            if (dev - dev_old).abs() / (dev.abs() + T::from(0.1).unwrap()) < self.tolerance {
+                return Ok((coeffs, iteration, true));
+            }
+
+            dev_old = dev;
+        }
+
+        Ok((coeffs, self.max_iter, false))
+    }
+}
+
+/// The variance function of the family
+fn variance<T: Real<T>>(family: Family, mu: T) -> T {
+    match family {
+        Family::Poisson => mu,
+        Family::Binomial => mu * (T::one() - mu),
+    }
+}
+
+/// Keeps the mean response inside the domain of the family variance and link functions
+fn clip_mean<T: Real<T>>(family: Family, mu: T) -> T {
+    let eps = T::epsilon().sqrt();
+
+    match family {
+        Family::Poisson => mu.max(eps),
+        Family::Binomial => mu.max(eps).min(T::one() - eps),
+    }
+}
+
+/// Computes the deviance of the family for the given data and mean response
+fn deviance<T: Real<T>>(
+    family: Family,
+    y: ArrayView1<'_, T>,
+    mu: ArrayView1<'_, T>,
+    weights: ArrayView1<'_, T>,
+) -> T {
+    // y * log(y / mu) with the limit value 0 for y == 0
+    let ylog = |y: T, mu: T| {
+        if y > T::zero() {
+            y * (y / mu).ln()
+        } else {
+            T::zero()
+        }
+    };
+
+    let two = T::from(2.0).unwrap();
+
+    Zip::from(&y)
+        .and(&mu)
+        .and(&weights)
+        .fold(T::zero(), |acc, &v, &m, &w| {
+            let d = match family {
+                Family::Poisson => ylog(v, m) - (v - m),
+                Family::Binomial => ylog(v, m) + ylog(T::one() - v, T::one() - m),
+            };
+            acc + two * w * d
+        })
+}
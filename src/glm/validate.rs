@@ -0,0 +1,88 @@
+use ndarray::{ArrayView1, Dimension};
+
+use crate::{
+    validate::{validate_smooth_value, validate_weights_size, validate_xy_data},
+    CsapsError::InvalidInputData,
+    Real, RealRef, Result,
+};
+
+use super::{Family, GeneralizedSmoothingSpline, Link};
+
+impl<'a, T, D> GeneralizedSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn make_validate(&self) -> Result<()> {
+        validate_xy_data(self.x, self.y.view(), self.axis)?;
+        validate_weights_size(self.weights, self.x.len())?;
+
+        if let Some(weights) = self.weights {
+            if weights.iter().any(|&w| w <= T::zero() || !w.is_finite()) {
+                return Err(InvalidInputData(
+                    "`weights` values must be positive and finite".to_string(),
+                ));
+            }
+        }
+
+        if let Some(smooth) = self.smooth {
+            validate_smooth_value(smooth)?;
+        }
+
+        if self.family == Family::Poisson && self.link() == Link::Logit {
+            return Err(InvalidInputData(
+                "`Link::Logit` link cannot be used with `Family::Poisson` family".to_string(),
+            ));
+        }
+
+        match self.family {
+            Family::Poisson => {
+                if self.y.iter().any(|&v| v < T::zero() || !v.is_finite()) {
+                    return Err(InvalidInputData(
+                        "`y` values must be non-negative counts for `Family::Poisson`".to_string(),
+                    ));
+                }
+            }
+            Family::Binomial => {
+                if self.y.iter().any(|&v| !(v >= T::zero() && v <= T::one())) {
+                    return Err(InvalidInputData(
+                        "`y` values must be proportions in range 0..1 for `Family::Binomial`"
+                            .to_string(),
+                    ));
+                }
+            }
+        }
+
+        if self.max_iter == 0 {
+            return Err(InvalidInputData(
+                "`max_iter` must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self.tolerance <= T::zero() {
+            return Err(InvalidInputData(format!(
+                "`tolerance` must be positive, given {:?}",
+                self.tolerance
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn evaluate_validate(&self, xi: ArrayView1<'a, T>) -> Result<()> {
+        if xi.is_empty() {
+            return Err(InvalidInputData(
+                "The size of `xi` vector must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self.spline.is_none() {
+            return Err(InvalidInputData(
+                "The spline has not been computed, use `make` method before".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,332 @@
+use ndarray::prelude::*;
+use sprs::TriMat;
+
+use crate::{sprsext, Real, RealRef};
+
+/// The linear functional of the spline which is observed in the data
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Functional<T> {
+    /// The value of the spline at the site
+    Value(T),
+
+    /// The first derivative of the spline at the site
+    Derivative(T),
+
+    /// The integral of the spline over the interval
+    Integral(T, T),
+}
+
+/// Penalized least squares smoother in piecewise cubic Hermite basis
+///
+/// The unknowns are the values and the slopes of the spline at the knots, every piece of the spline
+/// is a cubic Hermite polynomial. The smoother minimizes
+///
+/// ```text
+/// p * sum(w * |l(f) - y|^2) + (1 - p) * integral(|f''(t)|^2 dt)
+/// ```
+///
+/// where `l` are the observed linear functionals (values, derivatives or integrals). The criterion
+/// has the same meaning of the smoothing parameter as `CubicSmoothingSpline`. The pieces are
+/// C1-continuous except the knots where the continuity is released (jumps and kinks).
+pub(crate) struct HermiteSmoother<T>
+where
+    T: Real<T>,
+{
+    /// The knots of the spline (breaks)
+    knots: Array1<T>,
+
+    /// The global indices of `[left value, left slope, right value, right slope]` unknowns for every piece
+    dofs: Vec<[usize; 4]>,
+
+    /// The number of unknowns
+    ndof: usize,
+
+    /// The triplets of the weighted Gram matrix of observations `A' W A`
+    gram: TriMat<T>,
+
+    /// The right-hand side `A' W y`, `ndof x ndim`
+    rhs: Array2<T>,
+}
+
+impl<T> HermiteSmoother<T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// Creates the smoother for the given strictly increasing knots and the data dimensionality
+    pub(crate) fn new(knots: Array1<T>, ndim: usize) -> Self {
+        Self::with_discontinuities(knots, ndim, &[], &[])
+    }
+
+    /// Creates the periodic smoother, the last knot is identified with the first knot
+    ///
+    /// The value and the slope unknowns of the last knot are shared with the first knot,
+    /// the spline and its first two derivatives are continuous across the period ends.
+    pub(crate) fn periodic(knots: Array1<T>, ndim: usize) -> Self {
+        let mut smoother = Self::new(knots, ndim);
+
+        // The unknowns of the last knot are the last two unknowns
+        let last = smoother.dofs.len() - 1;
+        smoother.dofs[last][2] = 0;
+        smoother.dofs[last][3] = 1;
+        smoother.ndof -= 2;
+
+        smoother.gram = TriMat::new((smoother.ndof, smoother.ndof));
+        smoother.rhs = Array2::zeros((smoother.ndof, ndim));
+        smoother
+    }
+
+    /// Creates the smoother with the released continuity at the given interior knots
+    ///
+    /// The value and the slope are discontinuous at the jumps, the slope is discontinuous at the kinks.
+    /// The locations of the jumps and the kinks must be the knots.
+    pub(crate) fn with_discontinuities(
+        knots: Array1<T>,
+        ndim: usize,
+        jumps: &[T],
+        kinks: &[T],
+    ) -> Self {
+        let pieces = knots.len() - 1;
+        let is_at = |locations: &[T], knot: T| locations.iter().any(|v| v.almost_equals(knot));
+
+        // The value and the slope unknowns at every knot are shared by the adjacent pieces
+        // if the continuity is not released, `ends[k]` are the unknowns of the knot `k` from the left
+        // and from the right sides.
+        let mut ndof = 0;
+        let mut next = || {
+            ndof += 1;
+            ndof - 1
+        };
+
+        let mut ends: Vec<([usize; 2], [usize; 2])> = Vec::with_capacity(knots.len());
+
+        for (k, &knot) in knots.iter().enumerate() {
+            let left = [next(), next()];
+            let interior = k > 0 && k < pieces;
+
+            let right = if interior && is_at(jumps, knot) {
+                [next(), next()]
+            } else if interior && is_at(kinks, knot) {
+                [left[0], next()]
+            } else {
+                left
+            };
+
+            ends.push((left, right));
+        }
+
+        let dofs: Vec<[usize; 4]> = (0..pieces)
+            .map(|piece| {
+                let (_, [v0, d0]) = ends[piece];
+                let ([v1, d1], _) = ends[piece + 1];
+                [v0, d0, v1, d1]
+            })
+            .collect();
+
+        HermiteSmoother {
+            knots,
+            dofs,
+            ndof,
+            gram: TriMat::new((ndof, ndof)),
+            rhs: Array2::zeros((ndof, ndim)),
+        }
+    }
+
+    /// Adds the observation of the functional with the weight and the data values (for every dimension)
+    pub(crate) fn add_observation(
+        &mut self,
+        functional: Functional<T>,
+        weight: T,
+        values: ArrayView1<'_, T>,
+    ) {
+        let row = self.observation_row(functional);
+
+        for &(i, ai) in row.iter() {
+            for &(j, aj) in row.iter() {
+                self.gram.add_triplet(i, j, weight * (ai * aj));
+            }
+
+            let mut rhs_row = self.rhs.row_mut(i);
+            rhs_row.scaled_add(weight * ai, &values);
+        }
+    }
+
+    /// Solves the penalized least squares problem and returns the unknowns `ndof x ndim`
+    pub(crate) fn solve(&self, smooth: T) -> Array2<T> {
+        let gram = self.gram.to_csr::<usize>();
+        let penalty = self.penalty().to_csr::<usize>();
+
+        // The tiny fractions of the both terms are kept for the extreme smoothing parameters,
+        // it gives the interpolant (p = 1) or the least-squares fit of the null space of the penalty (p = 0).
+        let trace = |m| sprsext::diagonal(m, 0).sum();
+        let (gram_trace, penalty_trace) = (trace(&gram), trace(&penalty));
+
+        let delta = T::epsilon().sqrt();
+        let gram_part = smooth * gram_trace;
+        let penalty_part = (T::one() - smooth) * penalty_trace;
+        let total = gram_part + penalty_part;
+
+        let cg = gram_part.max(delta * total) / gram_trace;
+        let ck = penalty_part.max(delta * total) / penalty_trace;
+
+        let a = &gram.map(|v| *v * cg) + &penalty.map(|v| *v * ck);
+        let b = self.rhs.mapv(|v| v * cg);
+
+        sprsext::solve(&a, &b)
+    }
+
+    /// Converts the unknowns to PP-form coefficients `ndim x 4*pieces` in `NdSpline` layout
+    pub(crate) fn pp_coeffs(&self, solution: &Array2<T>) -> Array2<T> {
+        let pieces = self.dofs.len();
+        let ndim = solution.ncols();
+
+        let two = T::from(2.0).unwrap();
+        let three = T::from(3.0).unwrap();
+
+        let mut coeffs = Array2::zeros((ndim, 4 * pieces));
+
+        for (piece, dof) in self.dofs.iter().enumerate() {
+            let h = self.knots[piece + 1] - self.knots[piece];
+
+            for dim in 0..ndim {
+                let [v0, d0, v1, d1] = dof.map(|i| solution[[i, dim]]);
+                let slope = (v1 - v0) / h;
+
+                coeffs[[dim, piece]] = (d0 + d1 - two * slope) / (h * h);
+                coeffs[[dim, pieces + piece]] = (three * slope - two * d0 - d1) / h;
+                coeffs[[dim, 2 * pieces + piece]] = d0;
+                coeffs[[dim, 3 * pieces + piece]] = v0;
+            }
+        }
+
+        coeffs
+    }
+
+    /// Returns the index of the piece which contains the site (the end pieces are extended)
+    fn piece_index(&self, x: T) -> usize {
+        let pieces = self.dofs.len();
+        let interior = &self.knots.as_slice().unwrap()[1..pieces];
+
+        interior.partition_point(|&k| k <= x)
+    }
+
+    /// Returns the sparse row of the observation matrix for the functional
+    fn observation_row(&self, functional: Functional<T>) -> Vec<(usize, T)> {
+        let mut row: Vec<(usize, T)> = Vec::new();
+
+        let mut push = |piece: usize, basis: [T; 4]| {
+            for (&i, b) in self.dofs[piece].iter().zip(basis) {
+                row.push((i, b));
+            }
+        };
+
+        match functional {
+            Functional::Value(x) => {
+                let piece = self.piece_index(x);
+                let (t, h) = self.local(piece, x);
+                push(piece, basis_values(t, h));
+            }
+            Functional::Derivative(x) => {
+                let piece = self.piece_index(x);
+                let (t, h) = self.local(piece, x);
+                push(piece, basis_derivatives(t, h));
+            }
+            Functional::Integral(a, b) => {
+                let first = self.piece_index(a);
+                let last = self.piece_index(b);
+
+                for piece in first..=last {
+                    let lo = if piece == first { a } else { self.knots[piece] };
+                    let hi = if piece == last {
+                        b
+                    } else {
+                        self.knots[piece + 1]
+                    };
+
+                    let (ta, h) = self.local(piece, lo);
+                    let (tb, _) = self.local(piece, hi);
+
+                    let ia = basis_integrals(ta, h);
+                    let ib = basis_integrals(tb, h);
+
+                    push(piece, [0, 1, 2, 3].map(|k| ib[k] - ia[k]));
+                }
+            }
+        }
+
+        row
+    }
+
+    /// Returns the local coordinate `t` in `[0, 1]` of the site in the piece and the piece length
+    fn local(&self, piece: usize, x: T) -> (T, T) {
+        let h = self.knots[piece + 1] - self.knots[piece];
+        ((x - self.knots[piece]) / h, h)
+    }
+
+    /// Assembles the roughness penalty matrix `integral(f''(t)^2 dt)`
+    fn penalty(&self) -> TriMat<T> {
+        let mut penalty = TriMat::new((self.ndof, self.ndof));
+
+        let c = |v: f64| T::from(v).unwrap();
+
+        for (piece, dof) in self.dofs.iter().enumerate() {
+            let h = self.knots[piece + 1] - self.knots[piece];
+            let h2 = h * h;
+            let h3 = h2 * h;
+
+            let element = [
+                [c(12.), c(6.) * h, c(-12.), c(6.) * h],
+                [c(6.) * h, c(4.) * h2, c(-6.) * h, c(2.) * h2],
+                [c(-12.), c(-6.) * h, c(12.), c(-6.) * h],
+                [c(6.) * h, c(2.) * h2, c(-6.) * h, c(4.) * h2],
+            ];
+
+            for (r, &i) in dof.iter().enumerate() {
+                for (q, &j) in dof.iter().enumerate() {
+                    penalty.add_triplet(i, j, element[r][q] / h3);
+                }
+            }
+        }
+
+        penalty
+    }
+}
+
+/// The cubic Hermite basis functions at the local coordinate `t`
+fn basis_values<T: Real<T>>(t: T, h: T) -> [T; 4] {
+    let c = |v: f64| T::from(v).unwrap();
+    let (t2, t3) = (t * t, t * t * t);
+
+    [
+        c(2.) * t3 - c(3.) * t2 + T::one(),
+        (t3 - c(2.) * t2 + t) * h,
+        c(-2.) * t3 + c(3.) * t2,
+        (t3 - t2) * h,
+    ]
+}
+
+/// The first derivatives of the cubic Hermite basis functions at the local coordinate `t`
+fn basis_derivatives<T: Real<T>>(t: T, h: T) -> [T; 4] {
+    let c = |v: f64| T::from(v).unwrap();
+    let t2 = t * t;
+
+    [
+        (c(6.) * t2 - c(6.) * t) / h,
+        c(3.) * t2 - c(4.) * t + T::one(),
+        (c(6.) * t - c(6.) * t2) / h,
+        c(3.) * t2 - c(2.) * t,
+    ]
+}
+
+/// The integrals of the cubic Hermite basis functions from the piece start to the local coordinate `t`
+fn basis_integrals<T: Real<T>>(t: T, h: T) -> [T; 4] {
+    let c = |v: f64| T::from(v).unwrap();
+    let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+
+    [
+        (t4 / c(2.) - t3 + t) * h,
+        (t4 / c(4.) - c(2.) * t3 / c(3.) + t2 / c(2.)) * h * h,
+        (t3 - t4 / c(2.)) * h,
+        (t4 / c(4.) - t3 / c(3.)) * h * h,
+    ]
+}
@@ -0,0 +1,201 @@
+mod make;
+mod validate;
+
+use ndarray::{Array, ArrayView, ArrayView1, AsArray, Axis, Dimension};
+
+use crate::{ndarrayext::reshape_evaluated, NdSpline, Real, RealRef, Result};
+
+/// N-dimensional (univariate/multivariate) histopolating smoothing spline calculator/evaluator
+///
+/// The struct computes the cubic smoothing spline for the binned data: the totals (integrals)
+/// or the averages of the data over the intervals (bins) given by the bin edges. The spline `f`
+/// minimizes
+///
+/// ```text
+/// p * sum(w * |integral(f(t) dt, e[i], e[i+1]) - v|^2) + (1 - p) * integral(|f''(t)|^2 dt)
+/// ```
+///
+/// over the C1 piecewise-cubic splines with knots at the bin edges (the unknowns are the values
+/// and the slopes of the spline at the bin edges).
+///
+/// With the smoothing parameter equal to 1 the integral of the spline over every bin matches
+/// the data exactly (area-preserving histopolation). The smoothing parameter 0 gives the
+/// least-squares straight line fit to the bin integrals. Fitting the bin integrals avoids
+/// the bias of placing the bin averages at the bin midpoints for curved data.
+///
+/// If the data are the bin averages (`with_averages` method), the residuals are measured
+/// in the averages units.
+///
+/// The computed spline is represented as `NdSpline` with the bin edges as breaks.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use csaps::HistopolatingSmoothingSpline;
+///
+/// // Hourly energy totals
+/// let edges = array![0., 1., 2., 3., 4., 5.];
+/// let energy = array![1.2, 3.4, 5.1, 4.2, 2.0];
+///
+/// let s = HistopolatingSmoothingSpline::new(&edges, &energy)
+///     .with_smooth(1.0)
+///     .make().unwrap();
+///
+/// let power = s.evaluate(&array![0.5, 2.5, 4.5]).unwrap();
+/// ```
+///
+pub struct HistopolatingSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// The bin edges (also breaks)
+    edges: ArrayView1<'a, T>,
+
+    /// The bin integrals or averages
+    values: ArrayView<'a, T, D>,
+
+    /// The axis parameter defines axis of the bin values for spline computing
+    axis: Option<Axis>,
+
+    /// The optional bin weights
+    weights: Option<ArrayView1<'a, T>>,
+
+    /// The optional smoothing parameter
+    smooth: Option<T>,
+
+    /// The flag which is true if the bin values are the averages
+    averages: bool,
+
+    /// `NdSpline` struct with computed spline
+    spline: Option<NdSpline<'a, T>>,
+}
+
+impl<'a, T, D> HistopolatingSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// Creates `HistopolatingSmoothingSpline` struct from the given bin edges and bin values
+    ///
+    /// # Arguments
+    ///
+    /// - `edges` -- the bin edges 1-d array-like. Must strictly increasing: `e1 < e2 < ... < eN+1`
+    /// - `values` -- the bin integrals (totals) n-d array-like, `values.shape()[axis]` must be
+    ///   equal to the number of bins `N`. By default the axis parameter is equal to the last axis.
+    ///
+    pub fn new<E, V>(edges: E, values: V) -> Self
+    where
+        E: AsArray<'a, T>,
+        V: AsArray<'a, T, D>,
+    {
+        HistopolatingSmoothingSpline {
+            edges: edges.into(),
+            values: values.into(),
+            axis: None,
+            weights: None,
+            smooth: None,
+            averages: false,
+            spline: None,
+        }
+    }
+
+    /// Sets the axis parameter
+    ///
+    /// `values.shape()[axis]` must be equal to `edges.len() - 1`
+    ///
+    pub fn with_axis(mut self, axis: Axis) -> Self {
+        self.invalidate();
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets the bin weights vector
+    ///
+    /// `weights.len()` must be equal to the number of bins
+    ///
+    pub fn with_weights<W>(mut self, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.weights = Some(weights.into());
+        self
+    }
+
+    /// Sets the smoothing parameter
+    ///
+    /// The smoothing parameter should be in range `[0, 1]`, where bounds are:
+    ///
+    ///  - 0: The least-squares straight line fit to the bin integrals
+    ///  - 1: The histopolating spline, the integrals over the bins match the data exactly
+    ///
+    /// If the smoothing parameter is not set, it will be computed automatically as for
+    /// `CubicSmoothingSpline` with the bin midpoints as the data sites.
+    ///
+    pub fn with_smooth(mut self, smooth: T) -> Self {
+        self.invalidate();
+        self.smooth = Some(smooth);
+        self
+    }
+
+    /// Sets the flag of the bin averages data
+    ///
+    /// If true, the bin values are the averages over the bins, otherwise the values are
+    /// the integrals (totals) over the bins. False by default.
+    ///
+    pub fn with_averages(mut self, averages: bool) -> Self {
+        self.invalidate();
+        self.averages = averages;
+        self
+    }
+
+    /// Makes (computes) the spline for given data and parameters
+    ///
+    /// # Errors
+    ///
+    /// - If the data or parameters are invalid
+    /// - If reshaping the values data to 2-d view has failed
+    ///
+    pub fn make(mut self) -> Result<Self> {
+        self.make_validate()?;
+        self.make_spline()?;
+        Ok(self)
+    }
+
+    /// Evaluates the computed spline on the given data sites
+    ///
+    /// # Errors
+    ///
+    /// - If the `xi` data is invalid
+    /// - If the spline yet has not been computed
+    ///
+    pub fn evaluate<X>(&self, xi: X) -> Result<Array<T, D>>
+    where
+        X: AsArray<'a, T>,
+    {
+        let xi = xi.into();
+        self.evaluate_validate(xi)?;
+
+        let yi_2d = self.spline.as_ref().unwrap().evaluate(xi);
+        reshape_evaluated(yi_2d, self.values.view(), self.axis.unwrap())
+    }
+
+    /// Returns the smoothing parameter or None
+    pub fn smooth(&self) -> Option<T> {
+        self.smooth
+    }
+
+    /// Returns the ref to `NdSpline` struct with data of computed spline or None
+    pub fn spline(&self) -> Option<&NdSpline<'a, T>> {
+        self.spline.as_ref()
+    }
+
+    /// Invalidate computed spline
+    fn invalidate(&mut self) {
+        self.spline = None;
+    }
+}
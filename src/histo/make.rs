@@ -0,0 +1,75 @@
+use ndarray::prelude::*;
+
+use crate::{
+    hermite::{Functional, HermiteSmoother},
+    ndarrayext::{diff, to_2d},
+    umv::auto_smooth,
+    NdSpline, Real, RealRef, Result,
+};
+
+use super::HistopolatingSmoothingSpline;
+
+impl<'a, T, D> HistopolatingSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn make_spline(&mut self) -> Result<()> {
+        let axis = self.axis.unwrap_or_else(|| Axis(self.values.ndim() - 1));
+        self.axis = Some(axis);
+
+        let edges = self.edges;
+        let values = to_2d(self.values.view(), axis)?;
+
+        let bins = edges.len() - 1;
+        let widths = diff(edges, None);
+
+        let weights = match self.weights {
+            Some(weights) => weights.to_owned(),
+            None => Array1::ones((bins,)),
+        };
+
+        // The residuals of the averages are the residuals of the integrals divided by the bin widths
+        let integral_weights = if self.averages {
+            &weights / &widths.mapv(|w| w * w)
+        } else {
+            weights
+        };
+
+        let smooth = match self.smooth {
+            Some(smooth) => smooth,
+            None => {
+                let two = T::from(2.0).unwrap();
+                let midpoints = (&edges.slice(s![..-1]) + &edges.slice(s![1..])) / two;
+                let midpoint_weights = &integral_weights * &widths.mapv(|w| w * w);
+
+                auto_smooth(midpoints.view(), midpoint_weights.view())
+            }
+        };
+
+        let mut smoother = HermiteSmoother::new(edges.to_owned(), values.nrows());
+
+        for bin in 0..bins {
+            let integrals = if self.averages {
+                &values.column(bin) * widths[bin]
+            } else {
+                values.column(bin).to_owned()
+            };
+
+            smoother.add_observation(
+                Functional::Integral(edges[bin], edges[bin + 1]),
+                integral_weights[bin],
+                integrals.view(),
+            );
+        }
+
+        let solution = smoother.solve(smooth);
+        let coeffs = smoother.pp_coeffs(&solution);
+
+        self.smooth = Some(smooth);
+        self.spline = Some(NdSpline::new(edges, coeffs));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use ndarray::{ArrayView1, Axis, Dimension};
+
+use crate::{
+    validate::{validate_data_sites, validate_smooth_value},
+    CsapsError::InvalidInputData,
+    Real, RealRef, Result,
+};
+
+use super::HistopolatingSmoothingSpline;
+
+impl<'a, T, D> HistopolatingSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn make_validate(&self) -> Result<()> {
+        let edges_size = self.edges.len();
+
+        if edges_size < 3 {
+            return Err(InvalidInputData(
+                "The size of bin edges vector must be greater or equal to 3 (2 bins)".to_string(),
+            ));
+        }
+
+        validate_data_sites(self.edges)?;
+
+        if self.values.ndim() == 0 {
+            return Err(InvalidInputData(
+                "`values` has zero dimensionality".to_string(),
+            ));
+        }
+
+        let default_axis = Axis(self.values.ndim() - 1);
+        let axis = self.axis.unwrap_or(default_axis);
+
+        if axis > default_axis {
+            return Err(InvalidInputData(format!(
+                "`axis` value ({}) is out of bounds `values` dimensionality ({})",
+                axis.0,
+                self.values.ndim()
+            )));
+        }
+
+        let bins = edges_size - 1;
+        let values_size = self.values.len_of(axis);
+
+        if values_size != bins {
+            return Err(InvalidInputData(format!(
+                "The shape[{}] ({}) of `values` data is not equal to the number of bins ({})",
+                axis.0, values_size, bins
+            )));
+        }
+
+        if let Some(weights) = self.weights {
+            if weights.len() != bins {
+                return Err(InvalidInputData(format!(
+                    "`weights` size ({}) is not equal to the number of bins ({})",
+                    weights.len(),
+                    bins
+                )));
+            }
+        }
+
+        if let Some(smooth) = self.smooth {
+            validate_smooth_value(smooth)?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn evaluate_validate(&self, xi: ArrayView1<'a, T>) -> Result<()> {
+        if xi.is_empty() {
+            return Err(InvalidInputData(
+                "The size of `xi` vector must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self.spline.is_none() {
+            return Err(InvalidInputData(
+                "The spline has not been computed, use `make` method before".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
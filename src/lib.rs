@@ -26,6 +26,31 @@
 //! - weighted smoothing
 //! - automatic smoothing (automatic computing the smoothing parameter)
 //! - computing natural cubic spline interpolant when smoothing parameter is equal to one
+//! - generalized (penalized likelihood) smoothing of counts and proportions (Poisson/binomial)
+//! - smoothing splines under tension
+//! - L1 trend filtering (piecewise-constant, piecewise-linear and piecewise-quadratic trends)
+//! - errors-in-variables (orthogonal-distance) smoothing for the data with noise in X and Y
+//! - circular-valued data (angles and phases) smoothing
+//! - smoothing in transformed (log, log1p, Box-Cox or custom) X and Y space
+//! - Hermite smoothing of values together with first-derivative observations
+//! - smoothing with known jumps and kinks at the given locations
+//! - automatic segmentation of the data at the gaps
+//! - resampling of irregularly sampled data onto the uniform grid with anti-aliasing
+//! - choosing the smoothing parameter by the cutoff frequency and computing the frequency response
+//! - difference-based noise level estimation (Rice and Gasser-Sroka-Jennen-Steinmetz estimators)
+//! - partially linear (semiparametric) models with the smooth trend and the linear covariates
+//! - additive models with several smoothing spline terms computed by backfitting
+//! - area-preserving histopolation and smoothing of binned data (bin totals or averages)
+//! - functional data analysis of the smoothed curves (mean, variance and functional PCA)
+//! - landmark and continuous registration (alignment) of the curves by monotone time warping
+//! - parametric smoothing of open and closed 2-d/3-d curves (uniform, chord-length or centripetal parametrization)
+//! - arc length of the spline curves, arc length reparametrization and resampling at equal arc length spacing
+//! - differential geometry of the spline curves (Frenet frame, curvature and torsion)
+//! - closest-point projection of the points onto the spline curves
+//! - intersections of the spline curves with each other and with the lines and planes
+//! - analytic derivatives of the splines as the new splines
+//! - antiderivatives and definite integrals of the splines
+//! - root finding (level crossings) of the spline components
 //!
 //! # Quick Examples
 //!
@@ -119,11 +144,28 @@
 //! See [issue](https://github.com/vbarrielle/sprs/issues/184) for details.
 //!
 
+mod eiv;
 mod errors;
+mod fda;
+mod gam;
+mod glm;
+mod hermite;
+mod histo;
+mod linalg;
 mod ndarrayext;
 mod ndg;
+mod noise;
+mod parametric;
+mod plm;
+mod polynomial;
+mod registration;
+mod resample;
+mod spectral;
 mod sprsext;
+mod tension;
 mod traits;
+mod transform;
+mod trend;
 mod umv;
 mod util;
 mod validate;
@@ -133,10 +175,24 @@ use std::result;
 /// Provides result type for `make` and `evaluate` methods
 pub type Result<T> = result::Result<T, errors::CsapsError>;
 
+pub use eiv::ErrorsInVariablesSmoothingSpline;
 pub use errors::CsapsError;
+pub use fda::FunctionalData;
+pub use gam::AdditiveModel;
+pub use glm::{Family, GeneralizedSmoothingSpline, Link};
+pub use histo::HistopolatingSmoothingSpline;
 pub use ndg::{GridCubicSmoothingSpline, NdGridSpline};
+pub use noise::{DifferenceMethod, NoiseEstimator};
+pub use parametric::{ParametricSmoothingSpline, Parametrization};
+pub use plm::PartiallyLinearModel;
+pub use registration::CurveRegistration;
+pub use resample::Resampler;
+pub use spectral::{frequency_response, smooth_from_cutoff};
+pub use tension::{NdTensionSpline, TensionSmoothingSpline};
 pub use traits::{Real, RealRef};
-pub use umv::{CubicSmoothingSpline, NdSpline};
+pub use transform::Transform;
+pub use trend::TrendFilter;
+pub use umv::{AngleRange, CubicSmoothingSpline, GapFill, NdSegmentedSpline, NdSpline};
 
 // #[cfg(test)]
 // mod tests {
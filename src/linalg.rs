@@ -0,0 +1,182 @@
+use ndarray::prelude::*;
+
+use crate::Real;
+
+/// Solves the dense linear system `A X = B` by Gaussian elimination with partial pivoting
+///
+/// Returns None if the matrix is singular (the pivot is negligible relative to the matrix norm).
+pub(crate) fn solve<T: Real<T>>(a: ArrayView2<'_, T>, b: ArrayView2<'_, T>) -> Option<Array2<T>> {
+    let n = a.nrows();
+
+    let mut a = a.to_owned();
+    let mut x = b.to_owned();
+
+    let norm = a.iter().fold(T::zero(), |acc, v| acc.max(v.abs()));
+    let tolerance = norm * T::epsilon() * T::from(n).unwrap();
+
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&i, &j| a[[i, k]].abs().partial_cmp(&a[[j, k]].abs()).unwrap())
+            .unwrap();
+
+        if a[[pivot, k]].abs() <= tolerance {
+            return None;
+        }
+
+        if pivot != k {
+            for j in 0..n {
+                a.swap([k, j], [pivot, j]);
+            }
+            for j in 0..x.ncols() {
+                x.swap([k, j], [pivot, j]);
+            }
+        }
+
+        for i in k + 1..n {
+            let factor = a[[i, k]] / a[[k, k]];
+
+            if factor.is_zero() {
+                continue;
+            }
+
+            for j in k..n {
+                let akj = a[[k, j]];
+                a[[i, j]] -= factor * akj;
+            }
+            for j in 0..x.ncols() {
+                let xkj = x[[k, j]];
+                x[[i, j]] -= factor * xkj;
+            }
+        }
+    }
+
+    // Back substitution
+    for k in (0..n).rev() {
+        for j in 0..x.ncols() {
+            let sum = (k + 1..n).fold(x[[k, j]], |acc, i| acc - a[[k, i]] * x[[i, j]]);
+            x[[k, j]] = sum / a[[k, k]];
+        }
+    }
+
+    Some(x)
+}
+
+/// Computes the inverse of the dense matrix or returns None if the matrix is singular
+pub(crate) fn inverse<T: Real<T>>(a: ArrayView2<'_, T>) -> Option<Array2<T>> {
+    solve(a, Array2::eye(a.nrows()).view())
+}
+
+/// Computes the eigenvalues and eigenvectors of the dense symmetric matrix by cyclic Jacobi method
+///
+/// Returns the eigenvalues in descending order and the matrix of the corresponding
+/// orthonormal eigenvectors in the columns.
+pub(crate) fn symmetric_eigen<T: Real<T>>(a: ArrayView2<'_, T>) -> (Array1<T>, Array2<T>) {
+    const MAX_SWEEPS: usize = 100;
+
+    let n = a.nrows();
+
+    let mut a = a.to_owned();
+    let mut v = Array2::<T>::eye(n);
+
+    let norm = a.iter().fold(T::zero(), |acc, &x| acc + x * x);
+    let tolerance = norm * T::epsilon() * T::epsilon();
+
+    for _ in 0..MAX_SWEEPS {
+        let off = a
+            .indexed_iter()
+            .filter(|((i, j), _)| i != j)
+            .fold(T::zero(), |acc, (_, &x)| acc + x * x);
+
+        if off <= tolerance {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[[p, q]];
+
+                if apq.abs() <= T::min_positive_value() {
+                    continue;
+                }
+
+                // The rotation which zeroes `a[p, q]`
+                let two = T::from(2.0).unwrap();
+                let theta = (a[[q, q]] - a[[p, p]]) / (two * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (a[[k, p]], a[[k, q]]);
+                    a[[k, p]] = c * akp - s * akq;
+                    a[[k, q]] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[[p, k]], a[[q, k]]);
+                    a[[p, k]] = c * apk - s * aqk;
+                    a[[q, k]] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (v[[k, p]], v[[k, q]]);
+                    v[[k, p]] = c * vkp - s * vkq;
+                    v[[k, q]] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| a[[j, j]].partial_cmp(&a[[i, i]]).unwrap());
+
+    let values = order.iter().map(|&i| a[[i, i]]).collect();
+    let vectors = Array2::from_shape_fn((n, n), |(i, j)| v[[i, order[j]]]);
+
+    (values, vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2};
+
+    use crate::linalg;
+
+    #[test]
+    fn test_solve() {
+        let a = array![[0., 2., 1.], [1., 1., 0.], [3., 0., 1.]];
+        let b = array![[5., 1.], [3., 2.], [6., 3.]];
+
+        let x = linalg::solve(a.view(), b.view()).unwrap();
+
+        assert_abs_diff_eq!(a.dot(&x), b, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = array![[4., 1.], [2., 3.]];
+        let a_inv = linalg::inverse(a.view()).unwrap();
+
+        assert_abs_diff_eq!(a.dot(&a_inv), Array2::eye(2), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_singular() {
+        let a = array![[1., 2.], [2., 4.]];
+
+        assert!(linalg::inverse(a.view()).is_none());
+    }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let a = array![[4., 1., 2.], [1., 3., 0.], [2., 0., 5.]];
+        let (values, vectors) = linalg::symmetric_eigen(a.view());
+
+        assert!(values[0] >= values[1] && values[1] >= values[2]);
+        assert_abs_diff_eq!(vectors.t().dot(&vectors), Array2::eye(3), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            a.dot(&vectors),
+            &vectors * &values.insert_axis(ndarray::Axis(0)),
+            epsilon = 1e-12
+        );
+    }
+}
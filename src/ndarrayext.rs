@@ -173,6 +173,47 @@ where
     indices
 }
 
+/// Unwraps the angles (radians) along the rows of 2-d array
+///
+/// The jumps between consecutive values greater than `pi` are removed by adding
+/// the multiples of `2 * pi`.
+pub(crate) fn unwrap_phase<T>(data: ArrayView2<'_, T>) -> Array2<T>
+where
+    T: Real<T>,
+{
+    let two_pi = T::from(2.0 * std::f64::consts::PI).unwrap();
+    let mut unwrapped = data.to_owned();
+
+    for mut row in unwrapped.rows_mut() {
+        let mut offset = T::zero();
+
+        for i in 1..row.len() {
+            let jump = row[i] + offset - row[i - 1];
+            offset -= two_pi * (jump / two_pi).round();
+            row[i] += offset;
+        }
+    }
+
+    unwrapped
+}
+
+/// Reshapes 2-d evaluated values back to n-d layout of `y` data by the given axis
+pub(crate) fn reshape_evaluated<T, D>(
+    yi_2d: Array2<T>,
+    y: ArrayView<'_, T, D>,
+    axis: Axis,
+) -> Result<Array<T, D>>
+where
+    T: Real<T>,
+    D: Dimension,
+{
+    let mut shape_tmp = y.shape().to_owned();
+    shape_tmp[axis.0] = yi_2d.ncols();
+    let shape: D = dim_from_vec(y.ndim(), shape_tmp);
+
+    Ok(from_2d(&yi_2d, shape, axis)?.to_owned())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ndarrayext::*;
@@ -418,6 +459,24 @@ mod tests {
         assert_eq!(indices, array![1, 2, 2, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4])
     }
 
+    #[test]
+    fn test_unwrap_phase() {
+        let pi = f64::consts::PI;
+        let a = array![[3.0, -3.0, -2.5, 3.1, 2.9], [0.1, 0.2, 0.3, 0.4, 0.5]];
+
+        let unwrapped = unwrap_phase(a.view());
+
+        let expected = array![
+            [3.0, 2. * pi - 3.0, 2. * pi - 2.5, 3.1, 2.9],
+            [0.1, 0.2, 0.3, 0.4, 0.5]
+        ];
+
+        assert_eq!(unwrapped.shape(), expected.shape());
+        for (u, e) in unwrapped.iter().zip(expected.iter()) {
+            assert!((u - e).abs() < 1e-12);
+        }
+    }
+
     #[test]
     fn test_digitize_not_increased() {
         let xi = array![1., 2., 1., 3., 3., 2., 1., 4., 5., 5., 4., 4., 3., 3., 2., 1.];
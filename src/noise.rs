@@ -0,0 +1,166 @@
+use ndarray::{Array, ArrayView, ArrayView1, AsArray, Axis, Dimension, RemoveAxis};
+
+use crate::{validate::validate_xy_data, CsapsError::InvalidInputData, Real, Result};
+
+/// The difference-based estimator of the noise variance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DifferenceMethod {
+    /// Rice estimator by the first differences
+    ///
+    /// `sigma^2 = sum((y[i+1] - y[i])^2) / (2 * (n - 1))`. The estimator is simple but it is biased
+    /// upwards by the slope of the underlying function.
+    Rice,
+
+    /// Gasser-Sroka-Jennen-Steinmetz estimator by the local linear pseudo-residuals
+    ///
+    /// The pseudo-residuals are the differences between the data values and the straight lines
+    /// through the neighbour data points which take into account non-uniform data sites.
+    /// The estimator is unbiased for locally linear functions. It needs at least 3 data points.
+    Gsjs,
+}
+
+/// Difference-based estimator of the noise level of Y data without fitting
+///
+/// The estimator returns the noise standard deviation for every component of n-d Y data
+/// (every 1-d lane along the axis). It can be used as the quick estimate of the noise level
+/// before smoothing.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use csaps::{DifferenceMethod, NoiseEstimator};
+///
+/// let x = array![0., 0.5, 1.5, 2.0, 3.0, 3.5, 4.5, 5.0];
+/// let y = array![[0.1, 0.45, 1.6, 1.95, 3.1, 3.45, 4.6, 4.95],
+///                [1.0, 0.9, 1.1, 1.0, 0.9, 1.1, 1.0, 0.9]];
+///
+/// let sigma = NoiseEstimator::new(&x, &y)
+///     .with_method(DifferenceMethod::Gsjs)
+///     .estimate().unwrap();
+///
+/// assert_eq!(sigma.len(), 2);
+/// ```
+///
+pub struct NoiseEstimator<'a, T, D>
+where
+    T: Real<T>,
+    D: Dimension,
+{
+    /// X data sites
+    x: ArrayView1<'a, T>,
+
+    /// Y data values
+    y: ArrayView<'a, T, D>,
+
+    /// The axis parameter defines axis of Y data
+    axis: Option<Axis>,
+
+    /// The estimator method
+    method: DifferenceMethod,
+}
+
+impl<'a, T, D> NoiseEstimator<'a, T, D>
+where
+    T: Real<T>,
+    D: Dimension + RemoveAxis,
+{
+    /// Creates `NoiseEstimator` struct from the given `X` data sites and `Y` data values
+    ///
+    /// # Arguments
+    ///
+    /// - `x` -- the X-data sites 1-d array-like. Must strictly increasing: `x1 < x2 < x3 < ... < xN`
+    /// - `y` -- The Y-data values n-d array-like. By default the axis parameter is equal to the last axis.
+    ///
+    pub fn new<X, Y>(x: X, y: Y) -> Self
+    where
+        X: AsArray<'a, T>,
+        Y: AsArray<'a, T, D>,
+    {
+        NoiseEstimator {
+            x: x.into(),
+            y: y.into(),
+            axis: None,
+            method: DifferenceMethod::Gsjs,
+        }
+    }
+
+    /// Sets the axis parameter
+    ///
+    /// `y.shape()[axis]` must be equal to `x.len()`
+    ///
+    pub fn with_axis(mut self, axis: Axis) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets the estimator method
+    ///
+    /// `DifferenceMethod::Gsjs` by default.
+    ///
+    pub fn with_method(mut self, method: DifferenceMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Estimates the noise standard deviation for every component of Y data
+    ///
+    /// The shape of the returned array is the shape of Y data without the axis,
+    /// for 1-d Y data it is 0-d array (use `into_scalar` method).
+    ///
+    /// # Errors
+    ///
+    /// - If the data is invalid
+    ///
+    pub fn estimate(&self) -> Result<Array<T, D::Smaller>> {
+        validate_xy_data(self.x, self.y.view(), self.axis)?;
+
+        if self.method == DifferenceMethod::Gsjs && self.x.len() < 3 {
+            return Err(InvalidInputData(
+                "The size of data vectors must be greater or equal to 3 for GSJS estimator"
+                    .to_string(),
+            ));
+        }
+
+        let axis = self.axis.unwrap_or(Axis(self.y.ndim() - 1));
+
+        let variance = match self.method {
+            DifferenceMethod::Rice => self.y.map_axis(axis, rice_variance),
+            DifferenceMethod::Gsjs => self.y.map_axis(axis, |y| gsjs_variance(self.x, y)),
+        };
+
+        Ok(variance.mapv(T::sqrt))
+    }
+}
+
+/// Computes Rice estimate of the noise variance
+fn rice_variance<T: Real<T>>(y: ArrayView1<'_, T>) -> T {
+    let n = y.len();
+    let two = T::from(2.0).unwrap();
+
+    let sum = y
+        .windows(2)
+        .into_iter()
+        .fold(T::zero(), |acc, w| acc + (w[1] - w[0]).powi(2));
+
+    sum / (two * T::from(n - 1).unwrap())
+}
+
+/// Computes Gasser-Sroka-Jennen-Steinmetz estimate of the noise variance
+fn gsjs_variance<T: Real<T>>(x: ArrayView1<'_, T>, y: ArrayView1<'_, T>) -> T {
+    let n = y.len();
+
+    let sum = (1..n - 1).fold(T::zero(), |acc, i| {
+        let h = x[i + 1] - x[i - 1];
+        let a = (x[i + 1] - x[i]) / h;
+        let b = (x[i] - x[i - 1]) / h;
+
+        // The pseudo-residual is normalized to have the variance of the noise
+        let residual = a * y[i - 1] + b * y[i + 1] - y[i];
+        let c2 = (a * a + b * b + T::one()).recip();
+
+        acc + c2 * residual * residual
+    });
+
+    sum / T::from(n - 2).unwrap()
+}
@@ -0,0 +1,228 @@
+mod make;
+mod validate;
+
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2, AsArray, Ix2};
+
+use crate::{NdSpline, Real, RealRef, Result};
+
+/// The parametrization of the points of the curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parametrization {
+    /// The parameter increments are equal
+    Uniform,
+
+    /// The parameter increments are the distances between the points
+    ChordLength,
+
+    /// The parameter increments are the square roots of the distances between the points
+    ///
+    /// The centripetal parametrization avoids cusps and self-intersections of the curve at
+    /// the sharp turns of the path.
+    Centripetal,
+}
+
+/// Parametric cubic smoothing spline for 2-d/3-d (or any dimensional) curves and paths
+///
+/// The struct takes `N x D` array of the points of the curve (every row is the point),
+/// computes the parameter values of the points and smooths the coordinates as the functions
+/// of the parameter. The parameter is normalized to `[0, 1]` range.
+///
+/// The open curves are computed by `CubicSmoothingSpline`. The closed curves (see `with_periodic`
+/// method) are computed by the periodic smoothing spline: the closing segment from the last
+/// point to the first point is added to the parametrization and the spline and its first and
+/// second derivatives are continuous across the period ends. If the last point of the closed
+/// curve is equal to the first point, it is ignored.
+///
+/// The computed spline is represented as `NdSpline` with `D` dimensions and the parameter values
+/// as breaks (with the closing parameter value `1.0` for closed curves).
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{array, Array1};
+/// use csaps::{Parametrization, ParametricSmoothingSpline};
+///
+/// let points = array![[0., 0.], [1., 0.1], [2., 0.9], [2.1, 2.], [1.2, 2.9], [0.1, 3.1]];
+///
+/// let s = ParametricSmoothingSpline::new(&points)
+///     .with_parametrization(Parametrization::Centripetal)
+///     .with_smooth(0.95)
+///     .make().unwrap();
+///
+/// let t = s.parameter().unwrap();
+/// let ti = Array1::linspace(0., 1., 20);
+/// let pi = s.evaluate(&ti).unwrap();
+///
+/// assert_eq!(pi.shape(), &[20, 2]);
+/// ```
+///
+pub struct ParametricSmoothingSpline<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// `N x D` array of the points of the curve
+    points: ArrayView2<'a, T>,
+
+    /// The parametrization of the points
+    parametrization: Parametrization,
+
+    /// The flag of the closed (periodic) curve
+    periodic: bool,
+
+    /// The optional weights of the points
+    weights: Option<ArrayView1<'a, T>>,
+
+    /// The optional smoothing parameter
+    smooth: Option<T>,
+
+    /// The computed parameter values of the points
+    parameter: Option<Array1<T>>,
+
+    /// `NdSpline` struct with computed spline
+    spline: Option<NdSpline<'a, T>>,
+}
+
+impl<'a, T> ParametricSmoothingSpline<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// Creates `ParametricSmoothingSpline` struct from the given points
+    ///
+    /// # Arguments
+    ///
+    /// - `points` -- `N x D` 2-d array-like of the points of the curve, every row is the point
+    ///
+    pub fn new<P>(points: P) -> Self
+    where
+        P: AsArray<'a, T, Ix2>,
+    {
+        ParametricSmoothingSpline {
+            points: points.into(),
+            parametrization: Parametrization::ChordLength,
+            periodic: false,
+            weights: None,
+            smooth: None,
+            parameter: None,
+            spline: None,
+        }
+    }
+
+    /// Sets the parametrization of the points
+    ///
+    /// `Parametrization::ChordLength` by default.
+    ///
+    pub fn with_parametrization(mut self, parametrization: Parametrization) -> Self {
+        self.invalidate();
+        self.parametrization = parametrization;
+        self
+    }
+
+    /// Sets the periodic option for the closed curves
+    pub fn with_periodic(mut self, periodic: bool) -> Self {
+        self.invalidate();
+        self.periodic = periodic;
+        self
+    }
+
+    /// Sets the weights of the points
+    ///
+    /// `weights.len()` must be equal to the number of points.
+    ///
+    pub fn with_weights<W>(mut self, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.weights = Some(weights.into());
+        self
+    }
+
+    /// Sets the smoothing parameter
+    ///
+    /// The smoothing parameter should be in range `[0, 1]`, if it is not set,
+    /// it will be computed automatically.
+    ///
+    pub fn with_smooth(mut self, smooth: T) -> Self {
+        self.invalidate();
+        self.smooth = Some(smooth);
+        self
+    }
+
+    /// Makes (computes) the parametric spline for the given points and parameters
+    ///
+    /// # Errors
+    ///
+    /// - If the points or parameters are invalid
+    /// - If the consecutive points coincide for chord-length or centripetal parametrization
+    ///
+    pub fn make(mut self) -> Result<Self> {
+        self.make_validate()?;
+        self.make_spline()?;
+        Ok(self)
+    }
+
+    /// Evaluates the curve points for the given parameter values, returns `M x D` array
+    ///
+    /// The parameter values of the closed curve are wrapped into the period `[0, 1)`.
+    ///
+    /// # Errors
+    ///
+    /// - If the parameter values are invalid
+    /// - If the spline yet has not been computed
+    ///
+    pub fn evaluate<'b, P>(&self, t: P) -> Result<Array2<T>>
+    where
+        P: AsArray<'b, T>,
+        T: 'b,
+    {
+        let t = t.into();
+        self.evaluate_validate(t)?;
+
+        let t = self.wrap_parameter(t);
+        Ok(self
+            .spline
+            .as_ref()
+            .unwrap()
+            .evaluate(t.view())
+            .reversed_axes())
+    }
+
+    /// Returns the parameter values of the points or None
+    ///
+    /// For the closed curves the closing parameter value `1.0` is not included.
+    pub fn parameter(&self) -> Option<ArrayView1<'_, T>> {
+        self.parameter.as_ref().map(|v| v.view())
+    }
+
+    /// Returns the smoothing parameter or None
+    pub fn smooth(&self) -> Option<T> {
+        self.smooth
+    }
+
+    /// Returns true if the curve is closed (periodic)
+    pub fn periodic(&self) -> bool {
+        self.periodic
+    }
+
+    /// Returns the ref to `NdSpline` struct with data of computed spline or None
+    pub fn spline(&self) -> Option<&NdSpline<'a, T>> {
+        self.spline.as_ref()
+    }
+
+    /// Wraps the parameter values of the closed curve into the period
+    fn wrap_parameter(&self, t: ArrayView1<'_, T>) -> Array1<T> {
+        if self.periodic {
+            t.mapv(|v| v - v.floor())
+        } else {
+            t.to_owned()
+        }
+    }
+
+    /// Invalidate computed spline
+    fn invalidate(&mut self) {
+        self.parameter = None;
+        self.spline = None;
+    }
+}
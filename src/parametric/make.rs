@@ -0,0 +1,127 @@
+use ndarray::prelude::*;
+
+use crate::{
+    hermite::{Functional, HermiteSmoother},
+    umv::auto_smooth,
+    CsapsError::InvalidInputData,
+    CubicSmoothingSpline, NdSpline, Real, RealRef, Result,
+};
+
+use super::{ParametricSmoothingSpline, Parametrization};
+
+impl<'a, T> ParametricSmoothingSpline<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    pub(super) fn make_spline(&mut self) -> Result<()> {
+        let n = self.curve_size();
+        let points = self.points.slice(s![..n, ..]);
+
+        let weights = match self.weights {
+            Some(weights) => weights.slice(s![..n]).to_owned(),
+            None => Array1::ones((n,)),
+        };
+
+        let knots = self.make_parameter(points)?;
+        let parameter = knots.slice(s![..n]);
+
+        // The coordinates are the rows, every row is smoothed as the function of the parameter
+        let coords = points.t().to_owned();
+
+        let smooth = self
+            .smooth
+            .unwrap_or_else(|| auto_smooth(parameter, weights.view()));
+
+        let spline = if self.periodic {
+            let mut smoother = HermiteSmoother::periodic(knots.clone(), coords.nrows());
+
+            for (i, &t) in parameter.iter().enumerate() {
+                smoother.add_observation(Functional::Value(t), weights[i], coords.column(i));
+            }
+
+            let solution = smoother.solve(smooth);
+            NdSpline::new(knots.clone(), smoother.pp_coeffs(&solution))
+        } else {
+            let spline = CubicSmoothingSpline::new(parameter, &coords)
+                .with_weights(&weights)
+                .with_smooth(smooth)
+                .make()?;
+
+            let spline = spline.spline().unwrap();
+            NdSpline::new(knots.clone(), spline.coeffs().to_owned())
+        };
+
+        self.smooth = Some(smooth);
+        self.parameter = Some(parameter.to_owned());
+        self.spline = Some(spline);
+
+        Ok(())
+    }
+
+    /// Returns the number of the points of the curve
+    ///
+    /// The last point of the closed curve is ignored if it is equal to the first point.
+    pub(super) fn curve_size(&self) -> usize {
+        let n = self.points.nrows();
+
+        if self.periodic && n > 1 {
+            let first = self.points.row(0);
+            let last = self.points.row(n - 1);
+
+            if first
+                .iter()
+                .zip(last.iter())
+                .all(|(a, b)| a.almost_equals(*b))
+            {
+                return n - 1;
+            }
+        }
+
+        n
+    }
+
+    /// Computes the normalized parameter values of the points
+    ///
+    /// The closing parameter value `1.0` is appended for the closed curve.
+    fn make_parameter(&self, points: ArrayView2<'_, T>) -> Result<Array1<T>> {
+        let n = points.nrows();
+
+        let distance = |i: usize, j: usize| {
+            (&points.row(j) - &points.row(i))
+                .mapv(|v| v * v)
+                .sum()
+                .sqrt()
+        };
+
+        let mut pairs: Vec<(usize, usize)> = (1..n).map(|i| (i - 1, i)).collect();
+
+        if self.periodic {
+            pairs.push((n - 1, 0));
+        }
+
+        let mut parameter = Array1::<T>::zeros((pairs.len() + 1,));
+
+        for (k, &(i, j)) in pairs.iter().enumerate() {
+            let increment = match self.parametrization {
+                Parametrization::Uniform => T::one(),
+                Parametrization::ChordLength => distance(i, j),
+                Parametrization::Centripetal => distance(i, j).sqrt(),
+            };
+
+            if increment <= T::zero() {
+                return Err(InvalidInputData(format!(
+                    "The consecutive points {} and {} coincide",
+                    i, j
+                )));
+            }
+
+            parameter[k + 1] = parameter[k] + increment;
+        }
+
+        let total = parameter[pairs.len()];
+        parameter.mapv_inplace(|v| v / total);
+
+        Ok(parameter)
+    }
+}
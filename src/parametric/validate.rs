@@ -0,0 +1,64 @@
+use ndarray::ArrayView1;
+
+use crate::{
+    validate::{validate_smooth_value, validate_weights_size},
+    CsapsError::InvalidInputData,
+    Real, RealRef, Result,
+};
+
+use super::ParametricSmoothingSpline;
+
+impl<'a, T> ParametricSmoothingSpline<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    pub(super) fn make_validate(&self) -> Result<()> {
+        let (n, ndim) = self.points.dim();
+
+        if ndim == 0 {
+            return Err(InvalidInputData(
+                "The points must have at least 1 coordinate".to_string(),
+            ));
+        }
+
+        let min_points = if self.periodic { 3 } else { 2 };
+
+        if self.curve_size() < min_points {
+            return Err(InvalidInputData(format!(
+                "The number of points must be greater or equal to {}",
+                min_points
+            )));
+        }
+
+        if self.points.iter().any(|v| !v.is_finite()) {
+            return Err(InvalidInputData(
+                "The points must have finite coordinates".to_string(),
+            ));
+        }
+
+        validate_weights_size(self.weights, n)?;
+
+        if let Some(smooth) = self.smooth {
+            validate_smooth_value(smooth)?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn evaluate_validate(&self, t: ArrayView1<'_, T>) -> Result<()> {
+        if t.is_empty() {
+            return Err(InvalidInputData(
+                "The size of parameter values vector must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self.spline.is_none() {
+            return Err(InvalidInputData(
+                "The spline has not been computed, use `make` method before".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
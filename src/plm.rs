@@ -0,0 +1,227 @@
+mod make;
+mod validate;
+
+use ndarray::{Array1, ArrayView1, ArrayView2, AsArray, Ix2};
+
+use crate::{NdSpline, Real, RealRef, Result};
+
+/// Partially linear (semiparametric) model with the smoothing spline and the parametric covariates
+///
+/// The struct fits the model
+///
+/// ```text
+/// y = X * beta + f(t) + noise
+/// ```
+///
+/// where `X` is `N x K` matrix of the known covariates (regressors), `beta` are the linear
+/// coefficients and `f` is the smooth function (trend) which is represented by the cubic
+/// smoothing spline.
+///
+/// The model is computed by Speckman approach: the covariates and the data are partial
+/// residualized by the linear smoother `S` of `CubicSmoothingSpline` (`X~ = (I - S) X`,
+/// `y~ = (I - S) y`), the coefficients are the weighted least-squares solution
+/// `beta = (X~' W X~)^-1 X~' W y~` and the smooth part is the smoothing spline
+/// of the partial residuals `y - X * beta`.
+///
+/// The standard errors of the coefficients are computed from the covariance of the linear
+/// estimator with the noise variance estimated by the weighted residual sum of squares
+/// divided by the residual degrees of freedom `N - trace(H)` (`H` is the hat matrix of the model).
+///
+/// The covariates must not contain the constant column (the intercept is the part of `f`).
+/// The smoother matrix is computed explicitly, so the memory is `O(N^2)`.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use csaps::PartiallyLinearModel;
+///
+/// let t = array![0., 1., 2., 3., 4., 5., 6., 7.];
+/// let temperature = array![[12.], [15.], [11.], [18.], [14.], [20.], [13.], [16.]];
+/// let y = array![25.1, 32.0, 25.2, 40.3, 33.1, 46.2, 33.0, 40.1];
+///
+/// let model = PartiallyLinearModel::new(&t, &temperature, &y)
+///     .with_smooth(0.5)
+///     .make().unwrap();
+///
+/// let beta = model.coefficients().unwrap();
+/// let beta_se = model.standard_errors().unwrap();
+/// let trend = model.evaluate(&t).unwrap();
+/// ```
+///
+pub struct PartiallyLinearModel<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// The data sites of the smooth part
+    t: ArrayView1<'a, T>,
+
+    /// `N x K` matrix of the covariates
+    covariates: ArrayView2<'a, T>,
+
+    /// The data values
+    y: ArrayView1<'a, T>,
+
+    /// The optional data weights
+    weights: Option<ArrayView1<'a, T>>,
+
+    /// The optional smoothing parameter
+    smooth: Option<T>,
+
+    /// The estimated linear coefficients
+    coefficients: Option<Array1<T>>,
+
+    /// The standard errors of the linear coefficients
+    standard_errors: Option<Array1<T>>,
+
+    /// The residual degrees of freedom
+    residual_df: Option<T>,
+
+    /// `NdSpline` struct with computed spline of the smooth part
+    spline: Option<NdSpline<'a, T>>,
+}
+
+impl<'a, T> PartiallyLinearModel<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// Creates `PartiallyLinearModel` struct from the given data sites, covariates and data values
+    ///
+    /// # Arguments
+    ///
+    /// - `t` -- the data sites of the smooth part 1-d array-like. Must strictly increasing
+    /// - `covariates` -- `N x K` 2-d array-like of the covariates, the rows are the observations
+    /// - `y` -- the data values 1-d array-like
+    ///
+    pub fn new<X, C, Y>(t: X, covariates: C, y: Y) -> Self
+    where
+        X: AsArray<'a, T>,
+        C: AsArray<'a, T, Ix2>,
+        Y: AsArray<'a, T>,
+    {
+        PartiallyLinearModel {
+            t: t.into(),
+            covariates: covariates.into(),
+            y: y.into(),
+            weights: None,
+            smooth: None,
+            coefficients: None,
+            standard_errors: None,
+            residual_df: None,
+            spline: None,
+        }
+    }
+
+    /// Sets the weights data vector
+    ///
+    /// `weights.len()` must be equal to `t.len()`
+    ///
+    pub fn with_weights<W>(mut self, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.weights = Some(weights.into());
+        self
+    }
+
+    /// Sets the smoothing parameter of the smooth part
+    ///
+    /// If the smoothing parameter is not set, it will be computed automatically
+    /// as for `CubicSmoothingSpline`.
+    ///
+    pub fn with_smooth(mut self, smooth: T) -> Self {
+        self.invalidate();
+        self.smooth = Some(smooth);
+        self
+    }
+
+    /// Makes (computes) the model for given data and parameters
+    ///
+    /// # Errors
+    ///
+    /// - If the data or parameters are invalid
+    /// - If the covariates are collinear (or collinear with the smooth part)
+    ///
+    pub fn make(mut self) -> Result<Self> {
+        self.make_validate()?;
+        self.make_model()?;
+        Ok(self)
+    }
+
+    /// Evaluates the smooth part `f` of the model on the given data sites
+    ///
+    /// # Errors
+    ///
+    /// - If the `ti` data is invalid
+    /// - If the model yet has not been computed
+    ///
+    pub fn evaluate<X>(&self, ti: X) -> Result<Array1<T>>
+    where
+        X: AsArray<'a, T>,
+    {
+        let ti = ti.into();
+        self.evaluate_validate(ti)?;
+
+        let fi = self.spline.as_ref().unwrap().evaluate(ti);
+        Ok(fi.row(0).to_owned())
+    }
+
+    /// Predicts the data values `X * beta + f(t)` for the given data sites and covariates
+    ///
+    /// # Errors
+    ///
+    /// - If the data is invalid
+    /// - If the model yet has not been computed
+    ///
+    pub fn predict<X, C>(&self, ti: X, covariates: C) -> Result<Array1<T>>
+    where
+        X: AsArray<'a, T>,
+        C: AsArray<'a, T, Ix2>,
+    {
+        let ti = ti.into();
+        let covariates = covariates.into();
+        self.predict_validate(ti, covariates)?;
+
+        let fi = self.evaluate(ti)?;
+        Ok(covariates.dot(self.coefficients.as_ref().unwrap()) + fi)
+    }
+
+    /// Returns the estimated linear coefficients or None
+    pub fn coefficients(&self) -> Option<ArrayView1<'_, T>> {
+        self.coefficients.as_ref().map(|v| v.view())
+    }
+
+    /// Returns the standard errors of the linear coefficients or None
+    ///
+    /// The standard errors are NaN if the residual degrees of freedom are not positive
+    /// (for example, for the interpolating smooth part).
+    pub fn standard_errors(&self) -> Option<ArrayView1<'_, T>> {
+        self.standard_errors.as_ref().map(|v| v.view())
+    }
+
+    /// Returns the residual degrees of freedom `N - trace(H)` or None
+    pub fn residual_df(&self) -> Option<T> {
+        self.residual_df
+    }
+
+    /// Returns the smoothing parameter or None
+    pub fn smooth(&self) -> Option<T> {
+        self.smooth
+    }
+
+    /// Returns the ref to `NdSpline` struct with data of computed spline of the smooth part or None
+    pub fn spline(&self) -> Option<&NdSpline<'a, T>> {
+        self.spline.as_ref()
+    }
+
+    /// Invalidate computed model
+    fn invalidate(&mut self) {
+        self.coefficients = None;
+        self.standard_errors = None;
+        self.residual_df = None;
+        self.spline = None;
+    }
+}
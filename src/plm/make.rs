@@ -0,0 +1,172 @@
+use ndarray::prelude::*;
+use sprs::CsMat;
+
+use crate::{
+    linalg,
+    ndarrayext::diff,
+    sprsext::LdlSolver,
+    umv::{auto_smooth, continuity_matrix, second_differences, weighted_gram},
+    CsapsError::InvalidInputData,
+    CubicSmoothingSpline, NdSpline, Real, RealRef, Result,
+};
+
+use super::PartiallyLinearModel;
+
+impl<'a, T> PartiallyLinearModel<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    pub(super) fn make_model(&mut self) -> Result<()> {
+        let t = self.t;
+        let y = self.y;
+        let x = self.covariates;
+        let n = t.len();
+
+        let weights = match self.weights {
+            Some(weights) => weights.to_owned(),
+            None => Array1::ones((n,)),
+        };
+
+        let smooth = self
+            .smooth
+            .unwrap_or_else(|| auto_smooth(t, weights.view()));
+
+        let collinear = || {
+            InvalidInputData(
+                "The covariates are collinear or collinear with the smooth part".to_string(),
+            )
+        };
+
+        // The smoother reproduces the data of 2 sites, every covariate is collinear with it
+        if n < 3 {
+            return Err(collinear());
+        }
+
+        let smoother = Smoother::new(t, weights.view(), smooth);
+
+        let xr = smoother.residualize(x);
+        let xrw = &xr.t() * &weights;
+
+        // The covariate which is (almost) reproduced by the smoother is collinear with the smooth part
+        let gram = xrw.dot(&xr);
+        let scale = (&x.t() * &weights).dot(&x);
+        let tolerance = T::epsilon().sqrt();
+
+        if (0..gram.nrows()).any(|j| gram[[j, j]] <= tolerance * scale[[j, j]]) {
+            return Err(collinear());
+        }
+
+        let gram_inv = linalg::inverse(gram.view()).ok_or_else(collinear)?;
+
+        // The coefficients `beta = A y` where `A = G^-1 Xr' W (I - S)` and `G = Xr' W Xr`
+        let coefficients = gram_inv.dot(&xrw.dot(&smoother.residualize(y.insert_axis(Axis(1)))));
+        let coefficients = coefficients.column(0).to_owned();
+
+        let partial = &y - &x.dot(&coefficients);
+        let residuals = smoother.residualize(partial.view().insert_axis(Axis(1)));
+        let residuals = residuals.column(0);
+
+        // `W S` is symmetric, thus `(I - S)' W = W (I - S)` and `A Xr = G^-1 Xr' W Xrr`
+        // where `Xrr = (I - S) Xr`
+        let xrr = smoother.residualize(xr.view());
+
+        // The trace of the hat matrix `H = S + (I - S) X A`
+        let model_df = smoother.trace + gram_inv.dot(&xrw.dot(&xrr)).diag().sum();
+        let residual_df = T::from(n).unwrap() - model_df;
+
+        let variance = if residual_df > T::zero() {
+            (&residuals * &residuals * &weights).sum() / residual_df
+        } else {
+            T::nan()
+        };
+
+        // Cov(beta) = sigma^2 * A W^-1 A' = sigma^2 * G^-1 Xrr' W Xrr G^-1
+        let xrrw = &xrr.t() * &weights;
+        let covariance = gram_inv.dot(&xrrw.dot(&xrr)).dot(&gram_inv) * variance;
+        let standard_errors = covariance.diag().mapv(T::sqrt);
+
+        let spline = CubicSmoothingSpline::new(t, &partial)
+            .with_weights(&weights)
+            .with_smooth(smooth)
+            .make()?;
+
+        let spline = spline.spline().unwrap();
+
+        self.smooth = Some(smooth);
+        self.coefficients = Some(coefficients);
+        self.standard_errors = Some(standard_errors);
+        self.residual_df = Some(residual_df);
+        self.spline = Some(NdSpline::new(
+            spline.breaks().to_owned(),
+            spline.coeffs().to_owned(),
+        ));
+
+        Ok(())
+    }
+}
+
+/// The linear smoother of the cubic smoothing spline for the data values at the data sites
+///
+/// The smoother is `S = I - 6(1-p) W^-1 Q A^-1 Q'` where `A = 6(1-p) Q'W^-1Q + p R`,
+/// the matrix `A` is factorized once and the smoother is applied to the columns of the data
+/// without forming `S`.
+struct Smoother<T>
+where
+    T: Real<T>,
+{
+    /// `(n-2)xn` matrix `Q'` of the second divided differences
+    qt: CsMat<T>,
+
+    /// The scaled inverse weights `6(1-p) W^-1`
+    scaled_inv_weights: Array1<T>,
+
+    /// The factorized matrix `A`
+    solver: LdlSolver<T>,
+
+    /// The trace of the smoother `tr(S) = n - 6(1-p) tr(A^-1 Q'W^-1Q)`
+    trace: T,
+}
+
+impl<T> Smoother<T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    fn new(t: ArrayView1<'_, T>, weights: ArrayView1<'_, T>, smooth: T) -> Self {
+        let n = t.len();
+        let s1 = T::from(6.0).unwrap() * (T::one() - smooth);
+
+        let dx = diff(t, None);
+        let qt = second_differences(dx.view());
+        let r = continuity_matrix(dx.view());
+
+        let qtwq = weighted_gram(&qt, weights);
+
+        let solver = LdlSolver::new(&(&qtwq.map(|el| *el * s1) + &r.map(|el| *el * smooth)));
+        let trace = T::from(n).unwrap() - s1 * solver.trace_inverse_product(&qtwq);
+
+        Smoother {
+            qt,
+            scaled_inv_weights: weights.mapv(|w| s1 / w),
+            solver,
+            trace,
+        }
+    }
+
+    /// Computes `(I - S) V` for the columns of `V`
+    fn residualize(&self, values: ArrayView2<'_, T>) -> Array2<T> {
+        let qt_t = self.qt.transpose_view();
+        let mut result = Array2::<T>::zeros(values.raw_dim());
+
+        for (mut res_col, col) in result.columns_mut().into_iter().zip(values.columns()) {
+            let qtv: Array1<T> = &self.qt * &col.to_owned();
+            let u = self.solver.solve(qtv.view());
+            let qu: Array1<T> = &qt_t * &u;
+
+            res_col.assign(&(&qu * &self.scaled_inv_weights));
+        }
+
+        result
+    }
+}
@@ -0,0 +1,96 @@
+use ndarray::{ArrayView1, ArrayView2};
+
+use crate::{
+    validate::{validate_data_sites, validate_smooth_value, validate_weights_size},
+    CsapsError::InvalidInputData,
+    Real, RealRef, Result,
+};
+
+use super::PartiallyLinearModel;
+
+impl<'a, T> PartiallyLinearModel<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    pub(super) fn make_validate(&self) -> Result<()> {
+        let n = self.t.len();
+
+        if n < 2 {
+            return Err(InvalidInputData(
+                "The size of data vectors must be greater or equal to 2".to_string(),
+            ));
+        }
+
+        validate_data_sites(self.t)?;
+
+        if self.y.len() != n {
+            return Err(InvalidInputData(format!(
+                "`y` size ({}) is not equal to `t` size ({})",
+                self.y.len(),
+                n
+            )));
+        }
+
+        let (rows, cols) = self.covariates.dim();
+
+        if rows != n {
+            return Err(InvalidInputData(format!(
+                "The number of rows of covariates ({}) is not equal to `t` size ({})",
+                rows, n
+            )));
+        }
+
+        if cols == 0 || cols >= n {
+            return Err(InvalidInputData(format!(
+                "The number of covariates must be in range 1..{}, given {}",
+                n - 1,
+                cols
+            )));
+        }
+
+        validate_weights_size(self.weights, n)?;
+
+        if let Some(smooth) = self.smooth {
+            validate_smooth_value(smooth)?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn evaluate_validate(&self, ti: ArrayView1<'a, T>) -> Result<()> {
+        if ti.is_empty() {
+            return Err(InvalidInputData(
+                "The size of `ti` vector must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self.spline.is_none() {
+            return Err(InvalidInputData(
+                "The model has not been computed, use `make` method before".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn predict_validate(
+        &self,
+        ti: ArrayView1<'a, T>,
+        covariates: ArrayView2<'a, T>,
+    ) -> Result<()> {
+        self.evaluate_validate(ti)?;
+
+        let expected = (ti.len(), self.covariates.ncols());
+
+        if covariates.dim() != expected {
+            return Err(InvalidInputData(format!(
+                "The shape of covariates {:?} is not equal to the expected shape {:?}",
+                covariates.dim(),
+                expected
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use crate::Real;
+
+/// The maximum number of bisection iterations for the root of the monotone polynomial
+const MAX_ITERATIONS: usize = 200;
+
+/// Evaluates the polynomial with the coefficients of the highest power first
+pub(crate) fn evaluate<T: Real<T>>(coeffs: &[T], x: T) -> T {
+    coeffs.iter().fold(T::zero(), |acc, &c| acc * x + c)
+}
+
+/// Returns the coefficients of the derivative of the polynomial
+pub(crate) fn derivative<T: Real<T>>(coeffs: &[T]) -> Vec<T> {
+    let degree = coeffs.len().saturating_sub(1);
+
+    coeffs
+        .iter()
+        .take(degree)
+        .enumerate()
+        .map(|(k, &c)| c * T::from(degree - k).unwrap())
+        .collect()
+}
+
+/// Finds the real roots of the polynomial on the interval `[lo, hi]`
+///
+/// The coefficients are given with the highest power first. The interval is split into
+/// the monotone intervals by the roots of the derivative (recursively), the roots in the monotone
+/// intervals are found by Newton method safeguarded by bisection. The multiple roots (the local
+/// extrema touching zero within the rounding tolerance) are also returned. The roots are sorted.
+/// The zero polynomial has no isolated roots and the empty vector is returned.
+pub(crate) fn roots<T: Real<T>>(coeffs: &[T], lo: T, hi: T) -> Vec<T> {
+    // Leading zero coefficients do not change the polynomial
+    let first = coeffs
+        .iter()
+        .position(|c| !c.is_zero())
+        .unwrap_or(coeffs.len());
+    let coeffs = &coeffs[first..];
+
+    if coeffs.len() <= 1 || hi < lo {
+        return Vec::new();
+    }
+
+    if coeffs.len() == 2 {
+        let root = -coeffs[1] / coeffs[0];
+        return if root >= lo && root <= hi {
+            vec![root]
+        } else {
+            Vec::new()
+        };
+    }
+
+    let scale = lo.abs().max(hi.abs()).max(T::one());
+    let magnitude = coeffs
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(T::zero(), |acc, (k, &c)| {
+            acc + c.abs() * scale.powi(k as i32)
+        });
+    let tolerance = T::epsilon() * T::from(64.0).unwrap() * magnitude;
+
+    let dcoeffs = derivative(coeffs);
+
+    let mut bounds = vec![lo];
+    bounds.extend(
+        roots(&dcoeffs, lo, hi)
+            .into_iter()
+            .filter(|&c| c > lo && c < hi),
+    );
+    bounds.push(hi);
+
+    let mut result: Vec<T> = Vec::new();
+    let mut push = |root: T| {
+        let is_new = result
+            .last()
+            .is_none_or(|&last| (root - last).abs() > T::epsilon().sqrt() * scale);
+
+        if is_new {
+            result.push(root);
+        }
+    };
+
+    for (k, bound) in bounds.windows(2).enumerate() {
+        let (a, b) = (bound[0], bound[1]);
+        let (fa, fb) = (evaluate(coeffs, a), evaluate(coeffs, b));
+
+        if fa.abs() <= tolerance && (k == 0 || is_extremum(&dcoeffs, a)) {
+            push(a);
+        }
+
+        if fa.abs() > tolerance && fb.abs() > tolerance && fa.signum() != fb.signum() {
+            push(monotone_root(coeffs, &dcoeffs, a, b, fa));
+        }
+
+        if fb.abs() <= tolerance {
+            push(b);
+        }
+    }
+
+    result
+}
+
+/// Returns true if the point is the critical point of the polynomial (the bound of monotone intervals)
+fn is_extremum<T: Real<T>>(dcoeffs: &[T], x: T) -> bool {
+    let value = evaluate(dcoeffs, x).abs();
+    let magnitude = dcoeffs.iter().fold(T::zero(), |acc, &c| acc + c.abs());
+
+    value <= T::epsilon().sqrt() * magnitude
+}
+
+/// Finds the root of the monotone polynomial with the sign change on the interval
+fn monotone_root<T: Real<T>>(coeffs: &[T], dcoeffs: &[T], lo: T, hi: T, flo: T) -> T {
+    let (mut lo, mut hi) = (lo, hi);
+    let mut x = (lo + hi) / T::from(2.0).unwrap();
+
+    for _ in 0..MAX_ITERATIONS {
+        let fx = evaluate(coeffs, x);
+
+        if fx.is_zero() {
+            return x;
+        }
+
+        if fx.signum() == flo.signum() {
+            lo = x;
+        } else {
+            hi = x;
+        }
+
+        let step = x - fx / evaluate(dcoeffs, x);
+        let next = if step > lo && step < hi {
+            step
+        } else {
+            (lo + hi) / T::from(2.0).unwrap()
+        };
+
+        if (next - x).abs() <= T::epsilon() * x.abs().max(T::min_positive_value()) {
+            return next;
+        }
+
+        x = next;
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::polynomial;
+
+    #[test]
+    fn test_roots_cubic() {
+        // (x - 1) * (x - 2) * (x + 3) = x^3 - 7x + 6
+        let roots = polynomial::roots(&[1., 0., -7., 6.], -5., 5.);
+
+        assert_eq!(roots.len(), 3);
+        assert_abs_diff_eq!(roots[0], -3., epsilon = 1e-12);
+        assert_abs_diff_eq!(roots[1], 1., epsilon = 1e-12);
+        assert_abs_diff_eq!(roots[2], 2., epsilon = 1e-12);
+
+        let roots = polynomial::roots(&[1., 0., -7., 6.], 0., 1.5);
+        assert_eq!(roots.len(), 1);
+    }
+
+    #[test]
+    fn test_roots_multiple() {
+        // (x - 1)^2 * (x + 1) = x^3 - x^2 - x + 1
+        let roots = polynomial::roots(&[1., -1., -1., 1.], -2., 2.);
+
+        assert_eq!(roots.len(), 2);
+        assert_abs_diff_eq!(roots[0], -1., epsilon = 1e-12);
+        assert_abs_diff_eq!(roots[1], 1., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_roots_degenerate() {
+        assert!(polynomial::roots(&[0., 0., 0., 0.], 0., 1.).is_empty());
+        assert!(polynomial::roots(&[0., 0., 1., 1.], 0., 1.).is_empty());
+
+        let roots = polynomial::roots(&[0., 0., 2., -1.], 0., 1.);
+        assert_eq!(roots, vec![0.5]);
+    }
+}
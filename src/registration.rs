@@ -0,0 +1,176 @@
+mod make;
+mod validate;
+
+use ndarray::{ArrayView2, AsArray, Ix2};
+
+use crate::{NdSpline, Real, RealRef, Result};
+
+/// Registration (alignment) of the set of curves by smooth monotone time warping
+///
+/// Every dimension of the given `NdSpline` is considered as the curve, all curves share
+/// the same breaks `[a, b]`. The registration estimates the warping function `h_i` for every curve
+/// which maps the common (registered) time to the curve time, the aligned curve is `x_i(h_i(t))`.
+/// The warps are strictly increasing and keep the ends of the range: `h_i(a) = a`, `h_i(b) = b`.
+///
+/// Two methods are supported:
+///
+/// - landmark registration (see `with_landmarks` method): the warp of every curve maps
+///   the mean landmarks to the curve landmarks
+/// - continuous registration (by default): the warp of every curve is defined by the control
+///   points at the equally spaced knots (see `with_knots` method) which are estimated by
+///   minimizing the squared distance between the aligned curve and the mean of the aligned curves
+///   (Procrustes iterations). The control points are searched with the piecewise-linear warps.
+///
+/// The warps are represented as the monotone smoothing splines through the control points:
+/// the spline is computed by `CubicSmoothingSpline` with the given smoothing parameter which is
+/// decreased if needed to make the spline monotone. The aligned curves are represented as
+/// the multivariate `NdSpline` which interpolates the aligned curves on the original breaks.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::{array, Array1, Array2};
+/// use csaps::{CubicSmoothingSpline, CurveRegistration};
+///
+/// let x = Array1::linspace(0., 10., 51);
+/// let shifts = [-0.5, 0., 0.4];
+/// let y = Array2::from_shape_fn((3, 51), |(i, j)| {
+///     let t: f64 = x[j] - 5. - shifts[i];
+///     (-t * t).exp()
+/// });
+///
+/// let spline = CubicSmoothingSpline::new(&x, &y)
+///     .with_smooth(1.0)
+///     .make().unwrap();
+///
+/// let landmarks = array![[4.5], [5.0], [5.4]];
+///
+/// let registration = CurveRegistration::new(spline.spline().unwrap())
+///     .with_landmarks(&landmarks)
+///     .make().unwrap();
+///
+/// let warps = registration.warps().unwrap();
+/// let aligned = registration.registered().unwrap();
+/// ```
+///
+pub struct CurveRegistration<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// The multivariate spline, every dimension is the curve
+    spline: &'a NdSpline<'a, T>,
+
+    /// The optional `N x L` array of the landmarks of the curves
+    landmarks: Option<ArrayView2<'a, T>>,
+
+    /// The number of the interior knots of the warps for continuous registration
+    knots: usize,
+
+    /// The optional smoothing parameter of the warps
+    smooth: Option<T>,
+
+    /// The maximum number of Procrustes iterations for continuous registration
+    max_iter: usize,
+
+    /// The computed warping functions of the curves
+    warps: Option<Vec<NdSpline<'a, T>>>,
+
+    /// The aligned curves
+    registered: Option<NdSpline<'a, T>>,
+}
+
+impl<'a, T> CurveRegistration<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    /// Creates `CurveRegistration` struct for the given multivariate spline
+    ///
+    /// # Arguments
+    ///
+    /// - `spline` -- the multivariate spline, every dimension is the curve.
+    ///   The spline must have at least 2 dimensions.
+    ///
+    pub fn new(spline: &'a NdSpline<'a, T>) -> Self {
+        CurveRegistration {
+            spline,
+            landmarks: None,
+            knots: 3,
+            smooth: None,
+            max_iter: 3,
+            warps: None,
+            registered: None,
+        }
+    }
+
+    /// Sets the landmarks of the curves and switches to landmark registration
+    ///
+    /// `landmarks` is `N x L` array where `N` is the number of curves and `L` is the number of
+    /// landmarks (the times of the same features of the curves). The landmarks of every curve
+    /// must be strictly increasing and lie inside the breaks range.
+    ///
+    pub fn with_landmarks<L>(mut self, landmarks: L) -> Self
+    where
+        L: AsArray<'a, T, Ix2>,
+    {
+        self.invalidate();
+        self.landmarks = Some(landmarks.into());
+        self
+    }
+
+    /// Sets the number of the interior knots of the warps for continuous registration, 3 by default
+    pub fn with_knots(mut self, knots: usize) -> Self {
+        self.invalidate();
+        self.knots = knots;
+        self
+    }
+
+    /// Sets the smoothing parameter of the warps
+    ///
+    /// The smoothing parameter must be in range `[0, 1]`, by default it is 1.0 and the warps
+    /// interpolate the control points (if it is possible with a monotone spline).
+    ///
+    pub fn with_smooth(mut self, smooth: T) -> Self {
+        self.invalidate();
+        self.smooth = Some(smooth);
+        self
+    }
+
+    /// Sets the maximum number of Procrustes iterations for continuous registration, 3 by default
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        self.invalidate();
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Makes (computes) the warps and the aligned curves
+    ///
+    /// # Errors
+    ///
+    /// - If the data or parameters are invalid
+    ///
+    pub fn make(mut self) -> Result<Self> {
+        self.make_validate()?;
+        self.make_registration()?;
+        Ok(self)
+    }
+
+    /// Returns the warping functions of the curves or None
+    ///
+    /// The warp maps the registered time to the curve time.
+    pub fn warps(&self) -> Option<&[NdSpline<'a, T>]> {
+        self.warps.as_deref()
+    }
+
+    /// Returns the aligned curves as the multivariate spline on the original breaks or None
+    pub fn registered(&self) -> Option<&NdSpline<'a, T>> {
+        self.registered.as_ref()
+    }
+
+    /// Invalidate computed results
+    fn invalidate(&mut self) {
+        self.warps = None;
+        self.registered = None;
+    }
+}
@@ -0,0 +1,290 @@
+use ndarray::prelude::*;
+
+use crate::{CubicSmoothingSpline, NdSpline, Real, RealRef, Result};
+
+use super::CurveRegistration;
+
+/// The number of coordinate descent sweeps over the control points of the warp
+const SWEEPS: usize = 4;
+
+/// The number of golden section search iterations for every control point
+const GOLDEN_ITERATIONS: usize = 40;
+
+/// The number of bisection iterations for the smoothing parameter of the monotone warp
+const MONOTONE_ITERATIONS: usize = 30;
+
+impl<'a, T> CurveRegistration<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    pub(super) fn make_registration(&mut self) -> Result<()> {
+        let spline = self.spline;
+        let n = spline.ndim();
+        let breaks = spline.breaks();
+        let a = breaks[0];
+        let b = breaks[breaks.len() - 1];
+        let smooth = self.smooth.unwrap_or_else(T::one);
+
+        let curves: Vec<NdSpline<'_, T>> = spline
+            .coeffs()
+            .rows()
+            .into_iter()
+            .map(|row| NdSpline::new(breaks, row.to_owned().insert_axis(Axis(0))))
+            .collect();
+
+        let (knots, controls) = match self.landmarks {
+            Some(landmarks) => landmark_controls(a, b, landmarks),
+            None => self.continuous_controls(&curves, a, b),
+        };
+
+        let mut warps = Vec::with_capacity(n);
+        let mut aligned = Array2::<T>::zeros((n, breaks.len()));
+
+        for (i, curve) in curves.iter().enumerate() {
+            let warp = monotone_warp(knots.view(), controls.row(i), smooth)?;
+
+            let times = warp.evaluate(breaks).row(0).mapv(|v| v.max(a).min(b));
+            aligned
+                .row_mut(i)
+                .assign(&curve.evaluate(times.view()).row(0));
+
+            warps.push(warp);
+        }
+
+        let registered = CubicSmoothingSpline::new(breaks, &aligned)
+            .with_smooth(T::one())
+            .make()?;
+        let registered = registered.spline().unwrap();
+
+        self.warps = Some(warps);
+        self.registered = Some(NdSpline::new(
+            breaks.to_owned(),
+            registered.coeffs().to_owned(),
+        ));
+
+        Ok(())
+    }
+
+    /// Estimates the control points of the warps for continuous registration
+    ///
+    /// Returns the knots (the registered times) and `N x (K + 2)` array of the curve times.
+    fn continuous_controls(
+        &self,
+        curves: &[NdSpline<'_, T>],
+        a: T,
+        b: T,
+    ) -> (Array1<T>, Array2<T>) {
+        let n = curves.len();
+        let grid = self.spline.breaks();
+
+        let knots = Array1::linspace(a, b, self.knots + 2);
+        let mut controls = Array2::from_shape_fn((n, knots.len()), |(_, j)| knots[j]);
+
+        let aligned_curve = |curve: &NdSpline<'_, T>, controls: ArrayView1<'_, T>| {
+            let times = grid.mapv(|t| linear_warp(knots.view(), controls, t));
+            curve.evaluate(times.view()).row(0).to_owned()
+        };
+
+        for _ in 0..self.max_iter {
+            // The target is the mean of the aligned curves on the current iteration
+            let target = curves
+                .iter()
+                .zip(controls.rows())
+                .fold(Array1::<T>::zeros(grid.raw_dim()), |acc, (curve, u)| {
+                    acc + aligned_curve(curve, u)
+                })
+                / T::from(n).unwrap();
+
+            for (i, curve) in curves.iter().enumerate() {
+                let mut u = controls.row(i).to_owned();
+
+                let cost = |u: &Array1<T>| {
+                    (aligned_curve(curve, u.view()) - &target)
+                        .mapv(|d| d * d)
+                        .sum()
+                };
+
+                for _ in 0..SWEEPS {
+                    for j in 1..u.len() - 1 {
+                        let margin = (u[j + 1] - u[j - 1]) * T::from(0.01).unwrap();
+
+                        let candidate = golden_section(u[j - 1] + margin, u[j + 1] - margin, |v| {
+                            let mut w = u.clone();
+                            w[j] = v;
+                            cost(&w)
+                        });
+
+                        let mut w = u.clone();
+                        w[j] = candidate;
+
+                        if cost(&w) < cost(&u) {
+                            u = w;
+                        }
+                    }
+                }
+
+                controls.row_mut(i).assign(&u);
+            }
+        }
+
+        (knots, controls)
+    }
+}
+
+/// Returns the knots (the mean landmarks) and the control points (the curves landmarks)
+fn landmark_controls<T: Real<T>>(
+    a: T,
+    b: T,
+    landmarks: ArrayView2<'_, T>,
+) -> (Array1<T>, Array2<T>) {
+    let (n, l) = landmarks.dim();
+
+    let mut knots = Array1::from_elem((l + 2,), a);
+    knots[l + 1] = b;
+    knots
+        .slice_mut(s![1..l + 1])
+        .assign(&(landmarks.sum_axis(Axis(0)) / T::from(n).unwrap()));
+
+    let mut controls = Array2::from_elem((n, l + 2), a);
+    controls.column_mut(l + 1).fill(b);
+    controls.slice_mut(s![.., 1..l + 1]).assign(&landmarks);
+
+    (knots, controls)
+}
+
+/// Evaluates the piecewise-linear warp through the control points
+fn linear_warp<T: Real<T>>(knots: ArrayView1<'_, T>, controls: ArrayView1<'_, T>, t: T) -> T {
+    let last = knots.len() - 2;
+    let j = (0..last).find(|&j| t < knots[j + 1]).unwrap_or(last);
+
+    let ratio = (t - knots[j]) / (knots[j + 1] - knots[j]);
+    controls[j] + (controls[j + 1] - controls[j]) * ratio
+}
+
+/// Finds the minimum of the function on the interval by golden section search
+fn golden_section<T: Real<T>, F: Fn(T) -> T>(lo: T, hi: T, f: F) -> T {
+    let ratio = T::from((5f64.sqrt() - 1.) / 2.).unwrap();
+
+    let (mut lo, mut hi) = (lo, hi);
+    let mut x1 = hi - (hi - lo) * ratio;
+    let mut x2 = lo + (hi - lo) * ratio;
+    let mut f1 = f(x1);
+    let mut f2 = f(x2);
+
+    for _ in 0..GOLDEN_ITERATIONS {
+        if f1 <= f2 {
+            hi = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = hi - (hi - lo) * ratio;
+            f1 = f(x1);
+        } else {
+            lo = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = lo + (hi - lo) * ratio;
+            f2 = f(x2);
+        }
+    }
+
+    if f1 <= f2 {
+        x1
+    } else {
+        x2
+    }
+}
+
+/// Computes the monotone smoothing spline warp through the control points
+///
+/// The smoothing parameter is decreased by bisection until the spline is monotone,
+/// the spline with zero smoothing parameter is the straight line which is always monotone
+/// for increasing control points. The warp is normalized to keep the ends of the range.
+fn monotone_warp<'b, T>(
+    knots: ArrayView1<'_, T>,
+    controls: ArrayView1<'_, T>,
+    smooth: T,
+) -> Result<NdSpline<'b, T>>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    let make = |smooth: T| -> Result<NdSpline<'b, T>> {
+        let spline = CubicSmoothingSpline::new(knots, controls)
+            .with_smooth(smooth)
+            .make()?;
+
+        Ok(normalized_warp(spline.spline().unwrap()))
+    };
+
+    let warp = make(smooth)?;
+
+    if is_monotone(&warp) {
+        return Ok(warp);
+    }
+
+    let mut warp = make(T::zero())?;
+    let (mut lo, mut hi) = (T::zero(), smooth);
+
+    for _ in 0..MONOTONE_ITERATIONS {
+        let mid = (lo + hi) / T::from(2.0).unwrap();
+        let candidate = make(mid)?;
+
+        if is_monotone(&candidate) {
+            lo = mid;
+            warp = candidate;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Ok(warp)
+}
+
+/// Returns the copy of the warp which is affinely rescaled to keep the ends of the breaks range
+fn normalized_warp<'b, T: Real<T>>(spline: &NdSpline<'_, T>) -> NdSpline<'b, T> {
+    let breaks = spline.breaks();
+    let pieces = spline.pieces();
+    let order = spline.order();
+
+    let a = breaks[0];
+    let b = breaks[breaks.len() - 1];
+
+    let ends = spline.evaluate(array![a, b].view());
+    let (ha, hb) = (ends[[0, 0]], ends[[0, 1]]);
+    let scale = (b - a) / (hb - ha);
+
+    let mut coeffs = spline.coeffs().mapv(|c| c * scale);
+    coeffs
+        .slice_mut(s![.., (order - 1) * pieces..])
+        .mapv_inplace(|c| c + a - ha * scale);
+
+    NdSpline::new(breaks.to_owned(), coeffs)
+}
+
+/// Returns true if the derivative of the spline is positive on every piece
+fn is_monotone<T: Real<T>>(spline: &NdSpline<'_, T>) -> bool {
+    let breaks = spline.breaks();
+    let pieces = spline.pieces();
+
+    let (order, coeffs) = NdSpline::derivative_coeffs(spline.order(), pieces, spline.coeffs(), 1);
+
+    (0..pieces).all(|j| {
+        let poly: Vec<T> = (0..order).map(|k| coeffs[[0, k * pieces + j]]).collect();
+        let value = |s: T| poly.iter().fold(T::zero(), |acc, &c| acc * s + c);
+
+        let h = breaks[j + 1] - breaks[j];
+        let mut sites = vec![T::zero(), h];
+
+        // The vertex of the quadratic derivative
+        if order == 3 && poly[0] != T::zero() {
+            let vertex = -poly[1] / (T::from(2.0).unwrap() * poly[0]);
+
+            if vertex > T::zero() && vertex < h {
+                sites.push(vertex);
+            }
+        }
+
+        sites.into_iter().all(|s| value(s) > T::zero())
+    })
+}
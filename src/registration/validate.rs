@@ -0,0 +1,70 @@
+use crate::{validate::validate_smooth_value, CsapsError::InvalidInputData, Real, RealRef, Result};
+
+use super::CurveRegistration;
+
+impl<'a, T> CurveRegistration<'a, T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    pub(super) fn make_validate(&self) -> Result<()> {
+        let n = self.spline.ndim();
+
+        if n < 2 {
+            return Err(InvalidInputData(format!(
+                "The number of curves (spline dimensions) must be greater or equal to 2, got {}",
+                n
+            )));
+        }
+
+        if let Some(smooth) = self.smooth {
+            validate_smooth_value(smooth)?;
+        }
+
+        let breaks = self.spline.breaks();
+        let a = breaks[0];
+        let b = breaks[breaks.len() - 1];
+
+        match self.landmarks {
+            Some(landmarks) => {
+                if landmarks.nrows() != n || landmarks.ncols() == 0 {
+                    return Err(InvalidInputData(format!(
+                        "The shape of landmarks {:?} is not valid, it must be ({}, L) with L >= 1",
+                        landmarks.dim(),
+                        n
+                    )));
+                }
+
+                for (i, row) in landmarks.rows().into_iter().enumerate() {
+                    let mut prev = a;
+
+                    for &v in row.iter().chain(std::iter::once(&b)) {
+                        if v.is_nan() || v <= prev {
+                            return Err(InvalidInputData(format!(
+                                "The landmarks of the curve {} must be strictly increasing and lie inside ({:?}, {:?})",
+                                i, a, b
+                            )));
+                        }
+                        prev = v;
+                    }
+                }
+            }
+            None => {
+                if self.knots == 0 {
+                    return Err(InvalidInputData(
+                        "The number of knots must be greater or equal to 1".to_string(),
+                    ));
+                }
+
+                if self.max_iter == 0 {
+                    return Err(InvalidInputData(
+                        "The maximum number of iterations must be greater or equal to 1"
+                            .to_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use ndarray::{Array, Array1, ArrayView, ArrayView1, AsArray, Axis, Dimension};
+
+use crate::{
+    smooth_from_cutoff, validate::validate_smooth_value, CsapsError::InvalidInputData,
+    CubicSmoothingSpline, Real, RealRef, Result,
+};
+
+/// Resampler of irregularly sampled data onto the uniform grid by the cubic smoothing spline
+///
+/// The data is smoothed by `CubicSmoothingSpline` and the spline is evaluated on the uniform grid
+/// `x1, x1 + period, x1 + 2 * period, ...` inside X data range.
+///
+/// With anti-aliasing the smoothing parameter is chosen so that the spline attenuates the content
+/// above the Nyquist frequency of the new sampling rate `1 / (2 * period)`: the -3 dB cutoff of
+/// the smoothing spline (as the low-pass filter for the mean spacing of X data sites) is placed
+/// at the new Nyquist frequency. If the smoothing parameter is given or computed automatically,
+/// the smaller (the smoother) of both parameters is used. Anti-aliasing has no effect when
+/// the new sampling rate is not lower than the mean sampling rate of the data.
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use csaps::Resampler;
+///
+/// let x = array![0.0, 0.12, 0.25, 0.41, 0.48, 0.63, 0.77, 0.81, 0.95, 1.1];
+/// let y = array![0.1, 0.7, 1.0, 0.6, 0.4, -0.3, -0.8, -0.9, -0.5, 0.2];
+///
+/// let (xi, yi) = Resampler::new(&x, &y, 0.25)
+///     .with_anti_aliasing(true)
+///     .resample().unwrap();
+///
+/// assert_eq!(xi.len(), 5);
+/// assert_eq!(yi.len(), 5);
+/// ```
+///
+pub struct Resampler<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// X data sites (also breaks)
+    x: ArrayView1<'a, T>,
+
+    /// Y data values
+    y: ArrayView<'a, T, D>,
+
+    /// The sample period of the uniform grid
+    period: T,
+
+    /// The axis parameter defines axis of Y data for spline computing
+    axis: Option<Axis>,
+
+    /// The optional data weights
+    weights: Option<ArrayView1<'a, T>>,
+
+    /// The optional smoothing parameter
+    smooth: Option<T>,
+
+    /// The flag which is true if the anti-aliasing smoothing is used
+    anti_aliasing: bool,
+}
+
+impl<'a, T, D> Resampler<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// Creates `Resampler` struct from the given `X` data sites, `Y` data values and the sample period
+    ///
+    /// # Arguments
+    ///
+    /// - `x` -- the X-data sites 1-d array-like. Must strictly increasing: `x1 < x2 < x3 < ... < xN`
+    /// - `y` -- The Y-data values n-d array-like. By default the axis parameter is equal to the last axis.
+    /// - `period` -- The sample period of the uniform grid, must be positive
+    ///
+    pub fn new<X, Y>(x: X, y: Y, period: T) -> Self
+    where
+        X: AsArray<'a, T>,
+        Y: AsArray<'a, T, D>,
+    {
+        Resampler {
+            x: x.into(),
+            y: y.into(),
+            period,
+            axis: None,
+            weights: None,
+            smooth: None,
+            anti_aliasing: false,
+        }
+    }
+
+    /// Sets the axis parameter
+    ///
+    /// `y.shape()[axis]` must be equal to `x.len()`
+    ///
+    pub fn with_axis(mut self, axis: Axis) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets the weights data vector
+    ///
+    /// `weights.len()` must be equal to `x.len()`
+    ///
+    pub fn with_weights<W>(mut self, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.weights = Some(weights.into());
+        self
+    }
+
+    /// Sets the smoothing parameter
+    ///
+    /// If the smoothing parameter is not set, it will be computed automatically.
+    ///
+    pub fn with_smooth(mut self, smooth: T) -> Self {
+        self.smooth = Some(smooth);
+        self
+    }
+
+    /// Sets the anti-aliasing flag
+    ///
+    /// If true, the content above the new Nyquist frequency is attenuated. False by default.
+    ///
+    pub fn with_anti_aliasing(mut self, anti_aliasing: bool) -> Self {
+        self.anti_aliasing = anti_aliasing;
+        self
+    }
+
+    /// Resamples the data and returns the uniform grid and the resampled values
+    ///
+    /// The resampled values have the same axis layout as Y data, `yi.shape()[axis]` is equal
+    /// to `xi.len()`.
+    ///
+    /// # Errors
+    ///
+    /// - If the data or parameters are invalid
+    ///
+    pub fn resample(&self) -> Result<(Array1<T>, Array<T, D>)> {
+        self.resample_validate()?;
+
+        let first = self.x[0];
+        let last = self.x[self.x.len() - 1];
+
+        let spline = CubicSmoothingSpline::new(self.x.view(), self.y.view())
+            .with_optional_weights(self.weights)
+            .with_optional_smooth(self.smooth)
+            .with_axis(self.axis.unwrap_or(Axis(self.y.ndim() - 1)))
+            .make()?;
+
+        let smooth = spline.smooth().unwrap();
+
+        let spline = match self.anti_aliasing_smooth(first, last)? {
+            Some(aa_smooth) if aa_smooth < smooth => spline.with_smooth(aa_smooth).make()?,
+            _ => spline,
+        };
+
+        // The tolerance avoids losing the last grid site due to the rounding errors
+        let tolerance = T::from(1e-9).unwrap();
+        let count = ((last - first) / self.period + tolerance).floor();
+        let count = count.to_usize().unwrap() + 1;
+
+        let xi = Array1::from_shape_fn(count, |i| first + self.period * T::from(i).unwrap());
+        let yi = spline.evaluate(xi.view())?;
+
+        Ok((xi, yi))
+    }
+
+    /// Returns the smoothing parameter with -3 dB cutoff at the new Nyquist frequency or None
+    fn anti_aliasing_smooth(&self, first: T, last: T) -> Result<Option<T>> {
+        if !self.anti_aliasing {
+            return Ok(None);
+        }
+
+        let two = T::from(2.0).unwrap();
+        let spacing = (last - first) / T::from(self.x.len() - 1).unwrap();
+
+        if self.period <= spacing {
+            return Ok(None);
+        }
+
+        let smooth = smooth_from_cutoff((two * self.period).recip(), spacing)?;
+
+        Ok(Some(smooth))
+    }
+
+    fn resample_validate(&self) -> Result<()> {
+        if self.period <= T::zero() || !self.period.is_finite() {
+            return Err(InvalidInputData(format!(
+                "The sample period must be positive, given {:?}",
+                self.period
+            )));
+        }
+
+        if self.x.len() < 2 {
+            return Err(InvalidInputData(
+                "The size of data vectors must be greater or equal to 2".to_string(),
+            ));
+        }
+
+        if let Some(smooth) = self.smooth {
+            validate_smooth_value(smooth)?;
+        }
+
+        Ok(())
+    }
+}
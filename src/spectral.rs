@@ -0,0 +1,136 @@
+use ndarray::{Array1, AsArray, Ix1};
+
+use crate::{validate::validate_smooth_value, CsapsError::InvalidInputData, Real, Result};
+
+/// Computes the frequency response symbol of the roughness penalty of the smoothing spline
+///
+/// The symbol of `Q R^-1 Q'` operator for the uniform data sites with the `spacing`
+/// at the normalized angular frequency `theta = 2 * pi * frequency * spacing`.
+fn penalty_symbol<T: Real<T>>(theta: T, spacing: T) -> T {
+    let two = T::from(2.0).unwrap();
+    let c48 = T::from(48.0).unwrap();
+
+    let s = (theta / two).sin();
+    let s2 = s * s;
+
+    c48 * s2 * s2 / (spacing.powi(3) * (two + theta.cos()))
+}
+
+/// Returns the normalized angular frequency for the frequency in cycles per X unit
+fn angular_frequency<T: Real<T>>(frequency: T, spacing: T) -> T {
+    let two = T::from(2.0).unwrap();
+    let pi = T::from(std::f64::consts::PI).unwrap();
+
+    two * pi * frequency * spacing
+}
+
+fn validate_spacing<T: Real<T>>(spacing: T) -> Result<()> {
+    if spacing <= T::zero() || !spacing.is_finite() {
+        return Err(InvalidInputData(format!(
+            "The sampling interval must be positive, given {:?}",
+            spacing
+        )));
+    }
+
+    Ok(())
+}
+
+/// Computes the smoothing parameter of the cubic smoothing spline with the given -3 dB cutoff frequency
+///
+/// For uniformly sampled data with unit weights the cubic smoothing spline is the linear low-pass
+/// filter (see `frequency_response`). The function returns the smoothing parameter for which
+/// the response of the filter is equal to `1 / sqrt(2)` (-3 dB) at the cutoff frequency.
+/// The returned parameter can be used with `CubicSmoothingSpline::with_smooth` method
+/// (also see `CubicSmoothingSpline::with_cutoff`).
+///
+/// # Arguments
+///
+/// - `cutoff` -- The cutoff frequency in cycles per X unit, must be in range `(0, 1 / (2 * spacing)]`
+///   (up to the Nyquist frequency)
+/// - `spacing` -- The sampling interval (the spacing of X data sites)
+///
+/// # Errors
+///
+/// - If the sampling interval is not positive
+/// - If the cutoff frequency is out of the range
+///
+/// # Example
+///
+/// ```
+/// use csaps::{frequency_response, smooth_from_cutoff};
+///
+/// // 100 Hz sampling rate and 5 Hz cutoff
+/// let smooth = smooth_from_cutoff(5.0, 0.01).unwrap();
+/// let response = frequency_response(smooth, 0.01, &[5.0]).unwrap();
+///
+/// assert!((response[0] - 0.5f64.sqrt()).abs() < 1e-10);
+/// ```
+///
+pub fn smooth_from_cutoff<T: Real<T>>(cutoff: T, spacing: T) -> Result<T> {
+    validate_spacing(spacing)?;
+
+    let two = T::from(2.0).unwrap();
+    let nyquist = (two * spacing).recip();
+
+    if cutoff <= T::zero() || cutoff > nyquist * (T::one() + T::epsilon()) {
+        return Err(InvalidInputData(format!(
+            "The cutoff frequency must be in range (0, {:?}] (up to the Nyquist frequency), given {:?}",
+            nyquist, cutoff
+        )));
+    }
+
+    let theta = angular_frequency(cutoff, spacing);
+
+    // H(theta) = 1 / (1 + lambda * S(theta)) = 1 / sqrt(2), lambda = (1 - p) / p
+    let lambda = (two.sqrt() - T::one()) / penalty_symbol(theta, spacing);
+
+    Ok(T::one() / (T::one() + lambda))
+}
+
+/// Computes the frequency response of the cubic smoothing spline for uniformly sampled data
+///
+/// For uniformly sampled data (infinite or long enough series far from the ends) with unit
+/// weights the cubic smoothing spline values at the data sites are the output of the linear
+/// low-pass filter with the real-valued response
+///
+/// ```text
+/// H(f) = p / (p + (1 - p) * 48 * sin(theta / 2)^4 / (h^3 * (2 + cos(theta))))
+/// ```
+///
+/// where `p` is the smoothing parameter, `h` is the sampling interval and `theta = 2 * pi * f * h`.
+/// The smoothing parameter has the same meaning as in `CubicSmoothingSpline::with_smooth` method.
+///
+/// # Arguments
+///
+/// - `smooth` -- The smoothing parameter in range `[0, 1]`
+/// - `spacing` -- The sampling interval (the spacing of X data sites)
+/// - `frequencies` -- The frequencies in cycles per X unit
+///
+/// # Errors
+///
+/// - If the smoothing parameter is out of the range
+/// - If the sampling interval is not positive
+///
+pub fn frequency_response<'a, T, F>(smooth: T, spacing: T, frequencies: F) -> Result<Array1<T>>
+where
+    T: Real<T>,
+    F: AsArray<'a, T, Ix1>,
+{
+    validate_smooth_value(smooth)?;
+    validate_spacing(spacing)?;
+
+    let frequencies = frequencies.into();
+
+    let response = frequencies.mapv(|frequency| {
+        let symbol = penalty_symbol(angular_frequency(frequency, spacing), spacing);
+
+        // The straight line (and the constant) passes through the filter with any smoothing
+        if symbol.is_zero() {
+            T::one()
+        } else {
+            smooth / (smooth + (T::one() - smooth) * symbol)
+        }
+    });
+
+    Ok(response)
+}
@@ -159,12 +159,112 @@ where
     x
 }
 
+/// LDL factorization of symmetric CSR matrix A for solving Ax = b for many right-hand sides
+///
+/// The matrix is factorized once and can be used for solving the systems repeatedly,
+/// in iterative algorithms for example.
+///
+pub struct LdlSolver<T>
+where
+    T: Real<T>,
+{
+    ldl: LdlNumeric<T, usize>,
+
+    /// The size of the system (the factorized matrix can be padded)
+    size: usize,
+}
+
+impl<T> LdlSolver<T>
+where
+    T: Real<T>,
+{
+    /// Factorizes the given symmetric CSR matrix
+    ///
+    /// 1x1 matrix is not supported by sprs-ldl, it is padded to 2x2 block-diagonal matrix
+    /// with the unit entry.
+    pub fn new(a: &CsMat<T>) -> Self {
+        let size = a.rows();
+
+        let ldl = if size == 1 {
+            let a00 = a.get(0, 0).copied().unwrap_or_else(T::zero);
+            let padded = diags(array![[a00, T::one()]], &[0], (2, 2));
+
+            LdlNumeric::new(padded.view()).unwrap()
+        } else {
+            LdlNumeric::new(a.view()).unwrap()
+        };
+
+        LdlSolver { ldl, size }
+    }
+
+    /// Solves linear system Ax = b for the dense vector b
+    pub fn solve(&self, b: ArrayView1<T>) -> Array1<T> {
+        let mut b_vec = Vec::from_iter(b.iter().cloned());
+        b_vec.resize(self.ldl.problem_size(), T::zero());
+
+        let mut x = self.ldl.solve(&b_vec);
+        x.truncate(self.size);
+
+        Array1::from(x)
+    }
+
+    /// Computes the trace `tr(A^-1 M)` without forming the inverse of the banded matrix A
+    ///
+    /// The entries of the inverse `Z` within the band of the factor L are computed by Takahashi
+    /// recurrences `Z = D^-1 L^-1 + (I - L') Z` from the last row. The non-zero entries of M must be
+    /// within the band.
+    pub fn trace_inverse_product(&self, m: &CsMat<T>) -> T {
+        let n = self.ldl.problem_size();
+        let l = self.ldl.l();
+        let d = self.ldl.d();
+
+        let band = l
+            .outer_iterator()
+            .enumerate()
+            .filter_map(|(j, col)| col.indices().iter().max().map(|&i| i - j))
+            .max()
+            .unwrap_or(0);
+
+        // `z[[i, k]]` is the entry `(i, i + k)` of the symmetric inverse
+        let mut z = Array2::<T>::zeros((n, band + 1));
+        let at = |z: &Array2<T>, i: usize, j: usize| z[[i.min(j), i.abs_diff(j)]];
+
+        for i in (0..n).rev() {
+            let col = l.outer_view(i).unwrap();
+
+            for j in i + 1..n.min(i + band + 1) {
+                z[[i, j - i]] = col
+                    .iter()
+                    .fold(T::zero(), |acc, (k, &lki)| acc - lki * at(&z, k, j));
+            }
+
+            z[[i, 0]] = col
+                .iter()
+                .fold(T::one() / d[i], |acc, (k, &lki)| acc - lki * at(&z, i, k));
+        }
+
+        m.iter().fold(T::zero(), |acc, (&v, (i, j))| {
+            assert!(i.abs_diff(j) <= band, "The entry of M is out of the band");
+            acc + at(&z, i, j) * v
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use approx::assert_abs_diff_eq;
     use ndarray::array;
     use sprs::Shape;
 
-    use crate::sprsext;
+    use crate::{linalg, sprsext};
+
+    #[test]
+    fn test_ldl_solver_1x1() {
+        let a = sprsext::diags(array![[4.]], &[0], (1, 1));
+        let solver = sprsext::LdlSolver::new(&a);
+
+        assert_eq!(solver.solve(array![2.].view()), array![0.5]);
+    }
 
     #[test]
     fn test_diags_1() {
@@ -352,6 +452,36 @@ mod tests {
         assert_eq!(sprsext::diagonal(&m_csr, k), array![1., 2.]);
         assert_eq!(sprsext::diagonal(&m_csc, k), array![1., 2.]);
     }
+
+    #[test]
+    fn test_trace_inverse_product() {
+        let a = sprsext::diags(
+            array![
+                [1., 1., 1., 0., 0.],
+                [5., 6., 7., 6., 5.],
+                [0., 0., 1., 1., 1.],
+                [2., 1., 2., 1., 0.],
+                [0., 2., 1., 2., 1.]
+            ],
+            &[-2, 0, 2, -1, 1],
+            (5, 5),
+        );
+        let m = sprsext::diags(
+            array![[1., 2., 3., 4., 0.], [4., 3., 2., 1., 5.]],
+            &[-1, 0],
+            (5, 5),
+        );
+
+        let expected = linalg::inverse(a.to_dense().view())
+            .unwrap()
+            .dot(&m.to_dense())
+            .diag()
+            .sum();
+
+        let solver = sprsext::LdlSolver::new(&a);
+
+        assert_abs_diff_eq!(solver.trace_inverse_product(&m), expected, epsilon = 1e-12);
+    }
 }
 
 // use std::ops::{Mul, Deref};
@@ -0,0 +1,327 @@
+mod evaluate;
+mod make;
+mod validate;
+
+use ndarray::{Array, Array2, ArrayView, ArrayView1, ArrayView2, AsArray, Axis, Dimension};
+
+use crate::{Real, RealRef, Result};
+
+/// N-dimensional (univariate/multivariate) spline under tension representation
+///
+/// `NdTensionSpline` represents the splines under tension as the set of the values and
+/// the second derivatives at the breaks for every dimension. On every piece the spline is
+/// a linear combination of `1`, `x`, `sinh(tension * x)` and `cosh(tension * x)`.
+///
+/// The spline is extrapolated linearly outside the breaks because the second derivative
+/// of the spline is equal to zero at the end breaks.
+///
+/// Also `evaluate` method is implemented for `NdTensionSpline` for evaluating the data values
+/// for the given data sites.
+///
+#[derive(Debug)]
+pub struct NdTensionSpline<'a, T>
+where
+    T: Real<T>,
+{
+    /// The spline dimensionality
+    ndim: usize,
+
+    /// The number of pieces of the spline
+    pieces: usize,
+
+    /// The tension parameter
+    tension: T,
+
+    /// The breaks (data sites) which have been used for computing spline
+    breaks: ArrayView1<'a, T>,
+
+    /// `NxM` array of spline values at the breaks where `N` is `ndim` and `M` is the number of breaks
+    values: Array2<T>,
+
+    /// `NxM` array of spline second derivatives at the breaks
+    second_derivs: Array2<T>,
+}
+
+impl<'a, T> NdTensionSpline<'a, T>
+where
+    T: Real<T>,
+{
+    /// Creates `NdTensionSpline` struct from given `breaks`, `values` and `second_derivs`
+    ///
+    /// # Arguments
+    ///
+    /// - `breaks` -- The breaks (data sites) which have been used for computing spline
+    /// - `tension` -- The tension parameter
+    /// - `values` -- The NxM array of the spline values at the breaks
+    /// - `second_derivs` -- The NxM array of the spline second derivatives at the breaks
+    ///
+    /// # Notes
+    ///
+    /// - `NdTensionSpline` struct should not be created directly by a user in most cases.
+    ///
+    pub fn new(
+        breaks: ArrayView1<'a, T>,
+        tension: T,
+        values: Array2<T>,
+        second_derivs: Array2<T>,
+    ) -> NdTensionSpline<'a, T> {
+        NdTensionSpline {
+            ndim: values.nrows(),
+            pieces: breaks.len() - 1,
+            tension,
+            breaks,
+            values,
+            second_derivs,
+        }
+    }
+
+    /// Returns the spline dimensionality
+    pub fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    /// Returns the number of pieces of the spline
+    pub fn pieces(&self) -> usize {
+        self.pieces
+    }
+
+    /// Returns the tension parameter
+    pub fn tension(&self) -> T {
+        self.tension
+    }
+
+    /// Returns the view to the breaks array
+    pub fn breaks(&self) -> ArrayView1<'_, T> {
+        self.breaks.view()
+    }
+
+    /// Returns the view to the spline values at the breaks
+    pub fn values(&self) -> ArrayView2<'_, T> {
+        self.values.view()
+    }
+
+    /// Returns the view to the spline second derivatives at the breaks
+    pub fn second_derivs(&self) -> ArrayView2<'_, T> {
+        self.second_derivs.view()
+    }
+
+    /// Evaluates the spline on the given data sites
+    pub fn evaluate(&self, xi: ArrayView1<'_, T>) -> Array2<T> {
+        self.evaluate_spline(xi)
+    }
+}
+
+/// N-dimensional (univariate/multivariate) smoothing spline under tension calculator/evaluator
+///
+/// The smoothing spline under tension minimizes
+///
+/// ```text
+/// p * sum(w * (y - f(x))^2) + (1 - p) / c * integral(f''(t)^2 + tension^2 * f'(t)^2 dt)
+/// ```
+///
+/// The additional penalty of the first derivative moves the spline from the cubic smoothing
+/// spline (`tension` is equal to 0) toward the piecewise-linear fit (`tension * dx >> 1`).
+/// The tension is measured in units of `1 / x`.
+///
+/// The penalty is normalized by the factor `c` (equal to 1 for zero tension): the ratio of
+/// the trace-based scales of the penalty with the given tension and without it. Thus the tension
+/// changes the shape of the fit and not the overall level of the smoothing, and the automatic
+/// smoothing parameter is the same as for `CubicSmoothingSpline`.
+///
+/// The methods API of `TensionSmoothingSpline` is implemented as builder-like pattern
+/// (also as `CubicSmoothingSpline` struct).
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use csaps::TensionSmoothingSpline;
+///
+/// let x = array![1., 2., 3., 4., 5.];
+/// let y = array![0.5, 4.2, 0.3, 3.8, 0.6];
+///
+/// let ys = TensionSmoothingSpline::new(&x, &y)
+///     .with_tension(5.0)
+///     .with_smooth(1.0)
+///     .make().unwrap()
+///     .evaluate(&x).unwrap();
+/// ```
+///
+pub struct TensionSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// X data sites (also breaks)
+    x: ArrayView1<'a, T>,
+
+    /// Y data values
+    y: ArrayView<'a, T, D>,
+
+    /// The axis parameter defines axis of Y data for spline computing
+    axis: Option<Axis>,
+
+    /// The optional data weights
+    weights: Option<ArrayView1<'a, T>>,
+
+    /// The optional smoothing parameter
+    smooth: Option<T>,
+
+    /// The tension parameter
+    tension: T,
+
+    /// `NdTensionSpline` struct with computed spline
+    spline: Option<NdTensionSpline<'a, T>>,
+}
+
+impl<'a, T, D> TensionSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// Creates `TensionSmoothingSpline` struct from the given `X` data sites and `Y` data values
+    ///
+    /// # Arguments
+    ///
+    /// - `x` -- the X-data sites 1-d array-like. Must strictly increasing: `x1 < x2 < x3 < ... < xN`
+    /// - `y` -- The Y-data values n-d array-like. By default the axis parameter is equal to
+    ///   the last axis of Y data.
+    ///
+    pub fn new<X, Y>(x: X, y: Y) -> Self
+    where
+        X: AsArray<'a, T>,
+        Y: AsArray<'a, T, D>,
+    {
+        TensionSmoothingSpline {
+            x: x.into(),
+            y: y.into(),
+            axis: None,
+            weights: None,
+            smooth: None,
+            tension: T::zero(),
+            spline: None,
+        }
+    }
+
+    /// Sets the axis parameter
+    ///
+    /// `y.shape()[axis]` must be equal to `x.len()`
+    ///
+    pub fn with_axis(mut self, axis: Axis) -> Self {
+        self.invalidate();
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets the weights data vector
+    ///
+    /// `weights.len()` must be equal to `x.len()`
+    ///
+    pub fn with_weights<W>(mut self, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.weights = Some(weights.into());
+        self
+    }
+
+    /// Sets the smoothing parameter
+    ///
+    /// The smoothing parameter should be in range `[0, 1]`,
+    /// where bounds are:
+    ///
+    ///  - 0: The least-squares straight line fit (constant fit if the tension is positive)
+    ///  - 1: The interpolating spline under tension
+    ///
+    pub fn with_smooth(mut self, smooth: T) -> Self {
+        self.invalidate();
+        self.smooth = Some(smooth);
+        self
+    }
+
+    /// Sets the tension parameter, 0 by default (the cubic smoothing spline)
+    ///
+    /// The tension must be non-negative. The higher tension moves the fit toward
+    /// the piecewise-linear fit for the same smoothing parameter.
+    ///
+    pub fn with_tension(mut self, tension: T) -> Self {
+        self.invalidate();
+        self.tension = tension;
+        self
+    }
+
+    /// Makes (computes) the spline for given data and parameters
+    ///
+    /// # Errors
+    ///
+    /// - If the data or parameters are invalid
+    /// - If reshaping Y data to 2-d view has failed
+    ///
+    pub fn make(mut self) -> Result<Self> {
+        self.make_validate()?;
+        self.make_spline()?;
+        Ok(self)
+    }
+
+    /// Evaluates the computed spline on the given data sites
+    ///
+    /// # Errors
+    ///
+    /// - If the `xi` data is invalid
+    /// - If the spline yet has not been computed
+    ///
+    pub fn evaluate<X>(&self, xi: X) -> Result<Array<T, D>>
+    where
+        X: AsArray<'a, T>,
+    {
+        let xi = xi.into();
+        self.evaluate_validate(xi)?;
+
+        let yi = self.evaluate_spline(xi)?;
+        Ok(yi)
+    }
+
+    /// Returns the smoothing parameter or None
+    pub fn smooth(&self) -> Option<T> {
+        self.smooth
+    }
+
+    /// Returns the tension parameter
+    pub fn tension(&self) -> T {
+        self.tension
+    }
+
+    /// Returns the ref to `NdTensionSpline` struct with data of computed spline or None
+    pub fn spline(&self) -> Option<&NdTensionSpline<'a, T>> {
+        self.spline.as_ref()
+    }
+
+    /// Invalidate computed spline
+    fn invalidate(&mut self) {
+        self.spline = None;
+    }
+}
+
+/// Returns the coefficients `(a, b)` of the C1 continuity equations for the piece with the length `h`
+///
+/// The coefficients tend to `(h/6, h/3)` (the cubic spline case) when `tension * h` tends to 0.
+pub(crate) fn tension_coeffs<T: Real<T>>(h: T, tension: T) -> (T, T) {
+    let z = tension * h;
+    let c = |v: f64| T::from(v).unwrap();
+
+    if z < c(0.02) {
+        let z2 = z * z;
+        let z4 = z2 * z2;
+        let a = h * (c(1. / 6.) - c(7. / 360.) * z2 + c(31. / 15120.) * z4);
+        let b = h * (c(1. / 3.) - z2 / c(45.) + c(2. / 945.) * z4);
+        (a, b)
+    } else {
+        let z2 = z * z;
+        let a = h * (T::one() / z2 - T::one() / (z * z.sinh()));
+        let b = h * (T::one() / (z * z.tanh()) - T::one() / z2);
+        (a, b)
+    }
+}
@@ -0,0 +1,107 @@
+use ndarray::{concatenate, prelude::*, s};
+
+use crate::{
+    ndarrayext::{digitize, reshape_evaluated},
+    Real, RealRef, Result,
+};
+
+use super::{tension_coeffs, NdTensionSpline, TensionSmoothingSpline};
+
+impl<'a, T> NdTensionSpline<'a, T>
+where
+    T: Real<T>,
+{
+    /// Implements evaluating the spline on the given mesh of Xi-sites
+    pub(super) fn evaluate_spline(&self, xi: ArrayView1<'_, T>) -> Array2<T> {
+        let breaks = self.breaks;
+        let tension = self.tension;
+        let last = self.pieces;
+
+        let edges = {
+            let mesh = breaks.slice(s![1..breaks.len() - 1]);
+            let one = Array1::<T>::ones((1,));
+            let left_bound = &one * T::neg_infinity();
+            let right_bound = &one * T::infinity();
+
+            concatenate![Axis(0), left_bound, mesh, right_bound]
+        };
+
+        let indices = digitize(xi, &edges);
+
+        let mut values = Array2::<T>::zeros((self.ndim, xi.len()));
+
+        for (col, (&x, &i)) in xi.iter().zip(indices.iter()).enumerate() {
+            let h = breaks[i + 1] - breaks[i];
+            let g0 = self.values.column(i);
+            let g1 = self.values.column(i + 1);
+            let u0 = self.second_derivs.column(i);
+            let u1 = self.second_derivs.column(i + 1);
+
+            let mut v = values.column_mut(col);
+
+            if x < breaks[0] || x > breaks[last] {
+                // Linear extrapolation by the end slopes, the second derivative is zero at the ends
+                let (a, _) = tension_coeffs(h, tension);
+
+                let (x0, g, slope) = if x < breaks[0] {
+                    (breaks[0], g0, (&g1 - &g0) / h - &u1 * a)
+                } else {
+                    (breaks[last], g1, (&g1 - &g0) / h + &u0 * a)
+                };
+
+                v.assign(&(&g + &(slope * (x - x0))));
+            } else {
+                let t = x - breaks[i];
+                let s = t / h;
+
+                let p0 = tension_basis(h - t, h, tension);
+                let p1 = tension_basis(t, h, tension);
+
+                v.assign(&(&g0 * (T::one() - s) + &g1 * s + &u0 * p0 + &u1 * p1));
+            }
+        }
+
+        values
+    }
+}
+
+impl<'a, T, D> TensionSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn evaluate_spline(&self, xi: ArrayView1<'a, T>) -> Result<Array<T, D>> {
+        let yi_2d = self.spline.as_ref().unwrap().evaluate(xi);
+        reshape_evaluated(yi_2d, self.y.view(), self.axis.unwrap())
+    }
+}
+
+/// Computes the basis function `(sinh(tension * u) / sinh(tension * h) - u / h) / tension^2`
+///
+/// The function is the contribution of the unit second derivative at the break on the distance
+/// `h - u` from `x`, it tends to the cubic `u (u^2 - h^2) / (6 h)` when the tension tends to 0.
+fn tension_basis<T: Real<T>>(u: T, h: T, tension: T) -> T {
+    let c = |v: f64| T::from(v).unwrap();
+    let z = tension * h;
+    let s = u / h;
+
+    if z < c(0.02) {
+        let u2 = u * u;
+        let h2 = h * h;
+        let t2 = tension * tension;
+
+        s * ((u2 - h2) / c(6.)
+            + t2 * (u2 * u2 / c(120.) - u2 * h2 / c(36.) + c(7. / 360.) * h2 * h2))
+    } else {
+        let ratio = if z > c(20.) {
+            let two = c(2.);
+            (tension * (u - h)).exp() * (T::one() - (-two * tension * u).exp())
+                / (T::one() - (-two * z).exp())
+        } else {
+            (tension * u).sinh() / z.sinh()
+        };
+
+        (ratio - s) / (tension * tension)
+    }
+}
@@ -0,0 +1,231 @@
+use ndarray::{concatenate, prelude::*, s, Zip};
+use sprs::TriMat;
+
+use crate::{
+    ndarrayext::{diff, to_2d},
+    sprsext, Real, RealRef, Result,
+};
+
+use super::{tension_coeffs, NdTensionSpline, TensionSmoothingSpline};
+
+impl<'a, T, D> TensionSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn make_spline(&mut self) -> Result<()> {
+        let one = T::one();
+        let zero = T::zero();
+
+        let breaks = self.x;
+        let tension = self.tension;
+
+        let weights_default = Array1::ones(breaks.raw_dim());
+        let weights = self
+            .weights
+            .map(|v| v.reborrow())
+            .unwrap_or_else(|| weights_default.view());
+
+        let axis = self.axis.unwrap_or_else(|| Axis(self.y.ndim() - 1));
+        self.axis = Some(axis);
+
+        let y = to_2d(self.y.view(), axis)?;
+
+        let pcount = breaks.len();
+        let icount = pcount - 2;
+
+        let dx = diff(breaks.view(), None);
+        let odx = dx.mapv(|h| one / h);
+
+        let (ca, cb): (Vec<T>, Vec<T>) = dx.iter().map(|&h| tension_coeffs(h, tension)).unzip();
+        let ca = Array1::from(ca);
+        let cb = Array1::from(cb);
+
+        // The tension analog of `r` matrix: (n-2)x(n-2) tridiagonal matrix of C1 continuity equations
+        let r = {
+            let r_body = (&cb.slice(s![..-1]) + &cb.slice(s![1..])).insert_axis(Axis(0));
+            let r_tail = ca.slice(s![1..]).insert_axis(Axis(0)).into_owned();
+            let r_head = ca.slice(s![..-1]).insert_axis(Axis(0)).into_owned();
+            let diags_r = concatenate![Axis(0), r_tail, r_body, r_head];
+
+            sprsext::diags(diags_r, &[-1, 0, 1], (icount, icount))
+        };
+
+        // Q' (n-2)xn matrix of the second divided differences
+        let qt_entries = |k: usize| {
+            [
+                (k, odx[k]),
+                (k + 1, -(odx[k] + odx[k + 1])),
+                (k + 2, odx[k + 1]),
+            ]
+        };
+
+        let trace_qtwq = (0..icount)
+            .flat_map(qt_entries)
+            .fold(zero, |acc, (j, q)| acc + q * q / weights[j]);
+
+        // The trace of `R` without the tension: the cubic spline coefficients `h/3` on the diagonal
+        let trace_r0 = (0..icount).fold(zero, |acc, k| {
+            acc + (dx[k] + dx[k + 1]) / T::from(3.0).unwrap()
+        });
+
+        let auto_smooth = || {
+            if pcount == 2 {
+                return one;
+            }
+
+            one / (one + trace_r0 / trace_qtwq)
+        };
+
+        let smooth = self.smooth.unwrap_or_else(auto_smooth);
+
+        // The normalization of the penalty: the ratio of the trace-based scales of the penalty
+        // of the values `tension^2 * T + Q R^-1 Q'` with the given tension and without it
+        let norm = if tension > zero && icount > 0 {
+            let trace_r = sprsext::diagonal(&r, 0).sum();
+            let trace_tw = (0..pcount).fold(zero, |acc, j| {
+                let left = if j > 0 { odx[j - 1] } else { zero };
+                let right = if j < pcount - 1 { odx[j] } else { zero };
+                acc + (left + right) / weights[j]
+            });
+            let icount_t = T::from(icount).unwrap();
+
+            let level = trace_qtwq / trace_r + tension * tension * trace_tw / icount_t;
+            let level0 = trace_qtwq / trace_r0;
+
+            level / level0
+        } else {
+            one
+        };
+
+        // `values` is NxM array where N is the number of breaks and M is the data dimensionality
+        let (values, d2) = if smooth == zero {
+            (fit_null_space(breaks, y, weights, tension), None)
+        } else if smooth == one {
+            let values = y.t().to_owned();
+
+            let d2 = if icount > 0 {
+                let mut qty = Array2::<T>::zeros((icount, y.nrows()));
+
+                for k in 0..icount {
+                    for (j, q) in qt_entries(k) {
+                        qty.row_mut(k).scaled_add(q, &values.row(j));
+                    }
+                }
+
+                Some(sprsext::solve(&r, &qty))
+            } else {
+                None
+            };
+
+            (values, d2)
+        } else {
+            // Solve the quasi-definite system for the values `g` and the second derivatives `u`:
+            //
+            //   | W + lam * tension^2 * T   Q        | | g       |   | W y |
+            //   | Q'                       -R / lam  | | lam * u | = | 0   |
+            //
+            // where `T` is the matrix of the first derivative penalty and `lam = (1 - p) / (p * c)`
+            let lam = (one - smooth) / (smooth * norm);
+            let lt2 = lam * tension * tension;
+            let size = pcount + icount;
+
+            let mut a = TriMat::<T>::new((size, size));
+
+            for j in 0..pcount {
+                let mut diag = weights[j];
+
+                if j > 0 {
+                    diag += lt2 * odx[j - 1];
+                }
+                if j < pcount - 1 {
+                    diag += lt2 * odx[j];
+                    a.add_triplet(j, j + 1, -lt2 * odx[j]);
+                    a.add_triplet(j + 1, j, -lt2 * odx[j]);
+                }
+
+                a.add_triplet(j, j, diag);
+            }
+
+            for k in 0..icount {
+                for (j, q) in qt_entries(k) {
+                    a.add_triplet(j, pcount + k, q);
+                    a.add_triplet(pcount + k, j, q);
+                }
+
+                a.add_triplet(pcount + k, pcount + k, -(cb[k] + cb[k + 1]) / lam);
+
+                if k + 1 < icount {
+                    a.add_triplet(pcount + k, pcount + k + 1, -ca[k + 1] / lam);
+                    a.add_triplet(pcount + k + 1, pcount + k, -ca[k + 1] / lam);
+                }
+            }
+
+            let mut b = Array2::<T>::zeros((size, y.nrows()));
+            Zip::from(b.slice_mut(s![..pcount, ..]).rows_mut())
+                .and(y.columns())
+                .and(&weights)
+                .for_each(|mut b_row, y_col, &w| b_row.assign(&(&y_col * w)));
+
+            let sol = sprsext::solve(&a.to_csr(), &b);
+
+            let values = sol.slice(s![..pcount, ..]).to_owned();
+            let d2 = if icount > 0 {
+                Some(sol.slice(s![pcount.., ..]).mapv(|v| v / lam))
+            } else {
+                None
+            };
+
+            (values, d2)
+        };
+
+        let mut second_derivs = Array2::<T>::zeros((y.nrows(), pcount));
+
+        if let Some(d2) = d2 {
+            second_derivs
+                .slice_mut(s![.., 1..pcount - 1])
+                .assign(&d2.t());
+        }
+
+        self.smooth = Some(smooth);
+        self.spline = Some(NdTensionSpline::new(
+            breaks,
+            tension,
+            values.t().to_owned(),
+            second_derivs,
+        ));
+
+        Ok(())
+    }
+}
+
+/// Computes the fit in the null space of the penalty (the smoothing parameter is equal to 0)
+///
+/// The null space contains the constants if the tension is positive and the straight lines otherwise.
+fn fit_null_space<T: Real<T>>(
+    x: ArrayView1<'_, T>,
+    y: ArrayView2<'_, T>,
+    weights: ArrayView1<'_, T>,
+    tension: T,
+) -> Array2<T> {
+    let wsum = weights.sum();
+    let xm = x.dot(&weights) / wsum;
+    let xc = x.mapv(|v| v - xm);
+    let sxx = (&xc * &xc).dot(&weights);
+
+    let mut values = Array2::<T>::zeros((x.len(), y.nrows()));
+
+    for (mut col, y_row) in values.columns_mut().into_iter().zip(y.rows()) {
+        let ym = y_row.dot(&weights) / wsum;
+
+        if tension > T::zero() {
+            col.fill(ym);
+        } else {
+            let slope = (&xc * &y_row).dot(&weights) / sxx;
+            col.assign(&xc.mapv(|v| ym + slope * v));
+        }
+    }
+
+    values
+}
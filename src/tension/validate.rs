@@ -0,0 +1,50 @@
+use ndarray::{ArrayView1, Dimension};
+
+use crate::{
+    validate::{validate_smooth_value, validate_weights_size, validate_xy_data},
+    CsapsError::InvalidInputData,
+    Real, RealRef, Result,
+};
+
+use super::TensionSmoothingSpline;
+
+impl<'a, T, D> TensionSmoothingSpline<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn make_validate(&self) -> Result<()> {
+        validate_xy_data(self.x, self.y.view(), self.axis)?;
+        validate_weights_size(self.weights, self.x.len())?;
+
+        if let Some(smooth) = self.smooth {
+            validate_smooth_value(smooth)?;
+        }
+
+        if !(self.tension >= T::zero() && self.tension.is_finite()) {
+            return Err(InvalidInputData(format!(
+                "`tension` value must be non-negative and finite, given {:?}",
+                self.tension
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn evaluate_validate(&self, xi: ArrayView1<'a, T>) -> Result<()> {
+        if xi.is_empty() {
+            return Err(InvalidInputData(
+                "The size of `xi` vector must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self.spline.is_none() {
+            return Err(InvalidInputData(
+                "The spline has not been computed, use `make` method before".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
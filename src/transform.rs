@@ -0,0 +1,137 @@
+use std::{fmt, sync::Arc};
+
+use crate::Real;
+
+/// The function type of custom transforms
+type TransformFn<T> = Arc<dyn Fn(T) -> T + Send + Sync>;
+
+/// The data transform for computing the spline in transformed space
+///
+/// The transform is applied to X data sites or Y data values before computing the spline
+/// (see `CubicSmoothingSpline::with_x_transform` and `CubicSmoothingSpline::with_y_transform`).
+/// The transform must be strictly increasing in its domain.
+#[derive(Clone)]
+pub enum Transform<T> {
+    /// `log(v)`, the data must be positive
+    Log,
+
+    /// `log(1 + v)`, the data must be greater than -1
+    Log1p,
+
+    /// Box-Cox transform `(v^lambda - 1) / lambda` (`log(v)` for `lambda = 0`), the data must be positive
+    BoxCox(T),
+
+    /// The custom transform given by the forward and inverse functions
+    ///
+    /// The derivatives of the custom transform are computed by finite differences.
+    Custom {
+        forward: TransformFn<T>,
+        inverse: TransformFn<T>,
+    },
+}
+
+impl<T: Real<T>> Transform<T> {
+    /// Creates the custom transform from the forward and inverse functions
+    pub fn custom<F, I>(forward: F, inverse: I) -> Self
+    where
+        F: Fn(T) -> T + Send + Sync + 'static,
+        I: Fn(T) -> T + Send + Sync + 'static,
+    {
+        Transform::Custom {
+            forward: Arc::new(forward),
+            inverse: Arc::new(inverse),
+        }
+    }
+
+    /// Computes the forward transform of the value
+    pub fn forward(&self, v: T) -> T {
+        match self {
+            Transform::Log => v.ln(),
+            Transform::Log1p => v.ln_1p(),
+            Transform::BoxCox(lambda) => {
+                if lambda.is_zero() {
+                    v.ln()
+                } else if v > T::zero() {
+                    (v.powf(*lambda) - T::one()) / *lambda
+                } else {
+                    T::nan()
+                }
+            }
+            Transform::Custom { forward, .. } => forward(v),
+        }
+    }
+
+    /// Computes the inverse transform of the value
+    pub fn inverse(&self, u: T) -> T {
+        match self {
+            Transform::Log => u.exp(),
+            Transform::Log1p => u.exp_m1(),
+            Transform::BoxCox(lambda) => {
+                if lambda.is_zero() {
+                    u.exp()
+                } else {
+                    (*lambda * u + T::one()).powf(T::one() / *lambda)
+                }
+            }
+            Transform::Custom { inverse, .. } => inverse(u),
+        }
+    }
+
+    /// Computes the first and the second derivatives of the forward transform
+    pub(crate) fn forward_derivatives(&self, v: T) -> (T, T) {
+        match self {
+            Transform::Log => (v.recip(), -(v * v).recip()),
+            Transform::Log1p => {
+                let v1 = T::one() + v;
+                (v1.recip(), -(v1 * v1).recip())
+            }
+            Transform::BoxCox(lambda) => {
+                let lm1 = *lambda - T::one();
+                (v.powf(lm1), lm1 * v.powf(lm1 - T::one()))
+            }
+            Transform::Custom { forward, .. } => finite_differences(forward.as_ref(), v),
+        }
+    }
+
+    /// Computes the first and the second derivatives of the inverse transform
+    pub(crate) fn inverse_derivatives(&self, u: T) -> (T, T) {
+        match self {
+            Transform::Log | Transform::Log1p => (u.exp(), u.exp()),
+            Transform::BoxCox(lambda) => {
+                if lambda.is_zero() {
+                    (u.exp(), u.exp())
+                } else {
+                    let base = *lambda * u + T::one();
+                    let p = lambda.recip() - T::one();
+                    (base.powf(p), (T::one() - *lambda) * base.powf(p - T::one()))
+                }
+            }
+            Transform::Custom { inverse, .. } => finite_differences(inverse.as_ref(), u),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Transform<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Transform::Log => write!(f, "Log"),
+            Transform::Log1p => write!(f, "Log1p"),
+            Transform::BoxCox(lambda) => f.debug_tuple("BoxCox").field(lambda).finish(),
+            Transform::Custom { .. } => write!(f, "Custom"),
+        }
+    }
+}
+
+/// Computes the first and the second derivatives of the function by central differences
+fn finite_differences<T: Real<T>>(func: &(dyn Fn(T) -> T + Send + Sync), v: T) -> (T, T) {
+    let two = T::from(2.0).unwrap();
+    let scale = v.abs().max(T::one());
+
+    let h1 = T::epsilon().cbrt() * scale;
+    let d1 = (func(v + h1) - func(v - h1)) / (two * h1);
+
+    let h2 = T::epsilon().sqrt().sqrt() * scale;
+    let d2 = (func(v + h2) - two * func(v) + func(v - h2)) / (h2 * h2);
+
+    (d1, d2)
+}
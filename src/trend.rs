@@ -0,0 +1,239 @@
+mod make;
+mod validate;
+
+use ndarray::{Array, ArrayView, ArrayView1, AsArray, Axis, Dimension};
+
+use crate::{ndarrayext::reshape_evaluated, NdSpline, Real, RealRef, Result};
+
+/// N-dimensional (univariate/multivariate) L1 trend filter calculator/evaluator
+///
+/// The trend filter of degree `k` minimizes
+///
+/// ```text
+/// 1/2 * sum(w * (y - f)^2) + lambda * |D(k+1) f|_1
+/// ```
+///
+/// where `D(k+1)` is the discrete difference operator of order `k + 1` adapted to
+/// non-uniform data sites. The L1 penalty gives the piecewise-constant (`k = 0`),
+/// piecewise-linear (`k = 1`) or piecewise-quadratic (`k = 2`) fit with adaptively chosen
+/// knots, the fit keeps the kinks (regime changes) in the data which are blurred by
+/// the quadratic penalty of smoothing splines.
+///
+/// The problem is solved by ADMM (alternating direction method of multipliers).
+/// The computed fit is represented in PP-form as `NdSpline` with the order `k + 1`.
+///
+/// The methods API of `TrendFilter` is implemented as builder-like pattern
+/// (also as `CubicSmoothingSpline` struct).
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use csaps::TrendFilter;
+///
+/// let x = array![1., 2., 3., 4., 5., 6., 7.];
+/// let y = array![1.1, 1.9, 3.2, 3.9, 3.1, 2.0, 0.9];
+///
+/// let yi = TrendFilter::new(&x, &y)
+///     .with_degree(1)
+///     .with_lambda(0.5)
+///     .make().unwrap()
+///     .evaluate(&x).unwrap();
+/// ```
+///
+pub struct TrendFilter<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// X data sites (also breaks)
+    x: ArrayView1<'a, T>,
+
+    /// Y data values
+    y: ArrayView<'a, T, D>,
+
+    /// The axis parameter defines axis of Y data for trend computing
+    axis: Option<Axis>,
+
+    /// The optional data weights
+    weights: Option<ArrayView1<'a, T>>,
+
+    /// The degree of piecewise polynomials
+    degree: usize,
+
+    /// The regularization parameter
+    lambda: Option<T>,
+
+    /// The maximum number of ADMM iterations
+    max_iter: usize,
+
+    /// The relative tolerance of ADMM residuals
+    tolerance: T,
+
+    /// The number of ADMM iterations which have been made (the maximum for all components)
+    iterations: usize,
+
+    /// The flag which is true if ADMM has converged for all components
+    converged: bool,
+
+    /// `NdSpline` struct with computed trend in PP-form
+    spline: Option<NdSpline<'a, T>>,
+}
+
+impl<'a, T, D> TrendFilter<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    /// Creates `TrendFilter` struct from the given `X` data sites and `Y` data values
+    ///
+    /// # Arguments
+    ///
+    /// - `x` -- the X-data sites 1-d array-like. Must strictly increasing: `x1 < x2 < x3 < ... < xN`
+    /// - `y` -- The Y-data values n-d array-like. By default the axis parameter is equal to
+    ///   the last axis of Y data.
+    ///
+    pub fn new<X, Y>(x: X, y: Y) -> Self
+    where
+        X: AsArray<'a, T>,
+        Y: AsArray<'a, T, D>,
+    {
+        TrendFilter {
+            x: x.into(),
+            y: y.into(),
+            axis: None,
+            weights: None,
+            degree: 1,
+            lambda: None,
+            max_iter: 10000,
+            tolerance: T::from(1e-8).unwrap(),
+            iterations: 0,
+            converged: false,
+            spline: None,
+        }
+    }
+
+    /// Sets the axis parameter
+    ///
+    /// `y.shape()[axis]` must be equal to `x.len()`
+    ///
+    pub fn with_axis(mut self, axis: Axis) -> Self {
+        self.invalidate();
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets the weights data vector
+    ///
+    /// `weights.len()` must be equal to `x.len()`
+    ///
+    pub fn with_weights<W>(mut self, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.weights = Some(weights.into());
+        self
+    }
+
+    /// Sets the degree of piecewise polynomials, 1 (piecewise-linear) by default
+    ///
+    /// The degree can be 0 (piecewise-constant), 1 (piecewise-linear) or 2 (piecewise-quadratic).
+    ///
+    pub fn with_degree(mut self, degree: usize) -> Self {
+        self.invalidate();
+        self.degree = degree;
+        self
+    }
+
+    /// Sets the regularization parameter
+    ///
+    /// The parameter must be non-negative, the greater values give the less number of
+    /// the pieces (knots) of the trend. If the parameter is not set, it is computed from the data
+    /// as 0.1 of the smallest value giving the least-squares polynomial fit of the degree `k`.
+    ///
+    pub fn with_lambda(mut self, lambda: T) -> Self {
+        self.invalidate();
+        self.lambda = Some(lambda);
+        self
+    }
+
+    /// Sets the maximum number of ADMM iterations, 10000 by default
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        self.invalidate();
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Sets the relative tolerance of ADMM residuals, `1e-8` by default
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        self.invalidate();
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Makes (computes) the trend for given data and parameters
+    ///
+    /// # Errors
+    ///
+    /// - If the data or parameters are invalid
+    /// - If reshaping Y data to 2-d view has failed
+    ///
+    pub fn make(mut self) -> Result<Self> {
+        self.make_validate()?;
+        self.make_spline()?;
+        Ok(self)
+    }
+
+    /// Evaluates the computed trend on the given data sites
+    ///
+    /// # Errors
+    ///
+    /// - If the `xi` data is invalid
+    /// - If the trend yet has not been computed
+    ///
+    pub fn evaluate<X>(&self, xi: X) -> Result<Array<T, D>>
+    where
+        X: AsArray<'a, T>,
+    {
+        let xi = xi.into();
+        self.evaluate_validate(xi)?;
+
+        let yi_2d = self.spline.as_ref().unwrap().evaluate(xi);
+        reshape_evaluated(yi_2d, self.y.view(), self.axis.unwrap())
+    }
+
+    /// Returns the regularization parameter or None
+    pub fn lambda(&self) -> Option<T> {
+        self.lambda
+    }
+
+    /// Returns the degree of piecewise polynomials
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Returns the number of ADMM iterations which have been made
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns true if ADMM has converged for all components
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+
+    /// Returns the ref to `NdSpline` struct with data of computed trend or None
+    pub fn spline(&self) -> Option<&NdSpline<'a, T>> {
+        self.spline.as_ref()
+    }
+
+    /// Invalidate computed trend
+    fn invalidate(&mut self) {
+        self.iterations = 0;
+        self.converged = false;
+        self.spline = None;
+    }
+}
@@ -0,0 +1,240 @@
+use ndarray::{concatenate, prelude::*, Zip};
+use sprs::CsMat;
+
+use crate::{
+    ndarrayext::to_2d,
+    sprsext::{self, LdlSolver},
+    NdSpline, Real, RealRef, Result,
+};
+
+use super::TrendFilter;
+
+/// The default `lambda` as the fraction of the smallest `lambda` giving the polynomial fit
+const AUTO_LAMBDA_FRACTION: f64 = 0.1;
+
+impl<'a, T, D> TrendFilter<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn make_spline(&mut self) -> Result<()> {
+        let x = self.x;
+        let degree = self.degree;
+
+        let weights_default = Array1::ones(x.raw_dim());
+        let weights = self
+            .weights
+            .map(|v| v.reborrow())
+            .unwrap_or_else(|| weights_default.view());
+
+        let axis = self.axis.unwrap_or_else(|| Axis(self.y.ndim() - 1));
+        self.axis = Some(axis);
+
+        let y = to_2d(self.y.view(), axis)?;
+        let d = difference_operator(x, degree + 1);
+
+        let lambda = self.lambda.unwrap_or_else(|| auto_lambda(&d, y, weights));
+        self.lambda = Some(lambda);
+
+        let mut fitted = y.to_owned();
+        let mut iterations = 0;
+        let mut converged = true;
+
+        if lambda > T::zero() {
+            // The step size of ADMM
+            let rho = lambda;
+
+            let solver = {
+                let dtd = &d.transpose_view() * &d;
+                let diags_w = weights.insert_axis(Axis(0)).to_owned();
+                let w = sprsext::diags(diags_w, &[0], (x.len(), x.len()));
+
+                LdlSolver::new(&(&w + &dtd.map(|el| *el * rho)))
+            };
+
+            for (mut fit_row, y_row) in fitted.outer_iter_mut().zip(y.outer_iter()) {
+                let (row_iterations, row_converged) =
+                    self.admm(&d, &solver, rho, y_row, weights, fit_row.view_mut());
+
+                iterations = iterations.max(row_iterations);
+                converged &= row_converged;
+            }
+        }
+
+        let coeffs = pp_coeffs(x, fitted.view(), degree);
+
+        self.iterations = iterations;
+        self.converged = converged;
+        self.spline = Some(NdSpline::new(x, coeffs));
+
+        Ok(())
+    }
+
+    /// Solves the trend filtering problem for one component of the data by ADMM
+    ///
+    /// Returns the number of iterations and the convergence flag.
+    fn admm(
+        &self,
+        d: &CsMat<T>,
+        solver: &LdlSolver<T>,
+        rho: T,
+        y: ArrayView1<'_, T>,
+        weights: ArrayView1<'_, T>,
+        mut beta: ArrayViewMut1<'_, T>,
+    ) -> (usize, bool) {
+        let kappa = self.lambda.unwrap() / rho;
+        let tol = self.tolerance;
+
+        let norm = |v: &Array1<T>| v.dot(v).sqrt();
+        let soft = |v: T| v.signum() * (v.abs() - kappa).max(T::zero());
+
+        let sqrt_m = T::from(d.rows()).unwrap().sqrt();
+        let sqrt_n = T::from(d.cols()).unwrap().sqrt();
+
+        let dt = d.transpose_view();
+        let wy = &y * &weights;
+
+        let mut alpha: Array1<T> = d * &beta.to_owned();
+        let mut u = Array1::<T>::zeros(alpha.raw_dim());
+
+        for iteration in 1..=self.max_iter {
+            let rhs = &wy + &((&dt * &(&alpha - &u)) * rho);
+            beta.assign(&solver.solve(rhs.view()));
+
+            let dbeta: Array1<T> = d * &beta.to_owned();
+            let alpha_prev = alpha;
+
+            alpha = (&dbeta + &u).mapv(soft);
+            Zip::from(&mut u)
+                .and(&dbeta)
+                .and(&alpha)
+                .for_each(|u, &db, &a| *u += db - a);
+
+            let r_norm = norm(&(&dbeta - &alpha));
+            let s_norm = norm(&(&dt * &(&alpha - &alpha_prev))) * rho;
+
+            let eps_pri = tol * (sqrt_m + norm(&dbeta).max(norm(&alpha)));
+            let eps_dual = tol * (sqrt_n + norm(&(&dt * &u)) * rho);
+
+            if r_norm < eps_pri && s_norm < eps_dual {
+                return (iteration, true);
+            }
+        }
+
+        (self.max_iter, false)
+    }
+}
+
+/// Computes the default regularization parameter from the data
+///
+/// The fit is the weighted least-squares polynomial of the degree `k` (`D f = 0`) if `lambda` is
+/// greater or equal to `|(D W^-1 D')^-1 D y|_inf`. The default is the fraction of this bound
+/// (the maximum for all components of the data).
+fn auto_lambda<T>(d: &CsMat<T>, y: ArrayView2<'_, T>, weights: ArrayView1<'_, T>) -> T
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    let n = weights.len();
+    let diags_w = weights.mapv(|w| T::one() / w).insert_axis(Axis(0));
+    let w_inv = sprsext::diags(diags_w, &[0], (n, n));
+
+    let solver = LdlSolver::new(&(&(d * &w_inv) * &d.transpose_view()));
+
+    let lambda_max = y.outer_iter().fold(T::zero(), |acc, y_row| {
+        let dy: Array1<T> = d * &y_row.to_owned();
+        let v = solver.solve(dy.view());
+
+        v.fold(acc, |acc, &el| acc.max(el.abs()))
+    });
+
+    lambda_max * T::from(AUTO_LAMBDA_FRACTION).unwrap()
+}
+
+/// Creates the discrete difference operator of the given order for non-uniform data sites
+///
+/// The operator `D(k+1)` is defined recursively as `D(k+1) = D(1) * diag(k / (x[i+k] - x[i])) * D(k)`,
+/// where `D(1)` is the matrix of the first differences.
+fn difference_operator<T>(x: ArrayView1<'_, T>, order: usize) -> CsMat<T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    let n = x.len();
+
+    let first_diff = |size: usize| {
+        let diags = concatenate![
+            Axis(0),
+            -Array2::<T>::ones((1, size - 1)),
+            Array2::<T>::ones((1, size - 1))
+        ];
+        sprsext::diags(diags, &[0, 1], (size - 1, size))
+    };
+
+    let mut d = first_diff(n);
+
+    for k in 1..order {
+        let kt = T::from(k).unwrap();
+        let scale = Array1::from_shape_fn(n - k, |i| kt / (x[i + k] - x[i])).insert_axis(Axis(0));
+        let scale = sprsext::diags(scale, &[0], (n - k, n - k));
+
+        d = &(&first_diff(n - k) * &scale) * &d;
+    }
+
+    d
+}
+
+/// Converts the fitted values to PP-form coefficients of the piecewise polynomials
+///
+/// The polynomial of the degree `k >= 1` on the piece `[x[i], x[i+1]]` interpolates the fitted values
+/// at `k + 1` data sites `x[i-k+1], ..., x[i+1]` (shifted to be inside the data sites at the ends).
+/// The constant (`k = 0`) on the piece is the fitted value at `x[i]`.
+fn pp_coeffs<T: Real<T>>(
+    x: ArrayView1<'_, T>,
+    values: ArrayView2<'_, T>,
+    degree: usize,
+) -> Array2<T> {
+    let n = x.len();
+    let pieces = n - 1;
+    let order = degree + 1;
+
+    let mut coeffs = Array2::<T>::zeros((values.nrows(), pieces * order));
+
+    for (row, v) in values.outer_iter().enumerate() {
+        for i in 0..pieces {
+            let start = i
+                .saturating_sub(degree.saturating_sub(1))
+                .min(n - 1 - degree);
+            let sites = x.slice(s![start..start + order]);
+
+            // Newton divided differences
+            let mut dd = v.slice(s![start..start + order]).to_vec();
+            for level in 1..order {
+                for j in (level..order).rev() {
+                    dd[j] = (dd[j] - dd[j - 1]) / (sites[j] - sites[j - level]);
+                }
+            }
+
+            // Expand Newton form to the powers of (t - x[i]), from the lowest to the highest
+            let mut poly = vec![T::zero(); order];
+            poly[0] = dd[degree];
+
+            for j in (0..degree).rev() {
+                let shift = sites[j] - x[i];
+                for m in (0..order - 1).rev() {
+                    let c = poly[m];
+                    poly[m + 1] += c;
+                    poly[m] = -shift * c;
+                }
+                poly[0] += dd[j];
+            }
+
+            for (m, &c) in poly.iter().enumerate() {
+                coeffs[[row, (order - 1 - m) * pieces + i]] = c;
+            }
+        }
+    }
+
+    coeffs
+}
@@ -0,0 +1,84 @@
+use ndarray::{ArrayView1, Dimension};
+
+use crate::{
+    validate::{validate_weights_size, validate_xy_data},
+    CsapsError::InvalidInputData,
+    Real, RealRef, Result,
+};
+
+use super::TrendFilter;
+
+impl<'a, T, D> TrendFilter<'a, T, D>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+    D: Dimension,
+{
+    pub(super) fn make_validate(&self) -> Result<()> {
+        validate_xy_data(self.x, self.y.view(), self.axis)?;
+        validate_weights_size(self.weights, self.x.len())?;
+
+        if let Some(weights) = self.weights {
+            if weights.iter().any(|&w| w <= T::zero() || !w.is_finite()) {
+                return Err(InvalidInputData(
+                    "`weights` values must be positive and finite".to_string(),
+                ));
+            }
+        }
+
+        if self.degree > 2 {
+            return Err(InvalidInputData(format!(
+                "`degree` value must be 0, 1 or 2, given {}",
+                self.degree
+            )));
+        }
+
+        if self.x.len() < self.degree + 2 {
+            return Err(InvalidInputData(format!(
+                "The size of data vectors must be greater or equal to {} for `degree` {}",
+                self.degree + 2,
+                self.degree
+            )));
+        }
+
+        if let Some(lambda) = self.lambda {
+            if !(lambda >= T::zero() && lambda.is_finite()) {
+                return Err(InvalidInputData(format!(
+                    "`lambda` value must be non-negative and finite, given {:?}",
+                    lambda
+                )));
+            }
+        }
+
+        if self.max_iter == 0 {
+            return Err(InvalidInputData(
+                "`max_iter` must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self.tolerance <= T::zero() {
+            return Err(InvalidInputData(format!(
+                "`tolerance` must be positive, given {:?}",
+                self.tolerance
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn evaluate_validate(&self, xi: ArrayView1<'a, T>) -> Result<()> {
+        if xi.is_empty() {
+            return Err(InvalidInputData(
+                "The size of `xi` vector must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        if self.spline.is_none() {
+            return Err(InvalidInputData(
+                "The trend has not been computed, use `make` method before".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
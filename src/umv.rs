@@ -1,10 +1,21 @@
 mod evaluate;
+mod geometry;
+mod intersect;
 mod make;
+mod roots;
+mod segmented;
 mod validate;
 
-use ndarray::{Array, Array2, ArrayView, ArrayView1, ArrayView2, AsArray, Axis, Dimension};
+use ndarray::{
+    array, Array, Array1, Array2, ArrayView, ArrayView1, ArrayView2, AsArray, Axis, CowArray,
+    Dimension, Ix1,
+};
 
-use crate::{Real, RealRef, Result};
+use crate::{Real, RealRef, Result, Transform};
+
+pub use segmented::{GapFill, NdSegmentedSpline};
+
+pub(crate) use make::{auto_smooth, continuity_matrix, second_differences, weighted_gram};
 
 /// N-dimensional (univariate/multivariate) spline PP-form representation
 ///
@@ -30,7 +41,7 @@ where
     pieces: usize,
 
     /// The breaks (data sites) which have been used for computing spline
-    breaks: ArrayView1<'a, T>,
+    breaks: CowArray<'a, T, Ix1>,
 
     /// `NxM` array of spline coefficients where `N` is `ndim` and `M` is row of pieces of coefficients
     coeffs: Array2<T>,
@@ -44,14 +55,19 @@ where
     ///
     /// # Arguments
     ///
-    /// - `breaks` -- The breaks (data sites) which have been used for computing spline
+    /// - `breaks` -- The breaks (data sites) which have been used for computing spline,
+    ///   the view to the data sites or the owned array of computed sites
     /// - `coeffs` -- The NxM array of spline coefficients where N is `ndim` and M is row of pieces of coefficients
     ///
     /// # Notes
     ///
     /// - `NdSpline` struct should not be created directly by a user in most cases.
     ///
-    pub fn new(breaks: ArrayView1<'a, T>, coeffs: Array2<T>) -> NdSpline<'a, T> {
+    pub fn new<B>(breaks: B, coeffs: Array2<T>) -> NdSpline<'a, T>
+    where
+        B: Into<CowArray<'a, T, Ix1>>,
+    {
+        let breaks = breaks.into();
         let c_shape = coeffs.shape();
         let ndim = c_shape[0];
         let pieces = breaks.len() - 1;
@@ -92,7 +108,7 @@ where
     }
 
     /// Evaluates the spline on the given data sites
-    pub fn evaluate(&self, xi: ArrayView1<'a, T>) -> Array2<T> {
+    pub fn evaluate(&self, xi: ArrayView1<'_, T>) -> Array2<T> {
         Self::evaluate_spline(
             self.order,
             self.pieces,
@@ -101,6 +117,85 @@ where
             xi,
         )
     }
+
+    /// Evaluates the left-continuous spline on the given data sites
+    ///
+    /// `evaluate` method is right-continuous, the data sites which are equal to the interior breaks
+    /// are evaluated by the right pieces. This method evaluates such sites by the left pieces,
+    /// it gives the left limits at the jumps of a discontinuous spline.
+    pub fn evaluate_left(&self, xi: ArrayView1<'_, T>) -> Array2<T> {
+        Self::evaluate_spline_left(
+            self.order,
+            self.pieces,
+            self.breaks.view(),
+            self.coeffs.view(),
+            xi,
+        )
+    }
+
+    /// Returns `nu`-th derivative of the spline as the new spline
+    ///
+    /// The derivative spline has the same breaks and the order `order - nu`, its coefficients
+    /// are computed by differentiating the piecewise polynomials. The derivative of the order
+    /// greater than or equal to the spline order is the zero spline of the order 1.
+    pub fn derivative(&self, nu: usize) -> NdSpline<'a, T> {
+        let (_, coeffs) = Self::derivative_coeffs(self.order, self.pieces, self.coeffs.view(), nu);
+        NdSpline::new(self.breaks.clone(), coeffs)
+    }
+
+    /// Returns `nu`-th antiderivative of the spline as the new spline
+    ///
+    /// The antiderivative spline has the same breaks and the order `order + nu`, it is equal
+    /// to zero at the first break and it is continuous across the breaks.
+    pub fn antiderivative(&self, nu: usize) -> NdSpline<'a, T> {
+        let (_, coeffs) = Self::antiderivative_coeffs(
+            self.order,
+            self.pieces,
+            self.breaks.view(),
+            self.coeffs.view(),
+            nu,
+        );
+        NdSpline::new(self.breaks.clone(), coeffs)
+    }
+
+    /// Computes the definite integral of the spline from `a` to `b` for every dimension
+    ///
+    /// The limits can be outside the breaks range, the spline is extrapolated by the first
+    /// and the last pieces as `evaluate` method does. The integral is negative if `b < a`.
+    pub fn integrate(&self, a: T, b: T) -> Array1<T> {
+        let antiderivative = self.antiderivative(1);
+        let values = antiderivative.evaluate(array![a, b].view());
+
+        &values.column(1) - &values.column(0)
+    }
+}
+
+/// The range of the evaluated angles (radians) for circular-valued data
+///
+/// See `CubicSmoothingSpline::with_circular` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AngleRange {
+    /// The angles are wrapped into `[-pi, pi)` range
+    Signed,
+
+    /// The angles are wrapped into `[0, 2*pi)` range
+    Unsigned,
+}
+
+impl AngleRange {
+    /// Wraps the angle into the range
+    pub fn wrap<T: Real<T>>(&self, angle: T) -> T {
+        let pi = T::from(std::f64::consts::PI).unwrap();
+        let two_pi = pi + pi;
+
+        let lower = match self {
+            AngleRange::Signed => -pi,
+            AngleRange::Unsigned => T::zero(),
+        };
+
+        let shifted = angle - lower;
+        shifted - two_pi * (shifted / two_pi).floor() + lower
+    }
 }
 
 /// N-dimensional (univariate/multivariate) smoothing spline calculator/evaluator
@@ -166,8 +261,44 @@ where
     /// The optional smoothing parameter
     smooth: Option<T>,
 
+    /// The optional cutoff frequency and sampling interval which define the smoothing parameter
+    cutoff: Option<(T, T)>,
+
+    /// The optional range of evaluated angles for circular-valued data
+    circular: Option<AngleRange>,
+
+    /// The optional transform of X data sites
+    x_transform: Option<Transform<T>>,
+
+    /// The optional transform of Y data values
+    y_transform: Option<Transform<T>>,
+
+    /// The optional data sites of the first-derivative observations
+    derivative_x: Option<ArrayView1<'a, T>>,
+
+    /// The optional first-derivative observations
+    derivative_y: Option<ArrayView<'a, T, D>>,
+
+    /// The optional weights of the first-derivative observations
+    derivative_weights: Option<ArrayView1<'a, T>>,
+
+    /// The optional locations of the jumps (the value and the slope discontinuities)
+    jumps: Option<ArrayView1<'a, T>>,
+
+    /// The optional locations of the kinks (the slope discontinuities)
+    kinks: Option<ArrayView1<'a, T>>,
+
+    /// The optional threshold of the data sites intervals which split the data into segments
+    gap_threshold: Option<T>,
+
+    /// The policy of evaluating the segmented spline inside the gaps
+    gap_fill: GapFill<T>,
+
     /// `NdSpline` struct with computed spline
     spline: Option<NdSpline<'a, T>>,
+
+    /// `NdSegmentedSpline` struct with computed segmented spline
+    segmented_spline: Option<NdSegmentedSpline<'a, T>>,
 }
 
 impl<'a, T, D> CubicSmoothingSpline<'a, T, D>
@@ -197,7 +328,19 @@ where
             axis: None,
             weights: None,
             smooth: None,
+            cutoff: None,
+            circular: None,
+            x_transform: None,
+            y_transform: None,
+            derivative_x: None,
+            derivative_y: None,
+            derivative_weights: None,
+            jumps: None,
+            kinks: None,
+            gap_threshold: None,
+            gap_fill: GapFill::Nan,
             spline: None,
+            segmented_spline: None,
         }
     }
 
@@ -274,6 +417,7 @@ where
     pub fn with_smooth(mut self, smooth: T) -> Self {
         self.invalidate();
         self.smooth = Some(smooth);
+        self.cutoff = None;
         self
     }
 
@@ -281,6 +425,282 @@ where
     pub fn with_optional_smooth(mut self, smooth: Option<T>) -> Self {
         self.invalidate();
         self.smooth = smooth;
+        self.cutoff = None;
+        self
+    }
+
+    /// Sets the smoothing parameter by the -3 dB cutoff frequency for the given sampling interval
+    ///
+    /// For uniformly sampled data the smoothing spline is the linear low-pass filter, the smoothing
+    /// parameter is computed by `smooth_from_cutoff` function when the spline is made. The cutoff
+    /// frequency is in cycles per X unit and must not exceed the Nyquist frequency `1 / (2 * spacing)`.
+    ///
+    /// The method replaces the smoothing parameter which is set by `with_smooth` method and vice versa.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array1;
+    /// use csaps::CubicSmoothingSpline;
+    ///
+    /// let t = Array1::linspace(0., 1., 101);
+    /// let y = t.mapv(|v: f64| (v * 10.).sin() + 0.1 * (v * 300.).sin());
+    ///
+    /// let s = CubicSmoothingSpline::new(&t, &y)
+    ///     .with_cutoff(5.0, 0.01)
+    ///     .make().unwrap();
+    /// ```
+    ///
+    pub fn with_cutoff(mut self, cutoff: T, spacing: T) -> Self {
+        self.invalidate();
+        self.smooth = None;
+        self.cutoff = Some((cutoff, spacing));
+        self
+    }
+
+    /// Sets the circular-valued data mode
+    ///
+    /// Y-data values are treated as angles in radians (headings, phases) which wrap at the period
+    /// `2*pi`. The data is unwrapped along the axis before computing the spline, so the wraps do not
+    /// produce the spikes in the spline. The evaluated values are wrapped into the given range,
+    /// the evaluated derivatives are the angular rates and they are not wrapped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use csaps::{AngleRange, CubicSmoothingSpline};
+    ///
+    /// let x = array![1., 2., 3., 4., 5.];
+    /// let heading = array![2.8, 3.0, -3.1, -2.9, -2.7];
+    ///
+    /// let s = CubicSmoothingSpline::new(&x, &heading)
+    ///     .with_circular(AngleRange::Signed)
+    ///     .with_smooth(0.9)
+    ///     .make().unwrap();
+    ///
+    /// let angles = s.evaluate(&x).unwrap();
+    /// let rates = s.evaluate_derivative(&x, 1).unwrap();
+    ///
+    /// assert!(rates.iter().all(|&r| r > 0.));
+    /// ```
+    ///
+    pub fn with_circular(mut self, range: AngleRange) -> Self {
+        self.invalidate();
+        self.circular = Some(range);
+        self
+    }
+
+    /// Sets the transform of X data sites
+    ///
+    /// The spline is computed in the transformed space, for example, in log-frequency space
+    /// for the data sites which span many decades. `evaluate` and `evaluate_derivative` methods
+    /// accept the data sites in the original space, the derivatives are computed with respect to
+    /// the original data sites by the chain rule.
+    ///
+    /// The transformed data sites must be strictly increasing.
+    ///
+    pub fn with_x_transform(mut self, transform: Transform<T>) -> Self {
+        self.invalidate();
+        self.x_transform = Some(transform);
+        self
+    }
+
+    /// Sets the transform of Y data values
+    ///
+    /// The spline is computed for the transformed data values, for example, for the logarithm of
+    /// the positive data values which span many decades. `evaluate` and `evaluate_derivative`
+    /// methods return the values in the original space, the derivatives are computed by the chain rule.
+    ///
+    /// The transform cannot be used together with the circular-valued data mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use csaps::{CubicSmoothingSpline, Transform};
+    ///
+    /// let freq = array![1., 10., 100., 1e3, 1e4, 1e5, 1e6];
+    /// let power = array![1e-3, 2e-2, 0.5, 7., 40., 1e3, 2e4];
+    ///
+    /// let s = CubicSmoothingSpline::new(&freq, &power)
+    ///     .with_x_transform(Transform::Log)
+    ///     .with_y_transform(Transform::Log)
+    ///     .with_smooth(0.9)
+    ///     .make().unwrap();
+    ///
+    /// let pi = s.evaluate(&array![5., 5e5]).unwrap();
+    /// assert!(pi.iter().all(|&v| v > 0.));
+    /// ```
+    ///
+    pub fn with_y_transform(mut self, transform: Transform<T>) -> Self {
+        self.invalidate();
+        self.y_transform = Some(transform);
+        self
+    }
+
+    /// Sets the first-derivative observations which are fitted together with Y data values
+    ///
+    /// The derivative data sites can differ from X data sites, for example, position and velocity
+    /// samples from different sensors. The single smoothing spline is computed for all observations
+    /// (Hermite smoothing), the derivative residuals are added to the least-squares part of the
+    /// criterion with their own weights and the roughness penalty is the same.
+    ///
+    /// `dydx` must have the same shape as Y data except `dydx.shape()[axis]` which must be equal
+    /// to the length of the derivative sites `x` passed to this method (not the data sites).
+    /// The derivative data sites must be strictly increasing.
+    ///
+    /// The derivative observations cannot be used with X or Y data transforms or with the gap
+    /// segmentation (`make` returns an error), they can be used with jumps and kinks.
+    ///
+    /// If the smoothing parameter is not set, it is computed automatically from Y data sites
+    /// and weights as without the derivative observations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use csaps::CubicSmoothingSpline;
+    ///
+    /// let t = array![0., 1., 2., 3., 4.];
+    /// let position = array![0.0, 0.9, 4.1, 8.9, 16.2];
+    ///
+    /// let tv = array![0.5, 1.5, 2.5, 3.5];
+    /// let velocity = array![1.1, 2.9, 5.2, 6.8];
+    ///
+    /// let s = CubicSmoothingSpline::new(&t, &position)
+    ///     .with_derivatives(&tv, &velocity)
+    ///     .with_smooth(0.9)
+    ///     .make().unwrap();
+    ///
+    /// let v = s.evaluate_derivative(&tv, 1).unwrap();
+    /// ```
+    ///
+    pub fn with_derivatives<X, Y>(mut self, x: X, dydx: Y) -> Self
+    where
+        X: AsArray<'a, T>,
+        Y: AsArray<'a, T, D>,
+    {
+        self.invalidate();
+        self.derivative_x = Some(x.into());
+        self.derivative_y = Some(dydx.into());
+        self
+    }
+
+    /// Sets the weights of the first-derivative observations
+    ///
+    /// `weights.len()` must be equal to the derivative data sites size, the weights must be
+    /// non-negative and finite. The derivative data must be set by `with_derivatives` method.
+    ///
+    pub fn with_derivative_weights<W>(mut self, weights: W) -> Self
+    where
+        W: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.derivative_weights = Some(weights.into());
+        self
+    }
+
+    /// Sets the locations of the known jumps (step changes) of the data
+    ///
+    /// The value and the first derivative of the spline are discontinuous at the jumps,
+    /// the segments between the jumps are smoothed jointly with the shared smoothing parameter.
+    /// The computed spline is right-continuous at the jumps (the data values at the jump
+    /// locations belong to the right segments), use `NdSpline::evaluate_left` method for
+    /// the left limits.
+    ///
+    /// The locations must be strictly increasing and lie strictly inside X data range.
+    /// Every segment between the jumps and the kinks must contain at least 2 data sites.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use csaps::CubicSmoothingSpline;
+    ///
+    /// let x = array![0., 1., 2., 3., 4., 5., 6., 7.];
+    /// let y = array![0.1, 0.0, 0.2, 0.1, 5.1, 4.9, 5.0, 5.2];
+    /// let jumps = array![3.5];
+    ///
+    /// let s = CubicSmoothingSpline::new(&x, &y)
+    ///     .with_jumps(&jumps)
+    ///     .with_smooth(0.8)
+    ///     .make().unwrap();
+    ///
+    /// let spline = s.spline().unwrap();
+    /// let before = spline.evaluate_left(jumps.view());
+    /// let after = spline.evaluate(jumps.view());
+    /// ```
+    ///
+    pub fn with_jumps<J>(mut self, jumps: J) -> Self
+    where
+        J: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.jumps = Some(jumps.into());
+        self
+    }
+
+    /// Sets the locations of the known kinks (slope changes) of the data
+    ///
+    /// The spline is continuous at the kinks, but its first derivative is discontinuous.
+    /// The locations must be strictly increasing, lie strictly inside X data range and differ
+    /// from the jumps locations. Every segment between the jumps and the kinks must contain
+    /// at least 2 data sites.
+    ///
+    pub fn with_kinks<K>(mut self, kinks: K) -> Self
+    where
+        K: AsArray<'a, T>,
+    {
+        self.invalidate();
+        self.kinks = Some(kinks.into());
+        self
+    }
+
+    /// Sets the threshold of the data gaps for automatic segmentation
+    ///
+    /// Any interval between the adjacent X data sites which is longer than the threshold splits
+    /// the data into the independent segments. Every segment is fitted separately by its own
+    /// smoothing spline, the smoothing parameter is computed automatically for every segment
+    /// if it is not set. A segment with the single data site is the constant.
+    ///
+    /// The computed spline is `NdSegmentedSpline` (see `segmented_spline` method) and `spline`
+    /// method returns None. The values inside the gaps are given by the gap fill policy
+    /// (NaN by default, see `with_gap_fill` method).
+    ///
+    /// Gap segmentation cannot be used with the data transforms, the derivative data, the jumps
+    /// or the kinks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Array1};
+    /// use csaps::CubicSmoothingSpline;
+    ///
+    /// let x: Array1<f64> = array![0., 1., 2., 3., 100., 101., 102., 103.];
+    /// let y = array![1.0, 1.2, 0.9, 1.1, 3.0, 3.3, 2.9, 3.1];
+    ///
+    /// let s = CubicSmoothingSpline::new(&x, &y)
+    ///     .with_gap_threshold(10.)
+    ///     .make().unwrap();
+    ///
+    /// let yi = s.evaluate(&array![1.5, 50., 101.5]).unwrap();
+    /// assert!(yi[1].is_nan());
+    /// ```
+    ///
+    pub fn with_gap_threshold(mut self, threshold: T) -> Self {
+        self.invalidate();
+        self.gap_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the policy of evaluating the segmented spline inside the gaps
+    ///
+    /// `GapFill::Nan` by default. See `with_gap_threshold` method.
+    ///
+    pub fn with_gap_fill(mut self, fill: GapFill<T>) -> Self {
+        self.invalidate();
+        self.gap_fill = fill;
         self
     }
 
@@ -298,23 +718,85 @@ where
         let xi = xi.into();
         self.evaluate_validate(xi)?;
 
-        let yi = self.evaluate_spline(xi)?;
+        let yi = self.evaluate_spline(xi, 0)?;
         Ok(yi)
     }
 
+    /// Evaluates the derivative of the given order `nu` of the computed spline on the given data sites
+    ///
+    /// The order 0 is equal to `evaluate` method. The derivatives of the order greater than 3 are zero.
+    /// If X or Y data transform is set, the derivatives up to the order 2 are supported.
+    ///
+    /// # Errors
+    ///
+    /// - If the `xi` data is invalid
+    /// - If the spline yet has not been computed
+    /// - If the derivative order is not supported with the data transforms
+    ///
+    pub fn evaluate_derivative<X>(&self, xi: X, nu: usize) -> Result<Array<T, D>>
+    where
+        X: AsArray<'a, T>,
+    {
+        let xi = xi.into();
+        self.evaluate_validate(xi)?;
+        self.derivative_validate(nu)?;
+
+        let yi = self.evaluate_spline(xi, nu)?;
+        Ok(yi)
+    }
+
+    /// Computes the definite integral of the computed spline from `a` to `b`
+    ///
+    /// The integrals are computed for every component of the data. The result has the layout of
+    /// `evaluate` method output for the single data site: the size of the axis is equal to 1.
+    /// The limits can be outside the data sites range, the spline is extrapolated. The integral
+    /// is negative if `b < a`. For circular-valued data the unwrapped angles are integrated.
+    ///
+    /// # Errors
+    ///
+    /// - If the limits are not finite
+    /// - If the spline yet has not been computed
+    /// - If X or Y data transform is set or the data is segmented at the gaps
+    ///
+    pub fn integrate(&self, a: T, b: T) -> Result<Array<T, D>> {
+        self.integrate_validate(a, b)?;
+        self.integrate_spline(a, b)
+    }
+
     /// Returns the smoothing parameter or None
+    ///
+    /// The automatically computed smoothing parameters of the segments with gap segmentation
+    /// are returned by `NdSegmentedSpline::smooths` method.
     pub fn smooth(&self) -> Option<T> {
         self.smooth
     }
 
+    /// Returns the range of evaluated angles if the circular-valued data mode is set or None
+    pub fn circular(&self) -> Option<AngleRange> {
+        self.circular
+    }
+
     /// Returns the ref to `NdSpline` struct with data of computed spline or None
+    ///
+    /// With `x` or `y` transforms the breaks and the values of the spline are in the transformed
+    /// space. With circular mode the values of the spline are the unwrapped angles, they are
+    /// wrapped to the angle range only by `evaluate` method.
+    ///
     pub fn spline(&self) -> Option<&NdSpline<'a, T>> {
         self.spline.as_ref()
     }
 
+    /// Returns the ref to `NdSegmentedSpline` struct with data of computed segmented spline or None
+    ///
+    /// The segmented spline is computed if the gap threshold is set.
+    pub fn segmented_spline(&self) -> Option<&NdSegmentedSpline<'a, T>> {
+        self.segmented_spline.as_ref()
+    }
+
     /// Invalidate computed spline
     fn invalidate(&mut self) {
         self.spline = None;
+        self.segmented_spline = None;
     }
     /// Makes (computes) the spline for given data and parameters
     ///
@@ -20,7 +20,7 @@ where
         pieces: usize,
         breaks: ArrayView1<'_, T>,
         coeffs: ArrayView2<'_, T>,
-        xi: ArrayView1<'a, T>,
+        xi: ArrayView1<'_, T>,
     ) -> Array2<T> {
         let edges = {
             let mesh = breaks.slice(s![1..breaks.len() - 1]);
@@ -31,8 +31,41 @@ where
             concatenate![Axis(0), left_bound, mesh, right_bound]
         };
 
-        let mut indices = digitize(xi, &edges);
+        let indices = digitize(xi, &edges);
+
+        Self::evaluate_pieces(order, pieces, breaks, coeffs, xi, indices)
+    }
+
+    /// Implements evaluating the spline with the left-continuous pieces on the given mesh of Xi-sites
+    ///
+    /// The sites which are equal to the interior breaks are evaluated by the left pieces.
+    pub(crate) fn evaluate_spline_left(
+        order: usize,
+        pieces: usize,
+        breaks: ArrayView1<'_, T>,
+        coeffs: ArrayView2<'_, T>,
+        xi: ArrayView1<'_, T>,
+    ) -> Array2<T> {
+        let mesh = breaks.slice(s![1..breaks.len() - 1]);
+
+        let indices = xi.mapv(|v| {
+            mesh.iter()
+                .filter(|&&b| b < v && !b.almost_equals(v))
+                .count()
+        });
 
+        Self::evaluate_pieces(order, pieces, breaks, coeffs, xi, indices)
+    }
+
+    /// Evaluates the spline pieces with the given indices on the given data sites
+    fn evaluate_pieces(
+        order: usize,
+        pieces: usize,
+        breaks: ArrayView1<'_, T>,
+        coeffs: ArrayView2<'_, T>,
+        xi: ArrayView1<'_, T>,
+        mut indices: Array1<usize>,
+    ) -> Array2<T> {
         // Go to local coordinates
         let xi = {
             let indexed_breaks = indices.mapv(|i| breaks[i]);
@@ -65,6 +98,96 @@ where
 
         values
     }
+
+    /// Computes the coefficients of `nu`-th derivative of the spline pieces
+    ///
+    /// Returns the order of the derivative spline and its coefficients array with the same layout.
+    /// The derivative of the order greater than or equal to the spline order is zero constant.
+    pub(crate) fn derivative_coeffs(
+        order: usize,
+        pieces: usize,
+        coeffs: ArrayView2<'_, T>,
+        nu: usize,
+    ) -> (usize, Array2<T>) {
+        if nu >= order {
+            return (1, Array2::zeros((coeffs.nrows(), pieces)));
+        }
+
+        let mut dorder = order;
+        let mut dcoeffs = coeffs.to_owned();
+
+        for _ in 0..nu {
+            // The coefficients of the highest power go first, the constant terms are dropped
+            dcoeffs = dcoeffs.slice(s![.., ..(dorder - 1) * pieces]).to_owned();
+
+            for k in 0..dorder - 1 {
+                let power = T::from(dorder - 1 - k).unwrap();
+                dcoeffs
+                    .slice_mut(s![.., k * pieces..(k + 1) * pieces])
+                    .mapv_inplace(|c| c * power);
+            }
+
+            dorder -= 1;
+        }
+
+        (dorder, dcoeffs)
+    }
+
+    /// Computes the coefficients of `nu`-th antiderivative of the spline pieces
+    ///
+    /// Returns the order of the antiderivative spline and its coefficients array with the same layout.
+    /// The antiderivative is equal to zero at the first break and it is continuous across the breaks:
+    /// the constant term of every piece is the value of the previous piece at its end.
+    pub(crate) fn antiderivative_coeffs(
+        order: usize,
+        pieces: usize,
+        breaks: ArrayView1<'_, T>,
+        coeffs: ArrayView2<'_, T>,
+        nu: usize,
+    ) -> (usize, Array2<T>) {
+        let ndim = coeffs.nrows();
+
+        let mut iorder = order;
+        let mut icoeffs = coeffs.to_owned();
+
+        for _ in 0..nu {
+            let mut next = Array2::zeros((ndim, (iorder + 1) * pieces));
+
+            // The coefficients of the highest power go first, the constant terms are appended
+            for k in 0..iorder {
+                let power = T::from(iorder - k).unwrap();
+                next.slice_mut(s![.., k * pieces..(k + 1) * pieces]).assign(
+                    &icoeffs
+                        .slice(s![.., k * pieces..(k + 1) * pieces])
+                        .mapv(|c| c / power),
+                );
+            }
+
+            for piece in 1..pieces {
+                let h = breaks[piece] - breaks[piece - 1];
+
+                for dim in 0..ndim {
+                    let end_value = (0..=iorder).fold(T::zero(), |acc, k| {
+                        acc * h + next[[dim, k * pieces + piece - 1]]
+                    });
+                    next[[dim, iorder * pieces + piece]] = end_value;
+                }
+            }
+
+            iorder += 1;
+            icoeffs = next;
+        }
+
+        (iorder, icoeffs)
+    }
+
+    /// Evaluates `nu`-th derivative of the spline on the given data sites
+    pub(crate) fn evaluate_derivative(&self, xi: ArrayView1<'_, T>, nu: usize) -> Array2<T> {
+        let (dorder, dcoeffs) =
+            Self::derivative_coeffs(self.order, self.pieces, self.coeffs.view(), nu);
+
+        Self::evaluate_spline(dorder, self.pieces, self.breaks.view(), dcoeffs.view(), xi)
+    }
 }
 
 impl<'a, T, D> CubicSmoothingSpline<'a, T, D>
@@ -74,16 +197,120 @@ where
 
     D: Dimension,
 {
-    pub(super) fn evaluate_spline(&self, xi: ArrayView1<'a, T>) -> Result<Array<T, D>> {
+    pub(super) fn evaluate_spline(&self, xi: ArrayView1<'a, T>, nu: usize) -> Result<Array<T, D>> {
         let axis = self.axis.unwrap();
         let mut shape_tmp = self.y.shape().to_owned();
         shape_tmp[axis.0] = xi.len();
 
         let shape: D = dim_from_vec(self.y.ndim(), shape_tmp);
 
-        let yi_2d = self.spline.as_ref().unwrap().evaluate(xi);
+        if let Some(segmented) = &self.segmented_spline {
+            let yi_2d = match (nu, self.circular) {
+                (0, Some(range)) => segmented.evaluate(xi).mapv(|v| range.wrap(v)),
+                _ => segmented.evaluate_derivative(xi, nu),
+            };
+            return Ok(from_2d(&yi_2d, shape, axis)?.to_owned());
+        }
+
+        let spline = self.spline.as_ref().unwrap();
+
+        if self.x_transform.is_some() || self.y_transform.is_some() {
+            let yi_2d = match (nu, self.circular) {
+                (0, Some(range)) => self
+                    .evaluate_transformed(spline, xi, nu)
+                    .mapv(|v| range.wrap(v)),
+                _ => self.evaluate_transformed(spline, xi, nu),
+            };
+            return Ok(from_2d(&yi_2d, shape, axis)?.to_owned());
+        }
+
+        let yi_2d = match (nu, self.circular) {
+            (0, None) => spline.evaluate(xi),
+            (0, Some(range)) => spline.evaluate(xi).mapv(|v| range.wrap(v)),
+            _ => spline.evaluate_derivative(xi, nu),
+        };
+
         let yi = from_2d(&yi_2d, shape, axis)?.to_owned();
 
         Ok(yi)
     }
+
+    /// Computes the integrals of the spline from `a` to `b` in the layout of the single data site
+    pub(super) fn integrate_spline(&self, a: T, b: T) -> Result<Array<T, D>> {
+        let axis = self.axis.unwrap();
+        let mut shape_tmp = self.y.shape().to_owned();
+        shape_tmp[axis.0] = 1;
+
+        let shape: D = dim_from_vec(self.y.ndim(), shape_tmp);
+
+        let spline = self.spline.as_ref().unwrap();
+        let integrals = spline.integrate(a, b).insert_axis(Axis(1));
+
+        Ok(from_2d(&integrals, shape, axis)?.to_owned())
+    }
+
+    /// Evaluates the spline or its derivative (up to the order 2) in the original space
+    /// of the transformed data by the chain rule
+    fn evaluate_transformed(
+        &self,
+        spline: &NdSpline<'a, T>,
+        xi: ArrayView1<'a, T>,
+        nu: usize,
+    ) -> Array2<T> {
+        let one = T::one();
+        let zero = T::zero();
+
+        // The transformed data sites and the derivatives of X transform
+        let (ui, dx1, dx2) = match &self.x_transform {
+            Some(transform) => {
+                let ui = xi.mapv(|v| transform.forward(v));
+                let (d1, d2): (Vec<T>, Vec<T>) =
+                    xi.iter().map(|&v| transform.forward_derivatives(v)).unzip();
+                (ui, Array1::from(d1), Array1::from(d2))
+            }
+            None => (
+                xi.to_owned(),
+                Array1::ones(xi.len()),
+                Array1::zeros(xi.len()),
+            ),
+        };
+
+        let s0 = spline.evaluate(ui.view());
+
+        let inverse = |v: T| match &self.y_transform {
+            Some(transform) => transform.inverse(v),
+            None => v,
+        };
+
+        let inverse_derivatives = |v: T| match &self.y_transform {
+            Some(transform) => transform.inverse_derivatives(v),
+            None => (one, zero),
+        };
+
+        if nu == 0 {
+            return s0.mapv(inverse);
+        }
+
+        let s1 = spline.evaluate_derivative(ui.view(), 1);
+        let s2 = if nu == 2 {
+            spline.evaluate_derivative(ui.view(), 2)
+        } else {
+            Array2::zeros(s1.raw_dim())
+        };
+
+        let mut values = Array2::zeros(s0.raw_dim());
+
+        for ((row, col), value) in values.indexed_iter_mut() {
+            let (a, b) = (dx1[col], dx2[col]);
+            let (g1, g2) = inverse_derivatives(s0[[row, col]]);
+            let (d1, d2) = (s1[[row, col]], s2[[row, col]]);
+
+            *value = match nu {
+                1 => g1 * d1 * a,
+                _ => g2 * (d1 * a) * (d1 * a) + g1 * (d2 * a * a + d1 * b),
+            };
+        }
+
+        values
+    }
 }
@@ -0,0 +1,620 @@
+use ndarray::prelude::*;
+
+use crate::{CsapsError::InvalidInputData, Real, Result};
+
+use super::NdSpline;
+
+/// The nodes of 5-point Gauss-Legendre quadrature on `[-1, 1]`
+const GAUSS_NODES: [f64; 5] = [
+    -0.906_179_845_938_664,
+    -0.538_469_310_105_683,
+    0.0,
+    0.538_469_310_105_683,
+    0.906_179_845_938_664,
+];
+
+/// The weights of 5-point Gauss-Legendre quadrature on `[-1, 1]`
+const GAUSS_WEIGHTS: [f64; 5] = [
+    0.236_926_885_056_189,
+    0.478_628_670_499_366,
+    0.568_888_888_888_889,
+    0.478_628_670_499_366,
+    0.236_926_885_056_189,
+];
+
+/// The maximum depth of the adaptive quadrature bisection
+const MAX_DEPTH: usize = 30;
+
+/// The maximum number of Newton iterations for the inverse arc length map and the projection
+const MAX_NEWTON_ITERATIONS: usize = 100;
+
+/// The number of the coarse samples of every piece for the projection
+const PROJECTION_SAMPLES: usize = 8;
+
+impl<'a, T> NdSpline<'a, T>
+where
+    T: Real<T>,
+{
+    /// Computes the arc length of the spline curve over the parameter interval `[a, b]`
+    ///
+    /// The spline dimensions are the coordinates of the curve (usually `ndim >= 2`), the arc length
+    /// is the integral of the speed `|f'(t)|` which is computed by adaptive Gauss quadrature on
+    /// the pieces. The arc length is negative if `a > b`.
+    ///
+    /// # Errors
+    ///
+    /// - If the interval is out of the breaks range
+    ///
+    pub fn arc_length(&self, a: T, b: T) -> Result<T> {
+        self.parameter_validate(a)?;
+        self.parameter_validate(b)?;
+
+        let speed = Speed::new(self);
+
+        if a > b {
+            return Ok(-speed.integral(b, a));
+        }
+
+        Ok(speed.integral(a, b))
+    }
+
+    /// Computes the parameter values for the given arc lengths measured from the spline start
+    ///
+    /// This is the inverse map of the arc length function `s(t) = arc_length(t0, t)`. The arc
+    /// lengths must be in range `[0, L]` where `L` is the total arc length of the spline.
+    /// If the curve stops (the speed is zero) on an interval, the start of the interval is returned.
+    ///
+    /// # Errors
+    ///
+    /// - If the arc lengths are out of range
+    ///
+    pub fn arc_length_parameter(&self, s: ArrayView1<'_, T>) -> Result<Array1<T>> {
+        let speed = Speed::new(self);
+        let cumulative = speed.cumulative();
+        let total = cumulative[self.pieces];
+
+        let tolerance = T::epsilon().sqrt() * total;
+
+        if s.iter()
+            .any(|&v| v.is_nan() || v < -tolerance || v > total + tolerance)
+        {
+            return Err(InvalidInputData(format!(
+                "The arc length values must be in range [0, {:?}]",
+                total
+            )));
+        }
+
+        Ok(s.mapv(|v| speed.inverse(&cumulative, v.max(T::zero()).min(total))))
+    }
+
+    /// Resamples the spline curve at the points with equal arc length spacing
+    ///
+    /// Returns the parameter values and `ndim x count` array of the points, the first and
+    /// the last points are the ends of the curve.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of points is less than 2
+    ///
+    pub fn resample_arc_length(&self, count: usize) -> Result<(Array1<T>, Array2<T>)> {
+        if count < 2 {
+            return Err(InvalidInputData(
+                "The number of points must be greater or equal to 2".to_string(),
+            ));
+        }
+
+        let speed = Speed::new(self);
+        let cumulative = speed.cumulative();
+        let total = cumulative[self.pieces];
+
+        let s = Array1::linspace(T::zero(), total, count);
+        let t = s.mapv(|v| speed.inverse(&cumulative, v));
+        let points = self.evaluate(t.view());
+
+        Ok((t, points))
+    }
+
+    /// Computes the unit tangent vectors of the spline curve at the given parameter values
+    ///
+    /// Returns `ndim x M` array, the tangent is undefined (NaN) at the points with zero speed.
+    ///
+    /// # Errors
+    ///
+    /// - If the spline dimensionality is less than 2
+    ///
+    pub fn tangent(&self, t: ArrayView1<'_, T>) -> Result<Array2<T>> {
+        self.geometry_validate(2, None)?;
+
+        let d1 = self.evaluate_derivative(t, 1);
+        let speed = norms(d1.view());
+
+        Ok(d1 / &speed)
+    }
+
+    /// Computes the unit normal vectors of the spline curve at the given parameter values
+    ///
+    /// For 2-d curves the normal is the tangent rotated counterclockwise, it is consistent with
+    /// the sign of `curvature`. For 3-d curves the normal is the principal normal of Frenet frame,
+    /// it is undefined (NaN) at the points with zero curvature. Returns `ndim x M` array.
+    ///
+    /// # Errors
+    ///
+    /// - If the spline dimensionality is not 2 or 3
+    ///
+    pub fn normal(&self, t: ArrayView1<'_, T>) -> Result<Array2<T>> {
+        self.geometry_validate(2, Some(3))?;
+
+        if self.ndim == 2 {
+            let tangent = self.tangent(t)?;
+            let mut normal = Array2::zeros(tangent.raw_dim());
+
+            normal.row_mut(0).assign(&tangent.row(1).mapv(|v| -v));
+            normal.row_mut(1).assign(&tangent.row(0));
+
+            return Ok(normal);
+        }
+
+        let d1 = self.evaluate_derivative(t, 1);
+        let d2 = self.evaluate_derivative(t, 2);
+
+        // The principal normal is the direction of (r' x r'') x r'
+        let normal = cross(cross(d1.view(), d2.view()).view(), d1.view());
+        let length = norms(normal.view());
+
+        Ok(normal / &length)
+    }
+
+    /// Computes the unit binormal vectors of 3-d spline curve at the given parameter values
+    ///
+    /// The binormal is the direction of `r' x r''`, it is undefined (NaN) at the points
+    /// with zero curvature. Returns `3 x M` array.
+    ///
+    /// # Errors
+    ///
+    /// - If the spline dimensionality is not 3
+    ///
+    pub fn binormal(&self, t: ArrayView1<'_, T>) -> Result<Array2<T>> {
+        self.geometry_validate(3, Some(3))?;
+
+        let d1 = self.evaluate_derivative(t, 1);
+        let d2 = self.evaluate_derivative(t, 2);
+
+        let binormal = cross(d1.view(), d2.view());
+        let length = norms(binormal.view());
+
+        Ok(binormal / &length)
+    }
+
+    /// Computes the curvature of the spline curve at the given parameter values
+    ///
+    /// The curvature of 2-d curves is signed: it is positive if the curve turns counterclockwise.
+    /// The curvature of the curves of the higher dimensionality is non-negative:
+    /// `(|r'|^2 |r''|^2 - (r' . r'')^2)^(1/2) / |r'|^3` (`|r' x r''| / |r'|^3` for 3-d curves).
+    ///
+    /// # Errors
+    ///
+    /// - If the spline dimensionality is less than 2
+    ///
+    pub fn curvature(&self, t: ArrayView1<'_, T>) -> Result<Array1<T>> {
+        self.geometry_validate(2, None)?;
+
+        let d1 = self.evaluate_derivative(t, 1);
+        let d2 = self.evaluate_derivative(t, 2);
+        let speed = norms(d1.view());
+        let speed3 = speed.mapv(|v| v * v * v);
+
+        if self.ndim == 2 {
+            let cross = &d1.row(0) * &d2.row(1) - &d1.row(1) * &d2.row(0);
+            return Ok(cross / speed3);
+        }
+
+        let dot = (&d1 * &d2).sum_axis(Axis(0));
+        let accel = norms(d2.view());
+
+        let area =
+            (&speed * &speed * &accel * &accel - &dot * &dot).mapv(|v| v.max(T::zero()).sqrt());
+
+        Ok(area / speed3)
+    }
+
+    /// Computes the torsion of 3-d spline curve at the given parameter values
+    ///
+    /// The torsion is `(r' x r'') . r''' / |r' x r''|^2`, it is undefined (NaN) at the points
+    /// with zero curvature.
+    ///
+    /// # Errors
+    ///
+    /// - If the spline dimensionality is not 3
+    ///
+    pub fn torsion(&self, t: ArrayView1<'_, T>) -> Result<Array1<T>> {
+        self.geometry_validate(3, Some(3))?;
+
+        let d1 = self.evaluate_derivative(t, 1);
+        let d2 = self.evaluate_derivative(t, 2);
+        let d3 = self.evaluate_derivative(t, 3);
+
+        let binormal = cross(d1.view(), d2.view());
+        let length = norms(binormal.view());
+
+        Ok((&binormal * &d3).sum_axis(Axis(0)) / (&length * &length))
+    }
+
+    /// Projects the points onto the spline curve (finds the closest points of the curve)
+    ///
+    /// The points are given as `ndim x M` array (every column is the point). The closest point
+    /// is found by the coarse search over the samples of the pieces followed by the safeguarded
+    /// Newton refinement on the cubic polynomials. The search is restricted to the breaks range.
+    ///
+    /// Returns the parameter values of the closest points, `ndim x M` array of their positions
+    /// and the distances from the points to the curve.
+    ///
+    /// # Errors
+    ///
+    /// - If the points dimensionality is not equal to the spline dimensionality
+    ///
+    pub fn project(&self, points: ArrayView2<'_, T>) -> Result<(Array1<T>, Array2<T>, Array1<T>)> {
+        if points.nrows() != self.ndim {
+            return Err(InvalidInputData(format!(
+                "The points dimensionality ({}) is not equal to the spline dimensionality ({})",
+                points.nrows(),
+                self.ndim
+            )));
+        }
+
+        let curve = Curve::new(self);
+
+        // The coarse samples of the pieces
+        let mut samples: Vec<T> = Vec::with_capacity(self.pieces * PROJECTION_SAMPLES + 1);
+
+        for piece in 0..self.pieces {
+            let (a, b) = (self.breaks[piece], self.breaks[piece + 1]);
+            let step = (b - a) / T::from(PROJECTION_SAMPLES).unwrap();
+
+            samples.extend((0..PROJECTION_SAMPLES).map(|k| a + step * T::from(k).unwrap()));
+        }
+
+        samples.push(self.breaks[self.pieces]);
+        let samples = Array1::from(samples);
+
+        let sample_points = self.evaluate(samples.view());
+
+        let mut parameter = Array1::zeros((points.ncols(),));
+
+        for (k, point) in points.columns().into_iter().enumerate() {
+            let nearest = sample_points
+                .columns()
+                .into_iter()
+                .map(|sample| distance2(sample, point))
+                .enumerate()
+                .fold(
+                    (0, T::infinity()),
+                    |best, (i, d)| if d < best.1 { (i, d) } else { best },
+                )
+                .0;
+
+            let lo = samples[nearest.saturating_sub(1)];
+            let hi = samples[(nearest + 1).min(samples.len() - 1)];
+
+            parameter[k] = curve.closest(point, samples[nearest], lo, hi);
+        }
+
+        let positions = self.evaluate(parameter.view());
+        let distances = Array1::from_iter(
+            positions
+                .columns()
+                .into_iter()
+                .zip(points.columns())
+                .map(|(p, q)| distance2(p, q).sqrt()),
+        );
+
+        Ok((parameter, positions, distances))
+    }
+}
+
+/// Computes the Euclidean norms of the columns
+fn norms<T: Real<T>>(vectors: ArrayView2<'_, T>) -> Array1<T> {
+    vectors.map_axis(Axis(0), |v| v.fold(T::zero(), |acc, &x| acc + x * x).sqrt())
+}
+
+/// Computes the cross products of the columns of `3 x M` arrays
+fn cross<T: Real<T>>(a: ArrayView2<'_, T>, b: ArrayView2<'_, T>) -> Array2<T> {
+    let mut c = Array2::zeros(a.raw_dim());
+
+    for k in 0..3 {
+        let (i, j) = ((k + 1) % 3, (k + 2) % 3);
+        c.row_mut(k)
+            .assign(&(&a.row(i) * &b.row(j) - &a.row(j) * &b.row(i)));
+    }
+
+    c
+}
+
+/// Computes the squared Euclidean distance between the points
+pub(super) fn distance2<T: Real<T>>(a: ArrayView1<'_, T>, b: ArrayView1<'_, T>) -> T {
+    a.iter()
+        .zip(b.iter())
+        .fold(T::zero(), |acc, (&x, &y)| acc + (x - y) * (x - y))
+}
+
+/// The evaluator of the spline curve and its first two derivatives at the single parameter value
+pub(super) struct Curve<'s, T>
+where
+    T: Real<T>,
+{
+    /// The breaks of the spline
+    breaks: ArrayView1<'s, T>,
+
+    /// The number of pieces
+    pieces: usize,
+
+    /// The orders and the coefficients of the spline and its first two derivatives
+    derivatives: [(usize, Array2<T>); 3],
+}
+
+impl<'s, T> Curve<'s, T>
+where
+    T: Real<T>,
+{
+    pub(super) fn new(spline: &'s NdSpline<'_, T>) -> Self {
+        let derivative =
+            |nu| NdSpline::derivative_coeffs(spline.order, spline.pieces, spline.coeffs.view(), nu);
+
+        Curve {
+            breaks: spline.breaks.view(),
+            pieces: spline.pieces,
+            derivatives: [derivative(0), derivative(1), derivative(2)],
+        }
+    }
+
+    /// Evaluates `nu`-th derivative of the curve at the parameter value
+    pub(super) fn evaluate(&self, nu: usize, t: T) -> Array1<T> {
+        let piece = self
+            .breaks
+            .slice(s![1..self.pieces])
+            .iter()
+            .filter(|&&b| b <= t)
+            .count();
+
+        let x = t - self.breaks[piece];
+        let (order, coeffs) = &self.derivatives[nu];
+
+        coeffs.map_axis(Axis(1), |row| {
+            (0..*order).fold(T::zero(), |acc, k| acc * x + row[k * self.pieces + piece])
+        })
+    }
+
+    /// Finds the parameter of the closest point by safeguarded Newton method in the bracket
+    ///
+    /// The stationary points of the squared distance are the roots of `g(t) = (r(t) - q) . r'(t)`.
+    pub(super) fn closest(&self, point: ArrayView1<'_, T>, start: T, lo: T, hi: T) -> T {
+        let two = T::from(2.0).unwrap();
+        let distance = |t: T| distance2(self.evaluate(0, t).view(), point);
+
+        let (mut a, mut b) = (lo, hi);
+        let mut t = start;
+
+        for _ in 0..MAX_NEWTON_ITERATIONS {
+            let offset = self.evaluate(0, t) - point;
+            let d1 = self.evaluate(1, t);
+            let d2 = self.evaluate(2, t);
+
+            let g = offset.dot(&d1);
+            let dg = d1.dot(&d1) + offset.dot(&d2);
+
+            if g < T::zero() {
+                a = t;
+            } else {
+                b = t;
+            }
+
+            let step = t - g / dg;
+            let next = if dg > T::zero() && step > a && step < b {
+                step
+            } else {
+                (a + b) / two
+            };
+
+            let converged = (next - t).abs() <= T::epsilon() * t.abs().max(T::one());
+            t = next;
+
+            if converged || b - a <= T::epsilon() * b.abs().max(T::one()) {
+                break;
+            }
+        }
+
+        // The minimum can be at the ends of the bracket if the distance is monotone on it
+        [t, start, lo, hi]
+            .into_iter()
+            .map(|v| (v, distance(v)))
+            .fold(
+                (t, T::infinity()),
+                |best, (v, d)| if d < best.1 { (v, d) } else { best },
+            )
+            .0
+    }
+}
+
+/// The speed `|f'(t)|` of the spline curve
+struct Speed<'s, T>
+where
+    T: Real<T>,
+{
+    /// The breaks of the spline
+    breaks: ArrayView1<'s, T>,
+
+    /// The number of pieces
+    pieces: usize,
+
+    /// The order of the derivative
+    order: usize,
+
+    /// The coefficients of the derivative
+    coeffs: Array2<T>,
+}
+
+impl<'s, T> Speed<'s, T>
+where
+    T: Real<T>,
+{
+    fn new(spline: &'s NdSpline<'_, T>) -> Self {
+        let (order, coeffs) =
+            NdSpline::derivative_coeffs(spline.order, spline.pieces, spline.coeffs.view(), 1);
+
+        Speed {
+            breaks: spline.breaks.view(),
+            pieces: spline.pieces,
+            order,
+            coeffs,
+        }
+    }
+
+    /// Returns the index of the piece which contains the parameter value
+    fn piece_index(&self, t: T) -> usize {
+        let interior = self.breaks.slice(s![1..self.pieces]);
+        interior.iter().filter(|&&b| b <= t).count()
+    }
+
+    /// Evaluates the speed on the piece
+    fn value(&self, piece: usize, t: T) -> T {
+        let x = t - self.breaks[piece];
+
+        self.coeffs
+            .rows()
+            .into_iter()
+            .map(|row| {
+                (0..self.order).fold(T::zero(), |acc, k| acc * x + row[k * self.pieces + piece])
+            })
+            .fold(T::zero(), |acc, v| acc + v * v)
+            .sqrt()
+    }
+
+    /// Computes the integral of the speed over the interval `[a, b]` (`a <= b`) by the pieces
+    fn integral(&self, a: T, b: T) -> T {
+        let first = self.piece_index(a);
+        let last = self.piece_index(b);
+
+        (first..=last).fold(T::zero(), |acc, piece| {
+            let lo = if piece == first {
+                a
+            } else {
+                self.breaks[piece]
+            };
+            let hi = if piece == last {
+                b
+            } else {
+                self.breaks[piece + 1]
+            };
+
+            acc + self.piece_integral(piece, lo, hi)
+        })
+    }
+
+    /// Computes the integral of the speed over the interval on the piece by adaptive Gauss quadrature
+    fn piece_integral(&self, piece: usize, a: T, b: T) -> T {
+        if b <= a {
+            return T::zero();
+        }
+
+        let whole = self.gauss(piece, a, b);
+        self.adaptive(piece, a, b, whole, whole.abs(), MAX_DEPTH)
+    }
+
+    /// Bisects the interval until the error estimate is small relative to the integral over the whole interval
+    fn adaptive(&self, piece: usize, a: T, b: T, whole: T, scale: T, depth: usize) -> T {
+        let mid = (a + b) / T::from(2.0).unwrap();
+        let left = self.gauss(piece, a, mid);
+        let right = self.gauss(piece, mid, b);
+
+        let tolerance = T::from(1e-12)
+            .unwrap()
+            .max(T::epsilon() * T::from(100.0).unwrap());
+
+        if depth == 0 || (left + right - whole).abs() <= tolerance * scale {
+            return left + right;
+        }
+
+        self.adaptive(piece, a, mid, left, scale, depth - 1)
+            + self.adaptive(piece, mid, b, right, scale, depth - 1)
+    }
+
+    /// 5-point Gauss-Legendre quadrature of the speed over the interval on the piece
+    fn gauss(&self, piece: usize, a: T, b: T) -> T {
+        let two = T::from(2.0).unwrap();
+        let center = (a + b) / two;
+        let half = (b - a) / two;
+
+        GAUSS_NODES
+            .iter()
+            .zip(GAUSS_WEIGHTS.iter())
+            .fold(T::zero(), |acc, (&node, &weight)| {
+                let t = center + half * T::from(node).unwrap();
+                acc + T::from(weight).unwrap() * self.value(piece, t)
+            })
+            * half
+    }
+
+    /// Returns the cumulative arc length at the breaks
+    fn cumulative(&self) -> Array1<T> {
+        let mut cumulative = Array1::zeros((self.pieces + 1,));
+
+        for piece in 0..self.pieces {
+            let length = self.piece_integral(piece, self.breaks[piece], self.breaks[piece + 1]);
+            cumulative[piece + 1] = cumulative[piece] + length;
+        }
+
+        cumulative
+    }
+
+    /// Finds the parameter value for the arc length by safeguarded Newton method
+    fn inverse(&self, cumulative: &Array1<T>, s: T) -> T {
+        let total = cumulative[self.pieces];
+        let piece = cumulative
+            .slice(s![1..self.pieces])
+            .iter()
+            .filter(|&&c| c < s)
+            .count();
+
+        let (mut lo, mut hi) = (self.breaks[piece], self.breaks[piece + 1]);
+        let target = s - cumulative[piece];
+        let length = cumulative[piece + 1] - cumulative[piece];
+
+        if length <= T::zero() || target <= T::zero() {
+            return lo;
+        }
+        if target >= length {
+            return hi;
+        }
+
+        let start = lo;
+        let tolerance = T::epsilon() * T::from(16.0).unwrap() * total;
+        let mut t = lo + (hi - lo) * target / length;
+
+        for _ in 0..MAX_NEWTON_ITERATIONS {
+            let residual = self.piece_integral(piece, start, t) - target;
+
+            if residual.abs() <= tolerance {
+                break;
+            }
+
+            if residual > T::zero() {
+                hi = t;
+            } else {
+                lo = t;
+            }
+
+            let step = t - residual / self.value(piece, t);
+
+            t = if step.is_finite() && step > lo && step < hi {
+                step
+            } else {
+                (lo + hi) / T::from(2.0).unwrap()
+            };
+
+            if hi - lo <= T::epsilon() * hi.abs().max(T::one()) {
+                break;
+            }
+        }
+
+        t
+    }
+}
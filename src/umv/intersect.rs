@@ -0,0 +1,544 @@
+use ndarray::prelude::*;
+
+use crate::{polynomial, CsapsError::InvalidInputData, Real, Result};
+
+use super::{
+    geometry::{distance2, Curve},
+    validate::hyperplane_validate,
+    NdSpline,
+};
+
+/// The maximum depth of the subdivision of the pieces
+const MAX_DEPTH: usize = 40;
+
+/// The maximum number of iterations of Gauss-Newton polishing of the intersection
+const MAX_NEWTON_ITERATIONS: usize = 100;
+
+/// The maximum number of the polished pairs of the smallest segments
+const MAX_LEAVES: usize = 10_000;
+
+/// The number of the sample intervals for testing the coincidence of the arcs
+const OVERLAP_SAMPLES: usize = 4;
+
+impl<'a, T> NdSpline<'a, T>
+where
+    T: Real<T>,
+{
+    /// Finds the intersections of the spline curve with the other spline curve
+    ///
+    /// The bounding boxes of the pieces of both curves are tested for the overlap, the overlapped
+    /// pairs are subdivided recursively until the boxes are small and the intersections are
+    /// polished by Gauss-Newton method. The search is restricted to the breaks ranges of the curves.
+    ///
+    /// The isolated intersections are found. The overlapping (coincident) parts of the curves are
+    /// skipped: the pairs of the arcs lying on each other and the polished points with parallel
+    /// tangents where the curves coincide on a side are dropped, including the ends of
+    /// the overlapping parts and the smooth junctions of the curve ends. The self-intersections
+    /// of the curve are found by intersecting the curve with itself, the trivial coincidence
+    /// `t = u` and the seam of the closed curve are skipped.
+    ///
+    /// Returns `M x 2` array of the parameter pairs (the parameter of this curve and the parameter
+    /// of the other curve) sorted by the parameter of this curve and `ndim x M` array of
+    /// the intersection points.
+    ///
+    /// # Errors
+    ///
+    /// - If the spline dimensionality is less than 2
+    /// - If the dimensionalities of the curves are not equal
+    /// - If the number of the candidate pairs of the arcs exceeds the limit
+    ///
+    pub fn intersect(&self, other: &NdSpline<'_, T>) -> Result<(Array2<T>, Array2<T>)> {
+        self.geometry_validate(2, None)?;
+
+        if other.ndim != self.ndim {
+            return Err(InvalidInputData(format!(
+                "The other curve dimensionality ({}) is not equal to the spline dimensionality ({})",
+                other.ndim, self.ndim
+            )));
+        }
+
+        let first = Pieces::new(self);
+        let second = Pieces::new(other);
+
+        let extent = first
+            .extent()
+            .max(second.extent())
+            .max(T::min_positive_value());
+        let leaf_size = extent * T::epsilon().powf(T::from(0.25).unwrap());
+        let coarse_size = extent * T::epsilon().powf(T::from(0.0625).unwrap());
+        let tolerance = extent * T::epsilon().sqrt();
+
+        let polish = Polish {
+            first: Curve::new(self),
+            second: Curve::new(other),
+            first_range: (self.breaks[0], self.breaks[self.pieces]),
+            second_range: (other.breaks[0], other.breaks[other.pieces]),
+            tolerance,
+        };
+
+        let mut stack: Vec<(Segment<T>, Segment<T>, usize)> = Vec::new();
+
+        for i in 0..self.pieces {
+            let a = first.segment(i, self.breaks[i], self.breaks[i + 1]);
+
+            for j in 0..other.pieces {
+                let b = second.segment(j, other.breaks[j], other.breaks[j + 1]);
+                stack.push((a.clone(), b, 0));
+            }
+        }
+
+        let mut found: Vec<(T, T)> = Vec::new();
+        let mut skipped: Vec<(T, T)> = Vec::new();
+        let t_merge = (polish.first_range.1 - polish.first_range.0) * T::epsilon().cbrt();
+        let u_merge = (polish.second_range.1 - polish.second_range.0) * T::epsilon().cbrt();
+        let mut leaves = 0;
+
+        let is_known = |known: &[(T, T)], (t, u): (T, T)| {
+            known
+                .iter()
+                .any(|&(kt, ku)| (kt - t).abs() <= t_merge && (ku - u).abs() <= u_merge)
+        };
+
+        while let Some((a, b, depth)) = stack.pop() {
+            if !a.overlaps(&b, tolerance) {
+                continue;
+            }
+
+            let (size_a, size_b) = (a.size(), b.size());
+
+            // The overlapping arcs have no isolated intersections
+            if size_a.min(size_b) <= coarse_size && polish.coincident(&a, &b) {
+                continue;
+            }
+
+            if (size_a <= leaf_size && size_b <= leaf_size) || depth >= MAX_DEPTH {
+                leaves += 1;
+
+                if leaves > MAX_LEAVES {
+                    return Err(InvalidInputData(format!(
+                        "The number of the candidate pairs of the arcs exceeds {}",
+                        MAX_LEAVES
+                    )));
+                }
+
+                let start = (a.middle(), b.middle());
+
+                // The leaves around the already polished points converge to the same points
+                if is_known(&found, start) || is_known(&skipped, start) {
+                    continue;
+                }
+
+                if let Some(solution) = polish.solve(start.0, start.1) {
+                    if is_known(&found, solution) || is_known(&skipped, solution) {
+                        continue;
+                    }
+
+                    if polish.is_overlap(solution.0, solution.1) {
+                        skipped.push(solution);
+                    } else {
+                        found.push(solution);
+                    }
+                }
+                continue;
+            }
+
+            if size_a >= size_b {
+                let (left, right) = first.split(&a);
+                stack.push((left, b.clone(), depth + 1));
+                stack.push((right, b, depth + 1));
+            } else {
+                let (left, right) = second.split(&b);
+                stack.push((a.clone(), left, depth + 1));
+                stack.push((a, right, depth + 1));
+            }
+        }
+
+        found.sort_by(|x, y| x.partial_cmp(y).unwrap());
+
+        let parameters = Array2::from_shape_fn((found.len(), 2), |(i, k)| {
+            if k == 0 {
+                found[i].0
+            } else {
+                found[i].1
+            }
+        });
+        let points = self.evaluate(parameters.column(0));
+
+        Ok((parameters, points))
+    }
+
+    /// Finds the intersections of the 2-d spline curve with the line
+    ///
+    /// The line is given by the point on the line and the direction vector. The signed distance
+    /// from the curve to the line is the cubic polynomial on every piece, its roots are
+    /// the parameters of the intersections. The pieces lying on the line have no isolated
+    /// intersections and are skipped.
+    ///
+    /// Returns the sorted parameter values of the intersections and `2 x M` array of
+    /// the intersection points.
+    ///
+    /// # Errors
+    ///
+    /// - If the spline dimensionality is not equal to 2
+    /// - If the point or the direction is not the finite 2-d vector or the direction is zero
+    ///
+    pub fn intersect_line(
+        &self,
+        point: ArrayView1<'_, T>,
+        direction: ArrayView1<'_, T>,
+    ) -> Result<(Array1<T>, Array2<T>)> {
+        self.geometry_validate(2, Some(2))?;
+        hyperplane_validate(point, direction, 2, "direction")?;
+
+        let normal = array![-direction[1], direction[0]];
+        Ok(self.intersect_hyperplane(point, normal.view()))
+    }
+
+    /// Finds the intersections of the 3-d spline curve with the plane
+    ///
+    /// The plane is given by the point on the plane and the normal vector. The signed distance
+    /// from the curve to the plane is the cubic polynomial on every piece, its roots are
+    /// the parameters of the intersections. The pieces lying on the plane have no isolated
+    /// intersections and are skipped.
+    ///
+    /// Returns the sorted parameter values of the intersections and `3 x M` array of
+    /// the intersection points.
+    ///
+    /// # Errors
+    ///
+    /// - If the spline dimensionality is not equal to 3
+    /// - If the point or the normal is not the finite 3-d vector or the normal is zero
+    ///
+    pub fn intersect_plane(
+        &self,
+        point: ArrayView1<'_, T>,
+        normal: ArrayView1<'_, T>,
+    ) -> Result<(Array1<T>, Array2<T>)> {
+        self.geometry_validate(3, Some(3))?;
+        hyperplane_validate(point, normal, 3, "normal")?;
+
+        Ok(self.intersect_hyperplane(point, normal))
+    }
+
+    /// Finds the roots of the projection of the curve onto the normal of the hyperplane
+    fn intersect_hyperplane(
+        &self,
+        point: ArrayView1<'_, T>,
+        normal: ArrayView1<'_, T>,
+    ) -> (Array1<T>, Array2<T>) {
+        let parameters = self.level_roots(normal, normal.dot(&point), false);
+        let parameters = Array1::from(parameters);
+        let points = self.evaluate(parameters.view());
+
+        (parameters, points)
+    }
+}
+
+/// The parameter interval within the piece with the bounding box of the curve on it
+#[derive(Clone)]
+struct Segment<T> {
+    /// The piece index
+    piece: usize,
+
+    /// The start parameter
+    start: T,
+
+    /// The end parameter
+    end: T,
+
+    /// The minimal coordinates of the curve on the interval
+    lower: Array1<T>,
+
+    /// The maximal coordinates of the curve on the interval
+    upper: Array1<T>,
+}
+
+impl<T> Segment<T>
+where
+    T: Real<T>,
+{
+    /// Returns true if the bounding boxes overlap within the tolerance
+    fn overlaps(&self, other: &Segment<T>, tolerance: T) -> bool {
+        (0..self.lower.len()).all(|d| {
+            self.lower[d] <= other.upper[d] + tolerance
+                && other.lower[d] <= self.upper[d] + tolerance
+        })
+    }
+
+    /// Returns true if the bounding box is inside the other bounding box within the tolerance
+    fn inside(&self, other: &Segment<T>, tolerance: T) -> bool {
+        (0..self.lower.len()).all(|d| {
+            self.lower[d] >= other.lower[d] - tolerance
+                && self.upper[d] <= other.upper[d] + tolerance
+        })
+    }
+
+    /// Returns the diagonal length of the bounding box
+    fn size(&self) -> T {
+        distance2(self.lower.view(), self.upper.view()).sqrt()
+    }
+
+    /// Returns the middle parameter of the interval
+    fn middle(&self) -> T {
+        (self.start + self.end) / T::from(2.0).unwrap()
+    }
+}
+
+/// The piecewise polynomials of the spline curve coordinates
+struct Pieces<'s, T>
+where
+    T: Real<T>,
+{
+    /// The spline dimensionality
+    ndim: usize,
+
+    /// The spline order
+    order: usize,
+
+    /// The number of pieces
+    pieces: usize,
+
+    /// The breaks of the spline
+    breaks: ArrayView1<'s, T>,
+
+    /// The coefficients of the spline
+    coeffs: ArrayView2<'s, T>,
+}
+
+impl<'s, T> Pieces<'s, T>
+where
+    T: Real<T>,
+{
+    fn new(spline: &'s NdSpline<'_, T>) -> Self {
+        Pieces {
+            ndim: spline.ndim,
+            order: spline.order,
+            pieces: spline.pieces,
+            breaks: spline.breaks.view(),
+            coeffs: spline.coeffs.view(),
+        }
+    }
+
+    /// Returns the polynomial coefficients of the coordinate on the piece
+    fn polynomial(&self, dim: usize, piece: usize) -> Vec<T> {
+        let row = self.coeffs.row(dim);
+        (0..self.order)
+            .map(|k| row[k * self.pieces + piece])
+            .collect()
+    }
+
+    /// Computes the segment with the exact bounding box of the piece on the parameter interval
+    ///
+    /// The extrema of the coordinates are at the ends of the interval or at the roots of
+    /// the derivatives of the coordinate polynomials.
+    fn segment(&self, piece: usize, start: T, end: T) -> Segment<T> {
+        let ndim = self.ndim;
+        let origin = self.breaks[piece];
+        let (a, b) = (start - origin, end - origin);
+
+        let mut lower = Array1::zeros((ndim,));
+        let mut upper = Array1::zeros((ndim,));
+
+        for d in 0..ndim {
+            let coeffs = self.polynomial(d, piece);
+            let dcoeffs = polynomial::derivative(&coeffs);
+
+            let (min, max) = polynomial::roots(&dcoeffs, a, b)
+                .into_iter()
+                .chain([a, b])
+                .map(|x| polynomial::evaluate(&coeffs, x))
+                .fold((T::infinity(), T::neg_infinity()), |(min, max), v| {
+                    (min.min(v), max.max(v))
+                });
+
+            lower[d] = min;
+            upper[d] = max;
+        }
+
+        Segment {
+            piece,
+            start,
+            end,
+            lower,
+            upper,
+        }
+    }
+
+    /// Splits the segment in halves
+    fn split(&self, segment: &Segment<T>) -> (Segment<T>, Segment<T>) {
+        let middle = segment.middle();
+
+        (
+            self.segment(segment.piece, segment.start, middle),
+            self.segment(segment.piece, middle, segment.end),
+        )
+    }
+
+    /// Returns the diagonal length of the bounding box of the whole curve
+    fn extent(&self) -> T {
+        let pieces = self.pieces;
+        let breaks = self.breaks;
+
+        let (lower, upper) = (0..pieces)
+            .map(|piece| self.segment(piece, breaks[piece], breaks[piece + 1]))
+            .fold(
+                (
+                    Array1::from_elem((self.ndim,), T::infinity()),
+                    Array1::from_elem((self.ndim,), T::neg_infinity()),
+                ),
+                |(lower, upper), s| {
+                    (
+                        ndarray::Zip::from(&lower)
+                            .and(&s.lower)
+                            .map_collect(|&a, &b| a.min(b)),
+                        ndarray::Zip::from(&upper)
+                            .and(&s.upper)
+                            .map_collect(|&a, &b| a.max(b)),
+                    )
+                },
+            );
+
+        distance2(lower.view(), upper.view()).sqrt()
+    }
+}
+
+/// Gauss-Newton polishing of the intersection of two curves
+struct Polish<'s, T>
+where
+    T: Real<T>,
+{
+    /// The first curve
+    first: Curve<'s, T>,
+
+    /// The second curve
+    second: Curve<'s, T>,
+
+    /// The parameter range of the first curve
+    first_range: (T, T),
+
+    /// The parameter range of the second curve
+    second_range: (T, T),
+
+    /// The distance tolerance of the intersection
+    tolerance: T,
+}
+
+impl<'s, T> Polish<'s, T>
+where
+    T: Real<T>,
+{
+    /// Solves `r1(t) = r2(u)` from the starting parameters in the least squares sense
+    ///
+    /// Returns the parameters if the distance between the curve points is within the tolerance.
+    fn solve(&self, t: T, u: T) -> Option<(T, T)> {
+        let (mut t, mut u) = (t, u);
+
+        for _ in 0..MAX_NEWTON_ITERATIONS {
+            let residual = self.first.evaluate(0, t) - self.second.evaluate(0, u);
+            let d1 = self.first.evaluate(1, t);
+            let d2 = -self.second.evaluate(1, u);
+
+            // The normal equations of the linearized problem `[d1 d2] * [dt du] = -residual`
+            let (a11, a12, a22) = (d1.dot(&d1), d1.dot(&d2), d2.dot(&d2));
+            let (b1, b2) = (-d1.dot(&residual), -d2.dot(&residual));
+            let det = a11 * a22 - a12 * a12;
+
+            if det <= T::epsilon() * a11 * a22 {
+                break;
+            }
+
+            let dt = (b1 * a22 - b2 * a12) / det;
+            let du = (a11 * b2 - a12 * b1) / det;
+
+            let next_t = (t + dt).max(self.first_range.0).min(self.first_range.1);
+            let next_u = (u + du).max(self.second_range.0).min(self.second_range.1);
+
+            let converged = (next_t - t).abs() <= T::epsilon() * t.abs().max(T::one())
+                && (next_u - u).abs() <= T::epsilon() * u.abs().max(T::one());
+
+            t = next_t;
+            u = next_u;
+
+            if converged {
+                break;
+            }
+        }
+
+        let distance = distance2(
+            self.first.evaluate(0, t).view(),
+            self.second.evaluate(0, u).view(),
+        )
+        .sqrt();
+
+        if distance <= self.tolerance {
+            Some((t, u))
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if one of the arcs of the segments lies on the other arc
+    ///
+    /// The arc can lie on the other arc only if its bounding box is inside the bounding box of
+    /// the other arc, the samples are projected only for such pairs.
+    fn coincident(&self, a: &Segment<T>, b: &Segment<T>) -> bool {
+        let (range_a, range_b) = ((a.start, a.end), (b.start, b.end));
+
+        (a.inside(b, self.tolerance) && self.lies_on(&self.first, range_a, &self.second, range_b))
+            || (b.inside(a, self.tolerance)
+                && self.lies_on(&self.second, range_b, &self.first, range_a))
+    }
+
+    /// Returns true if the polished intersection is the point of the overlapping parts
+    ///
+    /// The tangents of the curves are parallel at the point of the overlapping parts and
+    /// the curves coincide on one side of the point, unlike the tangential intersection where
+    /// the curves diverge on both sides. The junction of the ends of the curves with parallel
+    /// tangents (the seam of the closed curve intersected with itself) is also the overlap.
+    fn is_overlap(&self, t: T, u: T) -> bool {
+        let d1 = self.first.evaluate(1, t);
+        let d2 = self.second.evaluate(1, u);
+
+        let (a11, a12, a22) = (d1.dot(&d1), d1.dot(&d2), d2.dot(&d2));
+
+        if a11 * a22 - a12 * a12 > T::epsilon().sqrt() * a11 * a22 {
+            return false;
+        }
+
+        let probe = T::epsilon().powf(T::from(0.125).unwrap());
+        let (t0, t1) = self.first_range;
+        let (u0, u1) = self.second_range;
+
+        if (t <= t0 || t >= t1) && (u <= u0 || u >= u1) {
+            return true;
+        }
+
+        let h = (t1 - t0) * probe;
+        let g = (u1 - u0) * probe * T::from(2.0).unwrap();
+        let window = ((u - g).max(u0), (u + g).min(u1));
+
+        [((t - h).max(t0), t), (t, (t + h).min(t1))]
+            .into_iter()
+            .filter(|&(lo, hi)| hi - lo >= h / T::from(2.0).unwrap())
+            .any(|side| self.lies_on(&self.first, side, &self.second, window))
+    }
+
+    /// Returns true if the samples of the arc of the `from` curve lie on the arc of the `to` curve
+    fn lies_on(
+        &self,
+        from: &Curve<'s, T>,
+        range: (T, T),
+        to: &Curve<'s, T>,
+        to_range: (T, T),
+    ) -> bool {
+        let middle = (to_range.0 + to_range.1) / T::from(2.0).unwrap();
+        let step = (range.1 - range.0) / T::from(OVERLAP_SAMPLES).unwrap();
+
+        (0..=OVERLAP_SAMPLES).all(|k| {
+            let point = from.evaluate(0, range.0 + step * T::from(k).unwrap());
+            let closest = to.closest(point.view(), middle, to_range.0, to_range.1);
+
+            distance2(to.evaluate(0, closest).view(), point.view()).sqrt() <= self.tolerance
+        })
+    }
+}
@@ -1,11 +1,13 @@
 use ndarray::{concatenate, prelude::*, s};
+use sprs::CsMat;
 
 use crate::{
-    ndarrayext::{diff, to_2d},
-    sprsext, Real, RealRef, Result,
+    hermite::{Functional, HermiteSmoother},
+    ndarrayext::{diff, to_2d, unwrap_phase},
+    smooth_from_cutoff, sprsext, Real, RealRef, Result,
 };
 
-use super::{CubicSmoothingSpline, NdSpline};
+use super::{CubicSmoothingSpline, NdSegmentedSpline, NdSpline};
 
 impl<'a, T, D> CubicSmoothingSpline<'a, T, D>
 where
@@ -15,12 +17,19 @@ where
 {
     pub(super) fn make_spline(&mut self) -> Result<()> {
         // todo!();
+        if let Some((cutoff, spacing)) = self.cutoff {
+            self.smooth = Some(smooth_from_cutoff(cutoff, spacing)?);
+        }
+
         let one = T::one();
         let two = T::from::<f64>(2.0).unwrap();
         let three = T::from::<f64>(3.0).unwrap();
         let six = T::from::<f64>(6.0).unwrap();
 
-        let breaks = self.x;
+        let breaks: CowArray<T, Ix1> = match &self.x_transform {
+            Some(transform) => self.x.mapv(|v| transform.forward(v)).into(),
+            None => self.x.into(),
+        };
 
         let weights_default = Array1::ones(breaks.raw_dim());
         let weights = self
@@ -33,7 +42,30 @@ where
         let axis = self.axis.unwrap_or_else(|| Axis(self.y.ndim() - 1));
         self.axis = Some(axis);
 
-        let y = to_2d(self.y.view(), axis)?;
+        let y: CowArray<T, Ix2> = match (self.circular, &self.y_transform) {
+            (Some(_), _) => unwrap_phase(to_2d(self.y.view(), axis)?).into(),
+            (None, Some(transform)) => to_2d(self.y.view(), axis)?
+                .mapv(|v| transform.forward(v))
+                .into(),
+            (None, None) => to_2d(self.y.view(), axis)?.into(),
+        };
+
+        if let Some(threshold) = self.gap_threshold {
+            let segmented_spline = self.make_segmented_spline(y.view(), weights, threshold)?;
+            self.segmented_spline = Some(segmented_spline);
+
+            return Ok(());
+        }
+
+        if self.derivative_x.is_some() || self.jumps.is_some() || self.kinks.is_some() {
+            let (smooth, spline) = self.make_hermite_spline(y.view(), weights)?;
+
+            self.smooth = Some(smooth);
+            self.spline = Some(spline);
+
+            return Ok(());
+        }
+
         let dydx = diff(y.view(), Some(Axis(1))) / &dx;
 
         let pcount = breaks.len();
@@ -53,43 +85,10 @@ where
         // General computing cubic smoothing spline for NxM data (3 and more data points)
         let ones = |n| Array1::<T>::ones((n,));
 
-        let qtwq = {
-            let qt = {
-                let odx = ones(pcount - 1) / &dx;
-                let odx_head = odx.slice(s![..-1]).insert_axis(Axis(0)).into_owned();
-                let odx_tail = odx.slice(s![1i32..]).insert_axis(Axis(0)).into_owned();
-                drop(odx);
-                let odx_body = -(&odx_tail + &odx_head);
-                let diags_qt = concatenate![Axis(0), odx_head, odx_body, odx_tail];
-
-                sprsext::diags(diags_qt, &[0, 1, 2], (pcount - 2, pcount))
-            };
-
-            let diags_sqrw = (ones(pcount) / weights.mapv(T::sqrt)).insert_axis(Axis(0));
-            let sqrw = sprsext::diags(diags_sqrw, &[0], (pcount, pcount));
-            let qtw = &qt * &sqrw;
-            drop(sqrw);
-            drop(qt);
-            let qtw_t = qtw.transpose_view();
-
-            &qtw * &qtw_t
-        };
-
-        let r = {
-            let dx_head = dx.slice(s![..-1]).insert_axis(Axis(0)).into_owned();
-            let dx_tail = dx.slice(s![1i32..]).insert_axis(Axis(0)).into_owned();
-            let dx_body = (&dx_tail + &dx_head) * two;
-            let diags_r = concatenate![Axis(0), dx_tail, dx_body, dx_head];
-
-            sprsext::diags(diags_r, &[-1, 0, 1], (pcount - 2, pcount - 2))
-        };
-
-        let auto_smooth = || {
-            let trace = |m| sprsext::diagonal(m, 0).sum();
-            one / (one + trace(&r) / (six * trace(&qtwq)))
-        };
+        let qtwq = weighted_gram(&second_differences(dx.view()), weights);
+        let r = continuity_matrix(dx.view());
 
-        let smooth = self.smooth.unwrap_or_else(auto_smooth);
+        let smooth = self.smooth.unwrap_or_else(|| smooth_from_traces(&r, &qtwq));
         let s1 = six * (one - smooth);
 
         // Solve linear system Ax = b for the 2nd derivatives
@@ -159,4 +158,209 @@ where
 
         Ok(())
     }
+
+    /// Computes the spline in Hermite basis for the values, the first-derivative observations
+    /// and the known discontinuities (Hermite smoothing)
+    fn make_hermite_spline(
+        &self,
+        y: ArrayView2<'_, T>,
+        weights: ArrayView1<'_, T>,
+    ) -> Result<(T, NdSpline<'a, T>)> {
+        let x = self.x;
+        let axis = self.axis.unwrap();
+
+        let empty = Array1::<T>::zeros((0,));
+        let jumps = self.jumps.unwrap_or_else(|| empty.view());
+        let kinks = self.kinks.unwrap_or_else(|| empty.view());
+        let derivative_x = self.derivative_x.unwrap_or_else(|| empty.view());
+
+        let derivative_y = match &self.derivative_y {
+            Some(derivative_y) => to_2d(derivative_y.view(), axis)?.to_owned(),
+            None => Array2::zeros((y.nrows(), 0)),
+        };
+
+        let derivative_weights_default = Array1::ones(derivative_x.raw_dim());
+        let derivative_weights = self
+            .derivative_weights
+            .map(|v| v.reborrow())
+            .unwrap_or_else(|| derivative_weights_default.view());
+
+        let smooth = self.smooth.unwrap_or_else(|| auto_smooth(x, weights));
+
+        // The knots are all value and derivative data sites and the discontinuities locations
+        let mut knots: Vec<T> = x
+            .iter()
+            .chain(derivative_x.iter())
+            .chain(jumps.iter())
+            .chain(kinks.iter())
+            .cloned()
+            .collect();
+        knots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        knots.dedup_by(|a, b| a.almost_equals(*b));
+
+        let knots = Array1::from(knots);
+        let mut smoother = HermiteSmoother::with_discontinuities(
+            knots.clone(),
+            y.nrows(),
+            &jumps.to_vec(),
+            &kinks.to_vec(),
+        );
+
+        for (i, &xv) in x.iter().enumerate() {
+            smoother.add_observation(Functional::Value(xv), weights[i], y.column(i));
+        }
+
+        for (i, &xv) in derivative_x.iter().enumerate() {
+            smoother.add_observation(
+                Functional::Derivative(xv),
+                derivative_weights[i],
+                derivative_y.column(i),
+            );
+        }
+
+        let solution = smoother.solve(smooth);
+        let coeffs = smoother.pp_coeffs(&solution);
+
+        Ok((smooth, NdSpline::new(knots, coeffs)))
+    }
+
+    /// Computes the splines for the segments of the data which are split at the gaps
+    fn make_segmented_spline(
+        &self,
+        y: ArrayView2<'_, T>,
+        weights: ArrayView1<'_, T>,
+        threshold: T,
+    ) -> Result<NdSegmentedSpline<'a, T>> {
+        let x = self.x;
+
+        let mut bounds = vec![0];
+        bounds.extend((1..x.len()).filter(|&i| x[i] - x[i - 1] > threshold));
+        bounds.push(x.len());
+
+        let mut segments = Vec::with_capacity(bounds.len() - 1);
+        let mut ranges = Vec::with_capacity(bounds.len() - 1);
+        let mut smooths = Vec::with_capacity(bounds.len() - 1);
+
+        for bound in bounds.windows(2) {
+            let (start, end) = (bound[0], bound[1]);
+            let xs = x.slice(s![start..end]);
+            let ys = y.slice(s![.., start..end]).to_owned();
+
+            ranges.push((xs[0], xs[xs.len() - 1]));
+
+            // The segment with the single data site is the constant
+            if xs.len() == 1 {
+                let breaks = Array1::from_elem((2,), xs[0]);
+
+                segments.push(NdSpline::new(breaks, ys));
+                smooths.push(T::one());
+
+                continue;
+            }
+
+            let segment = CubicSmoothingSpline::new(xs, &ys)
+                .with_weights(weights.slice(s![start..end]))
+                .with_optional_smooth(self.smooth)
+                .make()?;
+
+            let spline = segment.spline.as_ref().unwrap();
+
+            segments.push(NdSpline::new(
+                spline.breaks().to_owned(),
+                spline.coeffs().to_owned(),
+            ));
+            smooths.push(segment.smooth.unwrap());
+        }
+
+        Ok(NdSegmentedSpline::new(
+            segments,
+            ranges,
+            smooths,
+            self.gap_fill,
+        ))
+    }
+}
+
+/// Creates `(n-2)xn` matrix `Q'` of the second divided differences from the pieces lengths `dx`
+pub(crate) fn second_differences<T>(dx: ArrayView1<'_, T>) -> CsMat<T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    let pcount = dx.len() + 1;
+
+    let odx = dx.mapv(|h| T::one() / h);
+    let odx_head = odx.slice(s![..-1]).insert_axis(Axis(0)).into_owned();
+    let odx_tail = odx.slice(s![1i32..]).insert_axis(Axis(0)).into_owned();
+    let odx_body = -(&odx_tail + &odx_head);
+    let diags_qt = concatenate![Axis(0), odx_head, odx_body, odx_tail];
+
+    sprsext::diags(diags_qt, &[0, 1, 2], (pcount - 2, pcount))
+}
+
+/// Creates `(n-2)x(n-2)` tridiagonal matrix `R` of the continuity equations of the cubic spline
+/// from the pieces lengths `dx`
+pub(crate) fn continuity_matrix<T>(dx: ArrayView1<'_, T>) -> CsMat<T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    let icount = dx.len() - 1;
+
+    let dx_head = dx.slice(s![..-1]).insert_axis(Axis(0)).into_owned();
+    let dx_tail = dx.slice(s![1i32..]).insert_axis(Axis(0)).into_owned();
+    let dx_body = (&dx_tail + &dx_head) * T::from(2.0).unwrap();
+    let diags_r = concatenate![Axis(0), dx_tail, dx_body, dx_head];
+
+    sprsext::diags(diags_r, &[-1, 0, 1], (icount, icount))
+}
+
+/// Creates `(n-2)x(n-2)` matrix `Q'W^-1Q` from the second differences matrix `Q'` and the weights
+///
+/// The matrix is computed as the product of `Q'W^-1/2` by its transpose to be exactly symmetric.
+pub(crate) fn weighted_gram<T>(qt: &CsMat<T>, weights: ArrayView1<'_, T>) -> CsMat<T>
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    let pcount = weights.len();
+
+    let diags_sqrw = weights.mapv(|w| T::one() / w.sqrt()).insert_axis(Axis(0));
+    let sqrw = sprsext::diags(diags_sqrw, &[0], (pcount, pcount));
+    let qtw = qt * &sqrw;
+
+    &qtw * &qtw.transpose_view()
+}
+
+/// Computes the automatic smoothing parameter of the cubic smoothing spline
+/// for the data sites `x` and the weights
+///
+/// The smoothing parameter is the same as `CubicSmoothingSpline` computes when `smooth` is not set.
+pub(crate) fn auto_smooth<T>(x: ArrayView1<'_, T>, weights: ArrayView1<'_, T>) -> T
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    if x.len() < 3 {
+        return T::one();
+    }
+
+    let dx = diff(x, None);
+    let qtwq = weighted_gram(&second_differences(dx.view()), weights);
+    let r = continuity_matrix(dx.view());
+
+    smooth_from_traces(&r, &qtwq)
+}
+
+/// Computes the smoothing parameter from the traces of `R` and `Q'W^-1Q` matrices
+fn smooth_from_traces<T>(r: &CsMat<T>, qtwq: &CsMat<T>) -> T
+where
+    T: Real<T>,
+    for<'r> &'r T: RealRef<&'r T, T>,
+{
+    let one = T::one();
+    let six = T::from(6.0).unwrap();
+    let trace = |m| sprsext::diagonal(m, 0).sum();
+
+    one / (one + trace(r) / (six * trace(qtwq)))
 }
@@ -0,0 +1,94 @@
+use ndarray::prelude::*;
+
+use crate::{polynomial, Real, Result};
+
+use super::NdSpline;
+
+impl<'a, T> NdSpline<'a, T>
+where
+    T: Real<T>,
+{
+    /// Finds all x values (parameters) where the spline component is equal to the level
+    ///
+    /// The polynomial of every piece is solved on the piece interval: the interval is split into
+    /// the monotone intervals by the roots of the derivative and the roots are bracketed
+    /// in them. The double roots (the touch points where the spline reaches the level without
+    /// crossing) are also found. The search is restricted to the breaks range. The pieces
+    /// which are constant and equal to the level have no isolated roots and are skipped.
+    ///
+    /// Returns the sorted x values.
+    ///
+    /// # Errors
+    ///
+    /// - If the component index is out of the spline dimensionality
+    /// - If the level is not finite
+    ///
+    pub fn level_crossings(&self, level: T, dim: usize) -> Result<Array1<T>> {
+        self.level_validate(level, dim)?;
+
+        let weights = self.component_weights(dim);
+        Ok(Array1::from(self.level_roots(weights.view(), level, false)))
+    }
+
+    /// Finds the first x value (parameter) where the spline component reaches the level
+    ///
+    /// The pieces are solved in order as in `level_crossings` method and the search stops
+    /// on the first root (the crossing or the touch point). Returns `None` if the spline component
+    /// does not reach the level in the breaks range.
+    ///
+    /// # Errors
+    ///
+    /// - If the component index is out of the spline dimensionality
+    /// - If the level is not finite
+    ///
+    pub fn first_crossing(&self, level: T, dim: usize) -> Result<Option<T>> {
+        self.level_validate(level, dim)?;
+
+        let weights = self.component_weights(dim);
+        Ok(self
+            .level_roots(weights.view(), level, true)
+            .first()
+            .copied())
+    }
+
+    /// Finds the sorted roots of the weighted sum of the spline components minus the level
+    ///
+    /// The roots at the breaks are found on both adjacent pieces, they are merged.
+    pub(super) fn level_roots(&self, weights: ArrayView1<'_, T>, level: T, first: bool) -> Vec<T> {
+        let span = self.breaks[self.pieces] - self.breaks[0];
+        let merge = span * T::epsilon().sqrt();
+
+        let mut roots: Vec<T> = Vec::new();
+
+        for piece in 0..self.pieces {
+            let mut coeffs: Vec<T> = (0..self.order)
+                .map(|k| weights.dot(&self.coeffs.column(k * self.pieces + piece)))
+                .collect();
+            coeffs[self.order - 1] -= level;
+
+            let start = self.breaks[piece];
+            let length = self.breaks[piece + 1] - start;
+
+            for root in polynomial::roots(&coeffs, T::zero(), length) {
+                let x = start + root;
+
+                if roots.last().is_none_or(|&last| x - last > merge) {
+                    roots.push(x);
+                }
+            }
+
+            if first && !roots.is_empty() {
+                break;
+            }
+        }
+
+        roots
+    }
+
+    /// Returns the weights selecting the spline component
+    fn component_weights(&self, dim: usize) -> Array1<T> {
+        let mut weights = Array1::zeros((self.ndim,));
+        weights[dim] = T::one();
+        weights
+    }
+}
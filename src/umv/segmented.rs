@@ -0,0 +1,196 @@
+use ndarray::{Array1, Array2, ArrayView1, Axis};
+
+use crate::Real;
+
+use super::NdSpline;
+
+/// The policy of evaluating the segmented spline inside the data gaps
+///
+/// See `CubicSmoothingSpline::with_gap_threshold` method.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GapFill<T> {
+    /// The values inside the gaps are NaN
+    Nan,
+
+    /// The values inside the gaps are equal to the given constant
+    Value(T),
+
+    /// The values inside the gaps are equal to the last value of the left segment
+    Hold,
+
+    /// The values inside the gaps are linearly interpolated between the adjacent segments ends
+    Linear,
+}
+
+/// N-dimensional (univariate/multivariate) piecewise spline of independent segments
+///
+/// `NdSegmentedSpline` represents the set of `NdSpline` segments which have been computed
+/// independently for the data split at the gaps. The segments are evaluated inside their
+/// data sites ranges, the first and the last segments are extrapolated outside the data range
+/// and the values inside the gaps are given by `GapFill` policy.
+///
+#[derive(Debug)]
+pub struct NdSegmentedSpline<'a, T>
+where
+    T: Real<T>,
+{
+    /// The spline dimensionality
+    ndim: usize,
+
+    /// The spline segments
+    segments: Vec<NdSpline<'a, T>>,
+
+    /// The data sites ranges `(first, last)` of the segments
+    ranges: Vec<(T, T)>,
+
+    /// The smoothing parameters of the segments
+    smooths: Vec<T>,
+
+    /// The policy of evaluating inside the gaps
+    fill: GapFill<T>,
+}
+
+impl<'a, T> NdSegmentedSpline<'a, T>
+where
+    T: Real<T>,
+{
+    /// Creates `NdSegmentedSpline` struct from the given segments
+    ///
+    /// # Arguments
+    ///
+    /// - `segments` -- The splines of the segments in increasing order of the data sites
+    /// - `ranges` -- The data sites ranges `(first, last)` of the segments
+    /// - `smooths` -- The smoothing parameters of the segments
+    /// - `fill` -- The policy of evaluating inside the gaps
+    ///
+    /// # Notes
+    ///
+    /// - `NdSegmentedSpline` struct should not be created directly by a user in most cases.
+    ///
+    pub fn new(
+        segments: Vec<NdSpline<'a, T>>,
+        ranges: Vec<(T, T)>,
+        smooths: Vec<T>,
+        fill: GapFill<T>,
+    ) -> NdSegmentedSpline<'a, T> {
+        let ndim = segments[0].ndim();
+
+        NdSegmentedSpline {
+            ndim,
+            segments,
+            ranges,
+            smooths,
+            fill,
+        }
+    }
+
+    /// Returns the spline dimensionality
+    pub fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    /// Returns the spline segments
+    pub fn segments(&self) -> &[NdSpline<'a, T>] {
+        &self.segments
+    }
+
+    /// Returns the data sites ranges `(first, last)` of the segments
+    pub fn ranges(&self) -> &[(T, T)] {
+        &self.ranges
+    }
+
+    /// Returns the smoothing parameters of the segments
+    pub fn smooths(&self) -> &[T] {
+        &self.smooths
+    }
+
+    /// Returns the policy of evaluating inside the gaps
+    pub fn fill(&self) -> GapFill<T> {
+        self.fill
+    }
+
+    /// Evaluates the spline on the given data sites
+    pub fn evaluate(&self, xi: ArrayView1<'_, T>) -> Array2<T> {
+        self.evaluate_derivative(xi, 0)
+    }
+
+    /// Evaluates `nu`-th derivative of the spline on the given data sites
+    pub(crate) fn evaluate_derivative(&self, xi: ArrayView1<'_, T>, nu: usize) -> Array2<T> {
+        let mut values = Array2::zeros((self.ndim, xi.len()));
+
+        // The sites of every segment and the sites inside every gap
+        let mut segment_sites: Vec<Vec<usize>> = vec![Vec::new(); self.segments.len()];
+        let mut gap_sites: Vec<Vec<usize>> = vec![Vec::new(); self.segments.len() - 1];
+
+        for (i, &x) in xi.iter().enumerate() {
+            // The index of the first segment which ends after the site
+            let k = self.ranges.partition_point(|&(_, last)| last < x);
+
+            if k == self.segments.len() || k == 0 || x >= self.ranges[k].0 {
+                segment_sites[k.min(self.segments.len() - 1)].push(i);
+            } else {
+                gap_sites[k - 1].push(i);
+            }
+        }
+
+        for (k, sites) in segment_sites.iter().enumerate() {
+            if sites.is_empty() {
+                continue;
+            }
+
+            let x = Array1::from_iter(sites.iter().map(|&i| xi[i]));
+            let segment_values = self.segments[k].evaluate_derivative(x.view(), nu);
+
+            for (col, &i) in sites.iter().enumerate() {
+                values.column_mut(i).assign(&segment_values.column(col));
+            }
+        }
+
+        for (k, sites) in gap_sites.iter().enumerate() {
+            if sites.is_empty() {
+                continue;
+            }
+
+            let x = Array1::from_iter(sites.iter().map(|&i| xi[i]));
+            let gap_values = self.evaluate_gap(k, x.view(), nu);
+
+            for (col, &i) in sites.iter().enumerate() {
+                values.column_mut(i).assign(&gap_values.column(col));
+            }
+        }
+
+        values
+    }
+
+    /// Evaluates `nu`-th derivative of the fill inside the gap after the segment `k`
+    fn evaluate_gap(&self, k: usize, xi: ArrayView1<'_, T>, nu: usize) -> Array2<T> {
+        let shape = (self.ndim, xi.len());
+
+        let (left, right) = (self.ranges[k].1, self.ranges[k + 1].0);
+        let ends = |segment: usize, x: T| {
+            let x = Array1::from_elem((1,), x);
+            self.segments[segment]
+                .evaluate(x.view())
+                .index_axis_move(Axis(1), 0)
+        };
+
+        match (self.fill, nu) {
+            (GapFill::Nan, _) => Array2::from_elem(shape, T::nan()),
+            (GapFill::Value(value), 0) => Array2::from_elem(shape, value),
+            (GapFill::Hold, 0) => {
+                let y = ends(k, left).insert_axis(Axis(1));
+                y.broadcast(shape).unwrap().to_owned()
+            }
+            (GapFill::Linear, 0 | 1) => {
+                let (y0, y1) = (ends(k, left), ends(k + 1, right));
+                let slope = (&y1 - &y0) / (right - left);
+
+                Array2::from_shape_fn(shape, |(dim, i)| match nu {
+                    0 => y0[dim] + slope[dim] * (xi[i] - left),
+                    _ => slope[dim],
+                })
+            }
+            _ => Array2::zeros(shape),
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use ndarray::{ArrayView1, Axis, Dimension};
 
 use crate::{
-    validate::{validate_data_sites, validate_smooth_value},
+    validate::{
+        validate_data_sites, validate_smooth_value, validate_weights_size, validate_xy_data,
+    },
     CsapsError::InvalidInputData,
     CubicSmoothingSpline, Real, RealRef, Result,
 };
 
+use super::NdSpline;
+
 impl<'a, T, D> CubicSmoothingSpline<'a, T, D>
 where
     T: Real<T>,
@@ -14,53 +18,207 @@ where
     D: Dimension,
 {
     pub(super) fn make_validate(&self) -> Result<()> {
-        let x_size = self.x.len();
+        validate_xy_data(self.x, self.y.view(), self.axis)?;
+        validate_weights_size(self.weights, self.x.len())?;
+
+        if let Some(smooth) = self.smooth {
+            validate_smooth_value(smooth)?;
+        }
 
-        if x_size < 2 {
+        if self.circular.is_some() && self.y_transform.is_some() {
             return Err(InvalidInputData(
-                "The size of data vectors must be greater or equal to 2".to_string(),
+                "`y` transform cannot be used with circular-valued data".to_string(),
             ));
         }
 
-        validate_data_sites(self.x)?;
+        if let Some(transform) = &self.x_transform {
+            let x = self.x.mapv(|v| transform.forward(v));
+
+            if x.iter().any(|v| !v.is_finite()) {
+                return Err(InvalidInputData(
+                    "`x` values are out of the `x` transform domain".to_string(),
+                ));
+            }
+
+            validate_data_sites(x.view()).map_err(|_| {
+                InvalidInputData(
+                    "Transformed data site values must satisfy the condition: x1 < x2 < ... < xN"
+                        .to_string(),
+                )
+            })?;
+        }
 
-        if self.y.ndim() == 0 {
-            return Err(InvalidInputData("`y` has zero dimensionality".to_string()));
+        if let Some(transform) = &self.y_transform {
+            if self.y.iter().any(|&v| !transform.forward(v).is_finite()) {
+                return Err(InvalidInputData(
+                    "`y` values are out of the `y` transform domain".to_string(),
+                ));
+            }
         }
 
-        let default_axis = Axis(self.y.ndim() - 1);
-        let axis = self.axis.unwrap_or(default_axis);
+        if let Some(derivative_x) = self.derivative_x {
+            self.derivatives_data_validate(derivative_x)?;
+        } else if self.derivative_weights.is_some() {
+            return Err(InvalidInputData(
+                "Derivative weights cannot be used without derivative data".to_string(),
+            ));
+        }
 
-        if axis > default_axis {
-            return Err(InvalidInputData(format!(
-                "`axis` value ({}) is out of bounds `y` dimensionality ({})",
-                axis.0,
-                self.y.ndim()
-            )));
+        if self.jumps.is_some() || self.kinks.is_some() {
+            self.discontinuities_validate()?;
         }
 
-        let y_size = self.y.len_of(axis);
+        if let Some(threshold) = self.gap_threshold {
+            self.gap_threshold_validate(threshold)?;
+        }
 
-        if x_size != y_size {
+        Ok(())
+    }
+
+    fn derivatives_data_validate(&self, derivative_x: ArrayView1<'a, T>) -> Result<()> {
+        if self.x_transform.is_some() || self.y_transform.is_some() {
+            return Err(InvalidInputData(
+                "Derivative data cannot be used with `x` or `y` transforms".to_string(),
+            ));
+        }
+
+        if derivative_x.is_empty() {
+            return Err(InvalidInputData(
+                "The size of derivative data sites must be greater or equal to 1".to_string(),
+            ));
+        }
+
+        validate_data_sites(derivative_x)?;
+
+        let derivative_y = self.derivative_y.as_ref().unwrap();
+        let axis = self.axis.unwrap_or(Axis(self.y.ndim() - 1));
+
+        let mut expected_shape = self.y.shape().to_vec();
+        expected_shape[axis.0] = derivative_x.len();
+
+        if derivative_y.shape() != expected_shape.as_slice() {
             return Err(InvalidInputData(format!(
-                "The shape[{}] ({}) of `y` data is not equal to `x` size ({})",
-                axis.0, y_size, x_size
+                "The shape of derivative data {:?} is not equal to the expected shape {:?}",
+                derivative_y.shape(),
+                expected_shape
             )));
         }
 
-        if let Some(weights) = self.weights {
-            let w_size = weights.len();
+        if let Some(weights) = self.derivative_weights {
+            if weights.len() != derivative_x.len() {
+                return Err(InvalidInputData(format!(
+                    "Derivative weights size ({}) is not equal to derivative data sites size ({})",
+                    weights.len(),
+                    derivative_x.len()
+                )));
+            }
 
-            if w_size != x_size {
+            if weights.iter().any(|&w| w < T::zero() || !w.is_finite()) {
+                return Err(InvalidInputData(
+                    "Derivative weights values must be non-negative and finite".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn discontinuities_validate(&self) -> Result<()> {
+        if self.x_transform.is_some() || self.y_transform.is_some() {
+            return Err(InvalidInputData(
+                "Jumps and kinks cannot be used with `x` or `y` transforms".to_string(),
+            ));
+        }
+
+        let x_first = self.x[0];
+        let x_last = self.x[self.x.len() - 1];
+
+        let mut locations: Vec<T> = Vec::new();
+
+        for (name, values) in [("jumps", self.jumps), ("kinks", self.kinks)] {
+            let values = match values {
+                Some(values) => values,
+                None => continue,
+            };
+
+            validate_data_sites(values).map_err(|_| {
+                InvalidInputData(format!(
+                    "The {} locations must satisfy the condition: v1 < v2 < ... < vN",
+                    name
+                ))
+            })?;
+
+            if values.iter().any(|&v| v <= x_first || v >= x_last) {
                 return Err(InvalidInputData(format!(
-                    "`weights` size ({}) is not equal to `x` size ({})",
-                    w_size, x_size
+                    "The {} locations must lie strictly inside `x` data range",
+                    name
                 )));
             }
+
+            locations.extend(values.iter().cloned());
         }
 
-        if let Some(smooth) = self.smooth {
-            validate_smooth_value(smooth)?;
+        locations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        if locations.windows(2).any(|w| w[0].almost_equals(w[1])) {
+            return Err(InvalidInputData(
+                "The jumps and the kinks locations must be different".to_string(),
+            ));
+        }
+
+        // The data sites at the location belong to the right segment
+        let mut bounds = vec![T::neg_infinity()];
+        bounds.extend(locations);
+        bounds.push(T::infinity());
+
+        for segment in bounds.windows(2) {
+            let in_segment = |v: T| {
+                (v > segment[0] || v.almost_equals(segment[0]))
+                    && v < segment[1]
+                    && !v.almost_equals(segment[1])
+            };
+            let count = self.x.iter().filter(|&&v| in_segment(v)).count();
+
+            if count < 2 {
+                return Err(InvalidInputData(
+                    "Every segment between the jumps and the kinks must contain at least 2 data sites"
+                        .to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn gap_threshold_validate(&self, threshold: T) -> Result<()> {
+        if threshold <= T::zero() || !threshold.is_finite() {
+            return Err(InvalidInputData(format!(
+                "The gap threshold must be positive, given {:?}",
+                threshold
+            )));
+        }
+
+        if self.x_transform.is_some()
+            || self.y_transform.is_some()
+            || self.derivative_x.is_some()
+            || self.jumps.is_some()
+            || self.kinks.is_some()
+        {
+            return Err(InvalidInputData(
+                "Gap segmentation cannot be used with transforms, derivative data, jumps or kinks"
+                    .to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn derivative_validate(&self, nu: usize) -> Result<()> {
+        if nu > 2 && (self.x_transform.is_some() || self.y_transform.is_some()) {
+            return Err(InvalidInputData(format!(
+                "The derivative order must be less or equal to 2 with `x` or `y` transform, given {}",
+                nu
+            )));
         }
 
         Ok(())
@@ -73,12 +231,134 @@ where
             ));
         }
 
-        if self.spline.is_none() {
+        if self.spline.is_none() && self.segmented_spline.is_none() {
+            return Err(InvalidInputData(
+                "The spline has not been computed, use `make` method before".to_string(),
+            ));
+        }
+
+        if let Some(transform) = &self.x_transform {
+            if xi.iter().any(|&v| !transform.forward(v).is_finite()) {
+                return Err(InvalidInputData(
+                    "`xi` values are out of the `x` transform domain".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn integrate_validate(&self, a: T, b: T) -> Result<()> {
+        if !a.is_finite() || !b.is_finite() {
+            return Err(InvalidInputData(
+                "The integration limits must be finite".to_string(),
+            ));
+        }
+
+        if self.spline.is_none() && self.segmented_spline.is_none() {
             return Err(InvalidInputData(
                 "The spline has not been computed, use `make` method before".to_string(),
             ));
         }
 
+        if self.x_transform.is_some() || self.y_transform.is_some() {
+            return Err(InvalidInputData(
+                "The integration is not supported with `x` or `y` transform".to_string(),
+            ));
+        }
+
+        if self.segmented_spline.is_some() {
+            return Err(InvalidInputData(
+                "The integration is not supported for the spline segmented at the gaps".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, T> NdSpline<'a, T>
+where
+    T: Real<T>,
+{
+    pub(super) fn level_validate(&self, level: T, dim: usize) -> Result<()> {
+        if dim >= self.ndim {
+            return Err(InvalidInputData(format!(
+                "The component index {} is out of the spline dimensionality {}",
+                dim, self.ndim
+            )));
+        }
+
+        if !level.is_finite() {
+            return Err(InvalidInputData("The level must be finite".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Validates the spline dimensionality for the geometry queries
+    pub(super) fn geometry_validate(&self, min_ndim: usize, max_ndim: Option<usize>) -> Result<()> {
+        let valid = self.ndim >= min_ndim && max_ndim.is_none_or(|max| self.ndim <= max);
+
+        if !valid {
+            let expected = match max_ndim {
+                None => format!("greater or equal to {}", min_ndim),
+                Some(max) if max == min_ndim => format!("{}", min_ndim),
+                Some(max) => format!("in range [{}, {}]", min_ndim, max),
+            };
+
+            return Err(InvalidInputData(format!(
+                "The spline dimensionality must be {}, got {}",
+                expected, self.ndim
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validates the parameter value is in the breaks range
+    pub(super) fn parameter_validate(&self, t: T) -> Result<()> {
+        let first = self.breaks[0];
+        let last = self.breaks[self.pieces];
+
+        if t.is_nan() || t < first || t > last {
+            return Err(InvalidInputData(format!(
+                "The parameter value {:?} is out of the breaks range [{:?}, {:?}]",
+                t, first, last
+            )));
+        }
+
         Ok(())
     }
 }
+
+/// Validates the point and the vector defining the line or the plane
+pub(super) fn hyperplane_validate<T: Real<T>>(
+    point: ArrayView1<'_, T>,
+    vector: ArrayView1<'_, T>,
+    ndim: usize,
+    name: &str,
+) -> Result<()> {
+    if point.len() != ndim || vector.len() != ndim {
+        return Err(InvalidInputData(format!(
+            "The point and the {} must be {}-d vectors, got {} and {}",
+            name,
+            ndim,
+            point.len(),
+            vector.len()
+        )));
+    }
+
+    if point.iter().chain(vector.iter()).any(|v| !v.is_finite()) {
+        return Err(InvalidInputData(format!(
+            "The point and the {} must have finite coordinates",
+            name
+        )));
+    }
+
+    if vector.iter().all(|v| v.is_zero()) {
+        return Err(InvalidInputData(format!("The {} must be non-zero", name)));
+    }
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use ndarray::ArrayView1;
+use ndarray::{ArrayView, ArrayView1, Axis, Dimension};
 
 use crate::{CsapsError::InvalidInputData, Real, Result};
 
@@ -32,3 +32,69 @@ where
 
     Ok(())
 }
+
+/// Validates 1-d `x` data sites and n-d `y` data values varying along the given axis
+pub(crate) fn validate_xy_data<T, D>(
+    x: ArrayView1<T>,
+    y: ArrayView<T, D>,
+    axis: Option<Axis>,
+) -> Result<()>
+where
+    T: Real<T>,
+    D: Dimension,
+{
+    let x_size = x.len();
+
+    if x_size < 2 {
+        return Err(InvalidInputData(
+            "The size of data vectors must be greater or equal to 2".to_string(),
+        ));
+    }
+
+    validate_data_sites(x)?;
+
+    if y.ndim() == 0 {
+        return Err(InvalidInputData("`y` has zero dimensionality".to_string()));
+    }
+
+    let default_axis = Axis(y.ndim() - 1);
+    let axis = axis.unwrap_or(default_axis);
+
+    if axis > default_axis {
+        return Err(InvalidInputData(format!(
+            "`axis` value ({}) is out of bounds `y` dimensionality ({})",
+            axis.0,
+            y.ndim()
+        )));
+    }
+
+    let y_size = y.len_of(axis);
+
+    if x_size != y_size {
+        return Err(InvalidInputData(format!(
+            "The shape[{}] ({}) of `y` data is not equal to `x` size ({})",
+            axis.0, y_size, x_size
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validates the optional weights vector size
+pub(crate) fn validate_weights_size<T>(weights: Option<ArrayView1<T>>, x_size: usize) -> Result<()>
+where
+    T: Real<T>,
+{
+    if let Some(weights) = weights {
+        let w_size = weights.len();
+
+        if w_size != x_size {
+            return Err(InvalidInputData(format!(
+                "`weights` size ({}) is not equal to `x` size ({})",
+                w_size, x_size
+            )));
+        }
+    }
+
+    Ok(())
+}
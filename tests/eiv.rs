@@ -0,0 +1,189 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, s, Array1, ArrayView1, Axis};
+
+use csaps::{CubicSmoothingSpline, ErrorsInVariablesSmoothingSpline, NdSpline};
+
+/// The roughness penalty `integral(f''(t)^2 dt)` of 1-d cubic spline
+fn roughness(spline: &NdSpline<f64>) -> f64 {
+    let breaks = spline.breaks();
+    let coeffs = spline.coeffs();
+    let pieces = spline.pieces();
+
+    (0..pieces)
+        .map(|i| {
+            let h = breaks[i + 1] - breaks[i];
+            let a = 2. * coeffs[[0, pieces * 2 + i]];
+            let b = a + 6. * coeffs[[0, i]] * h;
+            h / 3. * (a * a + a * b + b * b)
+        })
+        .sum()
+}
+
+/// The errors-in-variables criterion value
+fn criterion(
+    spline: &NdSpline<f64>,
+    sites: ArrayView1<f64>,
+    x: &Array1<f64>,
+    y: &Array1<f64>,
+    smooth: f64,
+) -> f64 {
+    let yi = spline.evaluate(sites);
+    let data = (y - &yi.row(0)).mapv(|v| v * v).sum() + (x - &sites).mapv(|v| v * v).sum();
+
+    smooth * data + (1. - smooth) * roughness(spline)
+}
+
+#[test]
+fn test_exact_x_is_cubic_smoothing_spline() {
+    let x = array![1., 2., 3., 4., 5., 6.];
+    let y = array![1.2, 2.9, 2.1, 4.4, 3.8, 5.1];
+    let x_std = Array1::from_elem(6, 1e-7);
+    let xi = Array1::linspace(1., 6., 21);
+
+    let eiv = ErrorsInVariablesSmoothingSpline::new(&x, &y)
+        .with_x_std(&x_std)
+        .with_smooth(0.7)
+        .make()
+        .unwrap();
+
+    let cs = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(0.7)
+        .make()
+        .unwrap();
+
+    assert!(eiv.converged());
+    assert_abs_diff_eq!(eiv.estimated_sites().unwrap(), x.view(), epsilon = 1e-8);
+    assert_abs_diff_eq!(
+        eiv.evaluate(&xi).unwrap(),
+        cs.evaluate(&xi).unwrap(),
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn test_criterion_is_decreased() {
+    let x = array![0., 0.9, 2.2, 2.8, 4.1, 5.0, 6.2];
+    let y = array![0.1, 1.4, 0.3, 2.1, 1.2, 2.9, 2.2];
+    let smooth = 0.8;
+
+    let eiv = ErrorsInVariablesSmoothingSpline::new(&x, &y)
+        .with_smooth(smooth)
+        .make()
+        .unwrap();
+
+    let cs = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(smooth)
+        .make()
+        .unwrap();
+
+    assert!(eiv.converged());
+
+    let sites = eiv.estimated_sites().unwrap();
+
+    assert!(sites.windows(2).into_iter().all(|w| w[0] < w[1]));
+
+    let j_eiv = criterion(eiv.spline().unwrap(), sites, &x, &y, smooth);
+    let j_cs = criterion(cs.spline().unwrap(), x.view(), &x, &y, smooth);
+
+    assert!(j_eiv < j_cs);
+}
+
+#[test]
+fn test_invalidate_resets_iterations() {
+    let x = array![0., 0.9, 2.2, 2.8, 4.1, 5.0, 6.2];
+    let y = array![0.1, 1.4, 0.3, 2.1, 1.2, 2.9, 2.2];
+
+    let eiv = ErrorsInVariablesSmoothingSpline::new(&x, &y)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    assert!(eiv.converged());
+    assert!(eiv.iterations() > 0);
+
+    let eiv = eiv.with_smooth(0.5);
+
+    assert!(!eiv.converged());
+    assert_eq!(eiv.iterations(), 0);
+    assert!(eiv.estimated_sites().is_none());
+}
+
+#[test]
+fn test_sites_move_toward_curve() {
+    let x = array![0., 1., 2., 3., 4., 5.];
+    let y = array![0., 1., 4.5, 9., 16., 25.];
+    let x_std = Array1::from_elem(6, 0.3);
+
+    let eiv = ErrorsInVariablesSmoothingSpline::new(&x, &y)
+        .with_x_std(&x_std)
+        .with_smooth(0.99)
+        .make()
+        .unwrap();
+
+    let cs = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(0.99)
+        .make()
+        .unwrap();
+
+    assert!(eiv.converged());
+
+    let sites = eiv.estimated_sites().unwrap().to_owned();
+
+    // The site moves right along the increasing curve instead of lifting the curve
+    assert!(sites[2] > 2.);
+
+    let dev_eiv = f64::abs(eiv.evaluate(&sites).unwrap()[2] - 4.5);
+    let dev_cs = f64::abs(cs.evaluate(&x).unwrap()[2] - 4.5);
+
+    assert!(dev_eiv < dev_cs);
+}
+
+#[test]
+fn test_multivariate_axis() {
+    let x = array![1., 2., 3., 4., 5.];
+    let y = array![1.5, 3.5, 2.6, 1.2, 4.4];
+    let y2 = array![[1.5, 1.5], [3.5, 3.5], [2.6, 2.6], [1.2, 1.2], [4.4, 4.4]];
+    let x_weights = Array1::from_elem(5, 2.);
+
+    let s1 = ErrorsInVariablesSmoothingSpline::new(&x, &y)
+        .with_smooth(0.6)
+        .make()
+        .unwrap();
+
+    // Two equal components with the doubled `x` weights give the doubled criterion
+    let s2 = ErrorsInVariablesSmoothingSpline::new(&x, &y2)
+        .with_axis(Axis(0))
+        .with_x_weights(&x_weights)
+        .with_smooth(0.6)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        s1.estimated_sites().unwrap(),
+        s2.estimated_sites().unwrap(),
+        epsilon = 1e-8
+    );
+
+    let yi = s2.evaluate(&x).unwrap();
+
+    assert_eq!(yi.shape(), &[5, 2]);
+    assert_abs_diff_eq!(
+        yi.slice(s![.., 1]),
+        s1.evaluate(&x).unwrap(),
+        epsilon = 1e-8
+    );
+}
+
+#[test]
+#[should_panic(expected = "`x_weights` and `x_std` cannot be set together")]
+fn test_weights_and_std_error() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![1., 2., 3., 4.];
+    let w = array![1., 1., 1., 1.];
+
+    ErrorsInVariablesSmoothingSpline::new(&x, &y)
+        .with_x_weights(&w)
+        .with_x_std(&w)
+        .make()
+        .unwrap();
+}
@@ -0,0 +1,178 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2, Axis};
+
+use csaps::{CubicSmoothingSpline, FunctionalData, NdSpline};
+
+fn curves(scores: &Array2<f64>) -> (Array1<f64>, Array2<f64>) {
+    let x = Array1::linspace(0., 2., 11);
+    let y = Array2::from_shape_fn((scores.nrows(), x.len()), |(i, j)| {
+        let v: f64 = x[j];
+        1. + v + scores[[i, 0]] * (v * 1.5).sin() + scores[[i, 1]] * (v - 1.) * (v - 1.)
+    });
+
+    (x, y)
+}
+
+fn integrate(x: &Array1<f64>, y: &Array1<f64>) -> f64 {
+    let h = x[1] - x[0];
+    let n = x.len() - 1;
+
+    let sum: f64 = (0..=n)
+        .map(|i| {
+            let c = if i == 0 || i == n {
+                1.
+            } else if i % 2 == 1 {
+                4.
+            } else {
+                2.
+            };
+            c * y[i]
+        })
+        .sum();
+
+    sum * h / 3.
+}
+
+#[test]
+fn test_mean_and_variance() {
+    let scores = array![
+        [0.5, 1.0],
+        [-0.3, 0.2],
+        [1.2, -0.7],
+        [0.1, 0.4],
+        [-0.8, -0.1]
+    ];
+    let (x, y) = curves(&scores);
+
+    let css = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(0.9)
+        .make()
+        .unwrap();
+
+    let fda = FunctionalData::new(css.spline().unwrap()).make().unwrap();
+
+    let xi = Array1::linspace(0., 2., 37);
+    let yi = css.evaluate(&xi).unwrap();
+
+    let mean = yi.mean_axis(Axis(0)).unwrap();
+    let variance = yi.var_axis(Axis(0), 1.);
+
+    assert_abs_diff_eq!(
+        fda.mean().unwrap().evaluate(xi.view()).row(0),
+        mean,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        fda.variance().unwrap().evaluate(xi.view()).row(0),
+        variance,
+        epsilon = 1e-12
+    );
+    assert_eq!(fda.variance().unwrap().order(), 7);
+}
+
+#[test]
+fn test_principal_components() {
+    let scores = array![
+        [0.5, 1.0],
+        [-0.3, 0.2],
+        [1.2, -0.7],
+        [0.1, 0.4],
+        [-0.8, -0.1]
+    ];
+    let (x, y) = curves(&scores);
+
+    let css = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let fda = FunctionalData::new(css.spline().unwrap()).make().unwrap();
+
+    let eigenvalues = fda.eigenvalues().unwrap();
+    assert_eq!(eigenvalues.len(), 2);
+    assert!(eigenvalues[0] >= eigenvalues[1] && eigenvalues[1] > 0.);
+    assert_abs_diff_eq!(
+        fda.explained_variance_ratio().unwrap().sum(),
+        1.,
+        epsilon = 1e-10
+    );
+
+    // The eigenfunctions are orthonormal
+    let xi = Array1::linspace(0., 2., 2001);
+    let phi: Vec<Array1<f64>> = fda
+        .eigenfunctions()
+        .unwrap()
+        .iter()
+        .map(|f| f.evaluate(xi.view()).row(0).to_owned())
+        .collect();
+
+    assert_abs_diff_eq!(integrate(&xi, &(&phi[0] * &phi[0])), 1., epsilon = 1e-8);
+    assert_abs_diff_eq!(integrate(&xi, &(&phi[1] * &phi[1])), 1., epsilon = 1e-8);
+    assert_abs_diff_eq!(integrate(&xi, &(&phi[0] * &phi[1])), 0., epsilon = 1e-8);
+
+    // The curves are reconstructed by the mean and the principal components
+    let s = fda.scores().unwrap();
+    let yi = css.evaluate(&xi).unwrap();
+    let mean = fda.mean().unwrap().evaluate(xi.view());
+
+    for (i, curve) in yi.rows().into_iter().enumerate() {
+        let reconstructed = &mean.row(0) + &(&phi[0] * s[[i, 0]]) + &(&phi[1] * s[[i, 1]]);
+        assert_abs_diff_eq!(reconstructed, curve, epsilon = 1e-10);
+    }
+
+    // The scores are centered and their variance is the eigenvalue
+    let score_means = s.mean_axis(Axis(0)).unwrap();
+    assert_abs_diff_eq!(score_means, array![0., 0.], epsilon = 1e-10);
+    assert_abs_diff_eq!(s.var_axis(Axis(0), 1.), eigenvalues, epsilon = 1e-10);
+}
+
+#[test]
+fn test_components() {
+    let scores = array![
+        [0.5, 1.0],
+        [-0.3, 0.2],
+        [1.2, -0.7],
+        [0.1, 0.4],
+        [-0.8, -0.1]
+    ];
+    let (x, y) = curves(&scores);
+
+    let css = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+    let spline = css.spline().unwrap();
+
+    let fda = FunctionalData::new(spline)
+        .with_components(1)
+        .make()
+        .unwrap();
+
+    assert_eq!(fda.eigenfunctions().unwrap().len(), 1);
+    assert_eq!(fda.scores().unwrap().shape(), &[5, 1]);
+
+    // The curves span only 2-d space
+    assert!(FunctionalData::new(spline)
+        .with_components(3)
+        .make()
+        .is_err());
+}
+
+#[test]
+fn test_invalid_data() {
+    let spline = NdSpline::new(array![0., 1., 2.], array![[1., 2., 0., 1.]]);
+    assert!(FunctionalData::new(&spline).make().is_err());
+
+    let spline = NdSpline::new(
+        array![0., 1., 2.],
+        array![[1., 2., 0., 1.], [2., 1., 1., 0.]],
+    );
+    assert!(FunctionalData::new(&spline)
+        .with_components(0)
+        .make()
+        .is_err());
+    assert!(FunctionalData::new(&spline)
+        .with_components(2)
+        .make()
+        .is_err());
+}
@@ -0,0 +1,205 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2};
+
+use csaps::AdditiveModel;
+
+fn covariates(n: usize) -> Array2<f64> {
+    Array2::from_shape_fn((n, 2), |(i, j)| match j {
+        0 => ((i * 17) % n) as f64 / n as f64 * 6.,
+        _ => ((i * 29) % n) as f64 / n as f64 * 4. - 2.,
+    })
+}
+
+fn f1(x: f64) -> f64 {
+    (x * 0.8).sin()
+}
+
+fn f2(x: f64) -> f64 {
+    0.3 * x * x
+}
+
+#[test]
+fn test_recover_additive_functions() {
+    let x = covariates(101);
+    let y = x.column(0).mapv(f1) + x.column(1).mapv(f2) + 2.;
+
+    let model = AdditiveModel::new(&x, &y)
+        .with_smooths(&[0.999, 0.999])
+        .make()
+        .unwrap();
+
+    assert!(model.converged());
+    assert!(model.iterations() > 1);
+
+    let yi = model.predict(&x).unwrap();
+    assert_abs_diff_eq!(yi, y, epsilon = 5e-3);
+
+    let terms = model.evaluate(&x).unwrap();
+    let t1 = x.column(0).mapv(f1);
+    let t2 = x.column(1).mapv(f2);
+    assert_abs_diff_eq!(terms.column(0), &t1 - t1.mean().unwrap(), epsilon = 5e-3);
+    assert_abs_diff_eq!(terms.column(1), &t2 - t2.mean().unwrap(), epsilon = 5e-3);
+}
+
+#[test]
+fn test_centered_terms_and_intercept() {
+    let x = covariates(50);
+    let y = x.column(0).mapv(f1) + x.column(1).mapv(f2) + 1.5;
+    let weights = Array1::from_shape_fn(50, |i| 1. + (i % 4) as f64);
+
+    let model = AdditiveModel::new(&x, &y)
+        .with_weights(&weights)
+        .with_smooths(&[0.9, 0.9])
+        .make()
+        .unwrap();
+
+    let intercept = (&weights * &y).sum() / weights.sum();
+    assert_abs_diff_eq!(model.intercept().unwrap(), intercept, epsilon = 1e-12);
+
+    let terms = model.evaluate(&x).unwrap();
+    for column in terms.columns() {
+        assert_abs_diff_eq!((&column * &weights).sum(), 0., epsilon = 1e-10);
+    }
+
+    let splines = model.terms().unwrap();
+    assert_eq!(splines.len(), 2);
+    assert_abs_diff_eq!(
+        splines[0].evaluate(x.column(0)).row(0),
+        terms.column(0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_repeated_covariate_values() {
+    let x = array![
+        [0., 1.],
+        [2., 0.],
+        [1., 1.],
+        [2., 2.],
+        [0., 0.],
+        [1., 2.],
+        [3., 1.],
+        [3., 0.]
+    ];
+    let y = x.column(0).mapv(|v| 2. * v) + x.column(1).mapv(|v| -v);
+
+    let model = AdditiveModel::new(&x, &y)
+        .with_smooths(&[1.0, 1.0])
+        .make()
+        .unwrap();
+
+    let yi = model.predict(&x).unwrap();
+    assert_abs_diff_eq!(yi, y, epsilon = 1e-6);
+
+    let pi = model.predict(&array![[1.5, 0.5]]).unwrap();
+    assert_abs_diff_eq!(pi[0], 2.5, epsilon = 1e-6);
+}
+
+#[test]
+fn test_auto_smooths() {
+    let x = covariates(40);
+    let y = x.column(0).mapv(f1) + x.column(1).mapv(f2);
+
+    let model = AdditiveModel::new(&x, &y).make().unwrap();
+
+    let smooths = model.smooths().unwrap();
+    assert_eq!(smooths.len(), 2);
+    assert!(smooths.iter().all(|&p| p > 0. && p < 1.));
+}
+
+#[test]
+fn test_max_iter() {
+    let x = covariates(40);
+    let y = x.column(0).mapv(f1) + x.column(1).mapv(f2);
+
+    let model = AdditiveModel::new(&x, &y)
+        .with_smooths(&[0.9, 0.9])
+        .with_max_iter(1)
+        .make()
+        .unwrap();
+
+    assert_eq!(model.iterations(), 1);
+}
+
+#[test]
+fn test_term_weights() {
+    // The balanced grid design: the partial residuals of the first term do not depend
+    // on the second term fit
+    let (n1, n2) = (8, 6);
+    let x = Array2::from_shape_fn((n1 * n2, 2), |(i, j)| match j {
+        0 => (i / n2) as f64 * 0.8,
+        _ => (i % n2) as f64 * 0.8 - 2.,
+    });
+    let y = Array1::from_shape_fn(n1 * n2, |i| {
+        f1(x[[i, 0]]) + f2(x[[i, 1]]) + 0.2 * ((i * 7) as f64).sin()
+    });
+    let term_weights = Array1::from_shape_fn(n1 * n2, |i| 1. + (i % 5) as f64);
+
+    let model = AdditiveModel::new(&x, &y)
+        .with_smooths(&[0.6, 0.6])
+        .with_tolerance(1e-12)
+        .make()
+        .unwrap();
+
+    let weighted = AdditiveModel::new(&x, &y)
+        .with_smooths(&[0.6, 0.6])
+        .with_tolerance(1e-12)
+        .with_term_weights(1, &term_weights)
+        .make()
+        .unwrap();
+
+    let terms = model.evaluate(&x).unwrap();
+    let weighted_terms = weighted.evaluate(&x).unwrap();
+
+    assert_abs_diff_eq!(terms.column(0), weighted_terms.column(0), epsilon = 1e-9);
+
+    let change = (&terms.column(1) - &weighted_terms.column(1))
+        .iter()
+        .fold(0f64, |acc, v| acc.max(v.abs()));
+    assert!(change > 1e-3);
+}
+
+#[test]
+fn test_invalid_data() {
+    let x = covariates(10);
+    let y = Array1::<f64>::zeros(9);
+    assert!(AdditiveModel::new(&x, &y).make().is_err());
+
+    let y = Array1::<f64>::zeros(10);
+    assert!(AdditiveModel::new(&x, &y)
+        .with_smooths(&[0.5])
+        .make()
+        .is_err());
+
+    assert!(AdditiveModel::new(&x, &y)
+        .with_term_weights(2, &Array1::ones(10))
+        .make()
+        .is_err());
+    assert!(AdditiveModel::new(&x, &y)
+        .with_term_weights(0, &Array1::ones(9))
+        .make()
+        .is_err());
+    assert!(AdditiveModel::new(&x, &y)
+        .with_tolerance(-1e-8)
+        .make()
+        .is_err());
+    assert!(AdditiveModel::new(&x, &y)
+        .with_tolerance(f64::NAN)
+        .make()
+        .is_err());
+
+    let constant = Array2::<f64>::ones((10, 1));
+    assert!(AdditiveModel::new(&constant, &y).make().is_err());
+
+    let mut x_nan = covariates(10);
+    x_nan[[3, 1]] = f64::NAN;
+    assert!(AdditiveModel::new(&x_nan, &y).make().is_err());
+
+    let mut y_inf = Array1::<f64>::zeros(10);
+    y_inf[5] = f64::INFINITY;
+    assert!(AdditiveModel::new(&x, &y_inf).make().is_err());
+
+    let model = AdditiveModel::new(&x, &y).make().unwrap();
+    assert!(model.predict(&Array2::<f64>::zeros((3, 3))).is_err());
+}
@@ -0,0 +1,242 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1};
+
+use csaps::{NdSpline, ParametricSmoothingSpline};
+
+/// The cubic curve `x = t^3, y = t^3` on `[0, 1]` with zero speed at the start
+fn cubic_line() -> NdSpline<'static, f64> {
+    NdSpline::new(array![0., 1.], array![[1., 0., 0., 0.], [1., 0., 0., 0.]])
+}
+
+#[test]
+fn test_arc_length() {
+    let spline = cubic_line();
+    let sqrt2 = 2f64.sqrt();
+
+    assert_abs_diff_eq!(spline.arc_length(0., 1.).unwrap(), sqrt2, epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        spline.arc_length(0.5, 1.).unwrap(),
+        sqrt2 * (1. - 0.125),
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        spline.arc_length(1., 0.5).unwrap(),
+        -sqrt2 * (1. - 0.125),
+        epsilon = 1e-12
+    );
+
+    assert!(spline.arc_length(-0.1, 1.).is_err());
+    assert!(spline.arc_length(0., 1.1).is_err());
+}
+
+#[test]
+fn test_arc_length_circle() {
+    let n = 40;
+    let points = Array1::linspace(0., 2. * std::f64::consts::PI, n + 1)
+        .slice(ndarray::s![..n])
+        .mapv(|a| [a.cos() * 2., a.sin() * 2.]);
+    let points = ndarray::Array2::from_shape_fn((n, 2), |(i, j)| points[i][j]);
+
+    let s = ParametricSmoothingSpline::new(&points)
+        .with_periodic(true)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let length = s.spline().unwrap().arc_length(0., 1.).unwrap();
+    assert_abs_diff_eq!(length, 4. * std::f64::consts::PI, epsilon = 1e-4);
+}
+
+#[test]
+fn test_arc_length_parameter() {
+    let spline = cubic_line();
+    let sqrt2 = 2f64.sqrt();
+
+    let s = Array1::linspace(0., sqrt2, 11);
+    let t = spline.arc_length_parameter(s.view()).unwrap();
+
+    assert_abs_diff_eq!(t, s.mapv(|v| (v / sqrt2).cbrt()), epsilon = 1e-10);
+
+    assert!(spline.arc_length_parameter(array![-0.1].view()).is_err());
+    assert!(spline.arc_length_parameter(array![1.5].view()).is_err());
+}
+
+#[test]
+fn test_resample_arc_length() {
+    let points = array![
+        [0., 0.],
+        [1., 0.3],
+        [2., 1.5],
+        [2.5, 3.],
+        [2., 4.5],
+        [0.5, 5.]
+    ];
+
+    let s = ParametricSmoothingSpline::new(&points)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+    let spline = s.spline().unwrap();
+
+    let (t, resampled) = spline.resample_arc_length(21).unwrap();
+    assert_eq!(resampled.shape(), &[2, 21]);
+    assert_abs_diff_eq!(t[0], 0.);
+    assert_abs_diff_eq!(t[20], 1.);
+
+    let total = spline.arc_length(0., 1.).unwrap();
+
+    for k in 0..20 {
+        let length = spline.arc_length(t[k], t[k + 1]).unwrap();
+        assert_abs_diff_eq!(length, total / 20., epsilon = 1e-10);
+    }
+
+    assert!(spline.resample_arc_length(1).is_err());
+}
+
+/// The parabola `x = t, y = t^2`
+fn parabola() -> NdSpline<'static, f64> {
+    NdSpline::new(array![0., 2.], array![[0., 0., 1., 0.], [0., 1., 0., 0.]])
+}
+
+/// The twisted cubic `x = t, y = t^2, z = t^3`
+fn twisted_cubic() -> NdSpline<'static, f64> {
+    NdSpline::new(
+        array![0., 2.],
+        array![[0., 0., 1., 0.], [0., 1., 0., 0.], [1., 0., 0., 0.]],
+    )
+}
+
+#[test]
+fn test_planar_curve_geometry() {
+    let spline = parabola();
+    let t = array![0.1, 0.5, 1.2, 1.9];
+
+    let norm = t.mapv(|v: f64| (1. + 4. * v * v).sqrt());
+
+    let tangent = spline.tangent(t.view()).unwrap();
+    assert_abs_diff_eq!(tangent.row(0), 1. / &norm, epsilon = 1e-12);
+    assert_abs_diff_eq!(tangent.row(1), &t * 2. / &norm, epsilon = 1e-12);
+
+    let normal = spline.normal(t.view()).unwrap();
+    assert_abs_diff_eq!(normal.row(0), -&t * 2. / &norm, epsilon = 1e-12);
+    assert_abs_diff_eq!(normal.row(1), 1. / &norm, epsilon = 1e-12);
+
+    let curvature = spline.curvature(t.view()).unwrap();
+    assert_abs_diff_eq!(curvature, 2. / norm.mapv(|v| v.powi(3)), epsilon = 1e-12);
+
+    // The reversed parabola turns clockwise
+    let reversed = NdSpline::new(array![0., 2.], array![[0., 0., -1., 0.], [0., 1., 0., 0.]]);
+    let curvature = reversed.curvature(t.view()).unwrap();
+    assert!(curvature.iter().all(|&k| k < 0.));
+
+    assert!(spline.binormal(t.view()).is_err());
+    assert!(spline.torsion(t.view()).is_err());
+}
+
+#[test]
+fn test_space_curve_geometry() {
+    let spline = twisted_cubic();
+    let t = array![0.05, 0.4, 1.1, 1.7];
+
+    let b = t.mapv(|v: f64| 36. * v.powi(4) + 36. * v * v + 4.);
+    let speed = t.mapv(|v: f64| (1. + 4. * v * v + 9. * v.powi(4)).sqrt());
+
+    let curvature = spline.curvature(t.view()).unwrap();
+    assert_abs_diff_eq!(
+        curvature,
+        b.mapv(f64::sqrt) / speed.mapv(|v| v.powi(3)),
+        epsilon = 1e-12
+    );
+
+    let torsion = spline.torsion(t.view()).unwrap();
+    assert_abs_diff_eq!(torsion, 12. / &b, epsilon = 1e-12);
+
+    // The Frenet frame is orthonormal and right-handed
+    let tangent = spline.tangent(t.view()).unwrap();
+    let normal = spline.normal(t.view()).unwrap();
+    let binormal = spline.binormal(t.view()).unwrap();
+
+    for k in 0..t.len() {
+        let (tk, nk, bk) = (tangent.column(k), normal.column(k), binormal.column(k));
+
+        assert_abs_diff_eq!(tk.dot(&tk), 1., epsilon = 1e-12);
+        assert_abs_diff_eq!(nk.dot(&nk), 1., epsilon = 1e-12);
+        assert_abs_diff_eq!(bk.dot(&bk), 1., epsilon = 1e-12);
+        assert_abs_diff_eq!(tk.dot(&nk), 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(tk.dot(&bk), 0., epsilon = 1e-12);
+
+        let cross = array![
+            tk[1] * nk[2] - tk[2] * nk[1],
+            tk[2] * nk[0] - tk[0] * nk[2],
+            tk[0] * nk[1] - tk[1] * nk[0]
+        ];
+        assert_abs_diff_eq!(cross, bk, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_geometry_dimensionality_error() {
+    let spline = NdSpline::new(array![0., 1.], array![[1., 0., 0., 0.]]);
+    let t = array![0.5];
+
+    assert!(spline.tangent(t.view()).is_err());
+    assert!(spline.curvature(t.view()).is_err());
+    assert!(spline.normal(t.view()).is_err());
+}
+
+#[test]
+fn test_project() {
+    let spline = parabola();
+
+    // The points on the normals of the curve
+    let t = array![0.2, 0.7, 1.3];
+    let offsets = array![0.1, -0.2, 0.15];
+
+    let on_curve = spline.evaluate(t.view());
+    let normals = spline.normal(t.view()).unwrap();
+    let mut points = &on_curve + &(&normals * &offsets);
+
+    // The point beyond the curve end
+    points.push_column(array![3., 4.].view()).unwrap();
+
+    let (parameter, positions, distances) = spline.project(points.view()).unwrap();
+
+    assert_abs_diff_eq!(parameter, array![0.2, 0.7, 1.3, 2.0], epsilon = 1e-10);
+    assert_abs_diff_eq!(
+        positions.slice(ndarray::s![.., ..3]),
+        on_curve,
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(distances, array![0.1, 0.2, 0.15, 1.0], epsilon = 1e-10);
+}
+
+#[test]
+fn test_project_closed_curve() {
+    let n = 24;
+    let points = ndarray::Array2::from_shape_fn((n, 2), |(i, j)| {
+        let a = 2. * std::f64::consts::PI * i as f64 / n as f64;
+        if j == 0 {
+            2. * a.cos()
+        } else {
+            2. * a.sin()
+        }
+    });
+
+    let s = ParametricSmoothingSpline::new(&points)
+        .with_periodic(true)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let queries = array![[3., 0., -1.], [0., -2.5, 1.]];
+    let (_, positions, distances) = s.spline().unwrap().project(queries.view()).unwrap();
+
+    assert_abs_diff_eq!(distances, array![1., 0.5, 2. - 2f64.sqrt()], epsilon = 1e-3);
+    assert_abs_diff_eq!(positions.column(0), array![2., 0.], epsilon = 1e-3);
+
+    assert!(s
+        .spline()
+        .unwrap()
+        .project(array![[1.], [2.], [3.]].view())
+        .is_err());
+}
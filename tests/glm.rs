@@ -0,0 +1,141 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Axis};
+
+use csaps::{Family, GeneralizedSmoothingSpline, Link};
+
+#[test]
+fn test_poisson_constant_counts() {
+    let x = array![1., 2., 3., 4., 5., 6.];
+    let y = array![4., 4., 4., 4., 4., 4.];
+
+    let s = GeneralizedSmoothingSpline::new(&x, &y)
+        .with_family(Family::Poisson)
+        .make()
+        .unwrap();
+
+    assert!(s.converged());
+    assert_eq!(s.link(), Link::Log);
+
+    let xi = Array1::linspace(1., 6., 11);
+    let yi = s.evaluate(&xi).unwrap();
+
+    assert_abs_diff_eq!(yi, Array1::from_elem(11, 4.), epsilon = 1e-8);
+}
+
+#[test]
+fn test_invalidate_resets_iterations() {
+    let x = array![1., 2., 3., 4., 5.];
+    let y = array![1., 3., 8., 5., 2.];
+
+    let s = GeneralizedSmoothingSpline::new(&x, &y)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    assert!(s.converged());
+    assert!(s.iterations() > 0);
+
+    let s = s.with_smooth(0.5);
+
+    assert!(!s.converged());
+    assert_eq!(s.iterations(), 0);
+    assert!(s.spline().is_none());
+}
+
+#[test]
+fn test_poisson_interpolation() {
+    let x = array![1., 2., 3., 4., 5.];
+    let y = array![1., 3., 8., 5., 2.];
+
+    let s = GeneralizedSmoothingSpline::new(&x, &y)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    assert!(s.converged());
+    assert_abs_diff_eq!(s.evaluate(&x).unwrap(), y, epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        s.evaluate_link(&x).unwrap(),
+        y.mapv(f64::ln),
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn test_poisson_positive_rates() {
+    let x = array![1., 2., 3., 4., 5., 6., 7., 8.];
+    let y = array![0., 0., 1., 6., 9., 2., 0., 0.];
+
+    let s = GeneralizedSmoothingSpline::new(&x, &y)
+        .with_smooth(0.7)
+        .make()
+        .unwrap();
+
+    assert!(s.converged());
+
+    let xi = Array1::linspace(0., 9., 50);
+    let yi = s.evaluate(&xi).unwrap();
+
+    assert!(yi.iter().all(|&v| v > 0.));
+}
+
+#[test]
+fn test_binomial_linear_logistic() {
+    // The smoothing parameter 0 gives the logistic regression with the linear predictor
+    let x = array![-2., -1., 0., 1., 2.];
+    let y = array![0.1, 0.3, 0.5, 0.7, 0.9];
+    let trials = array![10., 10., 10., 10., 10.];
+
+    let s = GeneralizedSmoothingSpline::new(&x, &y)
+        .with_family(Family::Binomial)
+        .with_weights(&trials)
+        .with_smooth(0.0)
+        .make()
+        .unwrap();
+
+    assert!(s.converged());
+
+    let eta = s.evaluate_link(&x).unwrap();
+    let slopes = &eta.slice(ndarray::s![1..]) - &eta.slice(ndarray::s![..-1]);
+
+    assert_abs_diff_eq!(eta[2], 0., epsilon = 1e-8);
+    assert_abs_diff_eq!(slopes, Array1::from_elem(4, slopes[0]), epsilon = 1e-8);
+
+    let mu = s.evaluate(&array![-10., 10.]).unwrap();
+    assert!(mu.iter().all(|&v| v > 0. && v < 1.));
+}
+
+#[test]
+fn test_multivariate_axis() {
+    let x = array![1., 2., 3., 4., 5.];
+    let y = array![[1., 2.], [3., 2.], [8., 2.], [5., 2.], [2., 2.]];
+
+    let s = GeneralizedSmoothingSpline::new(&x, &y)
+        .with_axis(Axis(0))
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(s.evaluate(&x).unwrap(), y, epsilon = 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "`y` values must be non-negative counts for `Family::Poisson`")]
+fn test_poisson_negative_counts_error() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![1., -2., 3., 4.];
+
+    GeneralizedSmoothingSpline::new(&x, &y).make().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "`Link::Logit` link cannot be used with `Family::Poisson` family")]
+fn test_poisson_logit_error() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![1., 2., 3., 4.];
+
+    GeneralizedSmoothingSpline::new(&x, &y)
+        .with_link(Link::Logit)
+        .make()
+        .unwrap();
+}
@@ -0,0 +1,125 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2, Axis};
+
+use csaps::HistopolatingSmoothingSpline;
+
+/// Returns the Simpson's rule sites for every bin
+fn simpson_sites(edges: &Array1<f64>) -> Array1<f64> {
+    edges
+        .windows(2)
+        .into_iter()
+        .flat_map(|e| [e[0], (e[0] + e[1]) / 2., e[1] - 1e-12 * (e[1] - e[0])])
+        .collect()
+}
+
+/// Computes the bin integrals of the values at Simpson's sites (exact for cubic pieces)
+fn bin_integrals(edges: &Array1<f64>, values: &Array2<f64>) -> Array2<f64> {
+    let bins = edges.len() - 1;
+
+    Array2::from_shape_fn((values.nrows(), bins), |(d, i)| {
+        let h = edges[i + 1] - edges[i];
+        let v = values.row(d);
+        h / 6. * (v[3 * i] + 4. * v[3 * i + 1] + v[3 * i + 2])
+    })
+}
+
+#[test]
+fn test_histopolation_preserves_bin_integrals() {
+    let edges = array![0., 1., 2.5, 3., 4.5, 6.];
+    let totals = array![1.2, 3.4, 0.8, 4.2, 2.0];
+
+    let s = HistopolatingSmoothingSpline::new(&edges, &totals)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let xi = simpson_sites(&edges);
+    let values = s.evaluate(&xi).unwrap().insert_axis(Axis(0));
+    let integrals = bin_integrals(&edges, &values);
+
+    assert_abs_diff_eq!(integrals.row(0), totals, epsilon = 1e-6);
+}
+
+#[test]
+fn test_histopolation_averages() {
+    let edges = array![0., 1., 2.5, 3., 4.5, 6.];
+    let averages = array![1.2, 3.4, 0.8, 4.2, 2.0];
+    let widths = array![1., 1.5, 0.5, 1.5, 1.5];
+
+    let s = HistopolatingSmoothingSpline::new(&edges, &averages)
+        .with_averages(true)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let xi = simpson_sites(&edges);
+    let values = s.evaluate(&xi).unwrap().insert_axis(Axis(0));
+    let integrals = bin_integrals(&edges, &values);
+
+    assert_abs_diff_eq!(integrals.row(0), &averages * &widths, epsilon = 1e-6);
+}
+
+#[test]
+fn test_linear_function_is_reproduced() {
+    // The integrals of f(x) = 2x + 1 over the bins
+    let edges = array![0., 1., 3., 4., 6.];
+    let totals = edges
+        .windows(2)
+        .into_iter()
+        .map(|e| (e[1] * e[1] + e[1]) - (e[0] * e[0] + e[0]))
+        .collect::<Array1<f64>>();
+
+    let xi = Array1::linspace(0., 6., 13);
+
+    for smooth in [0.0, 0.5, 1.0] {
+        let s = HistopolatingSmoothingSpline::new(&edges, &totals)
+            .with_smooth(smooth)
+            .make()
+            .unwrap();
+
+        assert_abs_diff_eq!(s.evaluate(&xi).unwrap(), &xi * 2. + 1., epsilon = 1e-6);
+    }
+}
+
+#[test]
+fn test_auto_smooth() {
+    let edges = array![0., 1., 2., 3., 4., 5., 6.];
+    let totals = array![1.2, 3.4, 0.8, 4.2, 2.0, 2.5];
+
+    let s = HistopolatingSmoothingSpline::new(&edges, &totals)
+        .make()
+        .unwrap();
+
+    let smooth = s.smooth().unwrap();
+    assert!(smooth > 0. && smooth < 1.);
+}
+
+#[test]
+fn test_multivariate_with_axis() {
+    let edges = array![0., 1., 2.5, 3., 4.5, 6.];
+    let totals = array![[1.2, 3.4, 0.8, 4.2, 2.0], [2.2, 1.4, 3.8, 0.2, 5.0]];
+
+    let totals_t = totals.t();
+
+    let s = HistopolatingSmoothingSpline::new(&edges, totals_t)
+        .with_axis(Axis(0))
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let xi = simpson_sites(&edges);
+    let values = s.evaluate(&xi).unwrap().t().to_owned();
+    let integrals = bin_integrals(&edges, &values);
+
+    assert_abs_diff_eq!(integrals, totals, epsilon = 1e-6);
+}
+
+#[test]
+fn test_invalid_values_size() {
+    let edges = array![0., 1., 2., 3.];
+    let totals = array![1., 2., 3., 4.];
+
+    let s = HistopolatingSmoothingSpline::new(&edges, &totals).make();
+
+    assert!(s.is_err());
+}
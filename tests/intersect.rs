@@ -0,0 +1,203 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2};
+
+use csaps::{NdSpline, ParametricSmoothingSpline};
+
+/// The parabola `x = s, y = s^2` for `s = t - 1` on `t` in `[0, 2]`
+fn parabola() -> NdSpline<'static, f64> {
+    NdSpline::new(array![0., 2.], array![[0., 0., 1., -1.], [0., 1., -2., 1.]])
+}
+
+/// The horizontal line segment `x = t - 1, y = level` on `t` in `[0, 2]`
+fn horizontal(level: f64) -> NdSpline<'static, f64> {
+    NdSpline::new(
+        array![0., 2.],
+        array![[0., 0., 1., -1.], [0., 0., 0., level]],
+    )
+}
+
+/// The closed circle curve with the given center and radius
+fn circle(cx: f64, cy: f64, radius: f64) -> NdSpline<'static, f64> {
+    let n = 60;
+    let angles = Array1::linspace(0., 2. * std::f64::consts::PI, n + 1);
+    let points = Array2::from_shape_fn((n, 2), |(i, j)| {
+        if j == 0 {
+            cx + radius * angles[i].cos()
+        } else {
+            cy + radius * angles[i].sin()
+        }
+    });
+
+    let s = ParametricSmoothingSpline::new(&points)
+        .with_periodic(true)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let spline = s.spline().unwrap();
+    NdSpline::new(spline.breaks().to_owned(), spline.coeffs().to_owned())
+}
+
+#[test]
+fn test_intersect_curves() {
+    let (parameters, points) = parabola().intersect(&horizontal(0.25)).unwrap();
+
+    assert_eq!(parameters.shape(), &[2, 2]);
+    assert_abs_diff_eq!(parameters, array![[0.5, 0.5], [1.5, 1.5]], epsilon = 1e-12);
+    assert_abs_diff_eq!(points, array![[-0.5, 0.5], [0.25, 0.25]], epsilon = 1e-12);
+
+    let (parameters, _) = parabola().intersect(&horizontal(2.0)).unwrap();
+    assert_eq!(parameters.nrows(), 0);
+}
+
+#[test]
+fn test_intersect_curves_tangent() {
+    let (parameters, points) = parabola().intersect(&horizontal(0.0)).unwrap();
+
+    assert_eq!(parameters.nrows(), 1);
+    assert_abs_diff_eq!(parameters[[0, 0]], 1.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(points[[1, 0]], 0.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_intersect_circles() {
+    let first = circle(0., 0., 1.);
+    let second = circle(1., 0., 1.);
+
+    let (parameters, points) = first.intersect(&second).unwrap();
+
+    assert_eq!(parameters.nrows(), 2);
+    assert_abs_diff_eq!(points[[0, 0]], 0.5, epsilon = 1e-5);
+    assert_abs_diff_eq!(points[[0, 1]], 0.5, epsilon = 1e-5);
+    assert_abs_diff_eq!(points[[1, 0]], 3f64.sqrt() / 2., epsilon = 1e-5);
+    assert_abs_diff_eq!(points[[1, 1]], -3f64.sqrt() / 2., epsilon = 1e-5);
+
+    let other_points = second.evaluate(parameters.column(1));
+    assert_abs_diff_eq!(points, other_points, epsilon = 1e-10);
+
+    assert!(first
+        .intersect(&NdSpline::new(array![0., 1.], array![[0., 1.]]))
+        .is_err());
+}
+
+#[test]
+fn test_intersect_line() {
+    let spline = parabola();
+
+    let (t, points) = spline
+        .intersect_line(array![0., 0.25].view(), array![1., 0.].view())
+        .unwrap();
+
+    assert_abs_diff_eq!(t, array![0.5, 1.5], epsilon = 1e-12);
+    assert_abs_diff_eq!(points, array![[-0.5, 0.5], [0.25, 0.25]], epsilon = 1e-12);
+
+    let (t, _) = spline
+        .intersect_line(array![0., 0.].view(), array![1., 0.].view())
+        .unwrap();
+    assert_abs_diff_eq!(t, array![1.0], epsilon = 1e-6);
+
+    // The line through the vertex along the axis of the parabola
+    let (t, _) = spline
+        .intersect_line(array![0., 0.].view(), array![0., 1.].view())
+        .unwrap();
+    assert_abs_diff_eq!(t, array![1.0], epsilon = 1e-12);
+
+    assert!(spline
+        .intersect_line(array![0., 0.].view(), array![0., 0.].view())
+        .is_err());
+    assert!(spline
+        .intersect_line(array![0., 0., 0.].view(), array![1., 0.].view())
+        .is_err());
+}
+
+#[test]
+fn test_intersect_line_circle() {
+    let spline = circle(0., 0., 2.);
+
+    let (t, points) = spline
+        .intersect_line(array![0., 1.].view(), array![1., 1.].view())
+        .unwrap();
+
+    assert_eq!(t.len(), 2);
+
+    for point in points.columns() {
+        assert_abs_diff_eq!(point[1] - point[0], 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(point.dot(&point).sqrt(), 2.0, epsilon = 1e-4);
+    }
+}
+
+#[test]
+fn test_intersect_plane() {
+    // The twisted cubic `x = s, y = s^2, z = s^3` for `s = t - 1` on `t` in `[0, 2]`
+    let spline = NdSpline::new(
+        array![0., 2.],
+        array![[0., 0., 1., -1.], [0., 1., -2., 1.], [1., -3., 3., -1.]],
+    );
+
+    let (t, points) = spline
+        .intersect_plane(array![0., 0., 0.125].view(), array![0., 0., 1.].view())
+        .unwrap();
+
+    assert_abs_diff_eq!(t, array![1.5], epsilon = 1e-12);
+    assert_abs_diff_eq!(points, array![[0.5], [0.25], [0.125]], epsilon = 1e-12);
+
+    // The plane `x + y = 0` intersects at `s = 0` and `s = -1`
+    let (t, _) = spline
+        .intersect_plane(array![0., 0., 0.].view(), array![1., 1., 0.].view())
+        .unwrap();
+    assert_abs_diff_eq!(t, array![0., 1.], epsilon = 1e-12);
+
+    assert!(parabola()
+        .intersect_plane(array![0., 0., 0.].view(), array![0., 0., 1.].view())
+        .is_err());
+}
+
+#[test]
+fn test_intersect_self() {
+    // The nodal cubic `x = s^2 - 1, y = s^3 - s` for `s = t - 1.5` crosses itself at `s = -1, 1`
+    let spline = NdSpline::new(
+        array![0., 3.],
+        array![[0., 1., -3., 1.25], [1., -4.5, 5.75, -1.875]],
+    );
+
+    let (parameters, points) = spline.intersect(&spline).unwrap();
+
+    assert_abs_diff_eq!(parameters, array![[0.5, 2.5], [2.5, 0.5]], epsilon = 1e-10);
+    assert_abs_diff_eq!(points, array![[0., 0.], [0., 0.]], epsilon = 1e-10);
+
+    // The coincident closed curve without self-intersections
+    let circle = circle(0., 0., 1.);
+    let (parameters, _) = circle.intersect(&circle).unwrap();
+
+    assert_eq!(parameters.nrows(), 0);
+}
+
+#[test]
+fn test_intersect_partial_overlap() {
+    // The parabola `x = s, y = s^2` for `s` in `[-1, 1]` with two pieces
+    let first = NdSpline::new(
+        array![0., 1., 2.],
+        array![
+            [0., 0., 0., 0., 1., 1., -1., 0.],
+            [0., 0., 1., 1., -2., 0., 1., 0.]
+        ],
+    );
+
+    // The arc of the parabola for `s` in `[0, 1]` followed by the horizontal line `y = 0.25`
+    let second = NdSpline::new(
+        array![10., 11., 12.],
+        array![
+            [0., 0., 0., 0., 1., 2., 0., -1.],
+            [0., 0., 1., 0., 0., 0., 0., 0.25]
+        ],
+    );
+
+    let (parameters, points) = first.intersect(&second).unwrap();
+
+    assert_abs_diff_eq!(
+        parameters,
+        array![[0.5, 11.25], [1.5, 11.75]],
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(points, array![[-0.5, 0.5], [0.25, 0.25]], epsilon = 1e-10);
+}
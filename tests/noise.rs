@@ -0,0 +1,96 @@
+use std::f64::consts::PI;
+
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2, Axis};
+
+use csaps::{DifferenceMethod, NoiseEstimator};
+
+/// Generates the deterministic standard normal pseudo-random sequence (LCG and Box-Muller)
+fn normal_noise(n: usize, seed: u64) -> Array1<f64> {
+    let mut state = seed;
+    let mut uniform = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    };
+
+    Array1::from_shape_fn(n, |_| {
+        let (u1, u2) = (uniform(), uniform());
+        (-2. * u1.ln()).sqrt() * (2. * PI * u2).cos()
+    })
+}
+
+#[test]
+fn test_gsjs_is_exact_for_linear_data() {
+    let x = array![0., 0.3, 1.2, 1.5, 2.9, 3.0, 4.4];
+    let y = x.mapv(|v| 2. * v - 1.);
+
+    let sigma = NoiseEstimator::new(&x, &y)
+        .with_method(DifferenceMethod::Gsjs)
+        .estimate()
+        .unwrap()
+        .into_scalar();
+
+    assert_abs_diff_eq!(sigma, 0., epsilon = 1e-12);
+
+    let sigma = NoiseEstimator::new(&x, &y)
+        .with_method(DifferenceMethod::Rice)
+        .estimate()
+        .unwrap()
+        .into_scalar();
+
+    assert!(sigma > 0.5);
+}
+
+#[test]
+fn test_noise_level_estimates() {
+    let n = 20000;
+    let x = Array1::from_shape_fn(n, |i| i as f64 * 0.01 + 0.004 * ((i * 7) % 3) as f64);
+
+    let noise = normal_noise(n, 42) * 0.3;
+    let y = x.mapv(|v| (v * 0.5).sin()) + &noise;
+
+    for method in [DifferenceMethod::Rice, DifferenceMethod::Gsjs] {
+        let sigma = NoiseEstimator::new(&x, &y)
+            .with_method(method)
+            .estimate()
+            .unwrap()
+            .into_scalar();
+
+        assert_abs_diff_eq!(sigma, 0.3, epsilon = 0.01);
+    }
+}
+
+#[test]
+fn test_multivariate_with_axis() {
+    let n = 5000;
+    let x = Array1::linspace(0., 10., n);
+
+    let mut y = Array2::zeros((n, 3));
+    for (k, sigma) in [0.1, 0.5, 1.0].iter().enumerate() {
+        let noise = normal_noise(n, k as u64 + 1) * *sigma;
+        y.column_mut(k).assign(&(x.mapv(f64::cos) + noise));
+    }
+
+    let sigma = NoiseEstimator::new(&x, &y)
+        .with_axis(Axis(0))
+        .estimate()
+        .unwrap();
+
+    assert_eq!(sigma.shape(), &[3]);
+    assert_abs_diff_eq!(sigma, array![0.1, 0.5, 1.0], epsilon = 0.05);
+}
+
+#[test]
+fn test_gsjs_not_enough_data_error() {
+    let x = array![0., 1.];
+    let y = array![0., 1.];
+
+    assert!(NoiseEstimator::new(&x, &y).estimate().is_err());
+
+    assert!(NoiseEstimator::new(&x, &y)
+        .with_method(DifferenceMethod::Rice)
+        .estimate()
+        .is_ok());
+}
@@ -0,0 +1,157 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2};
+
+use csaps::{ParametricSmoothingSpline, Parametrization};
+
+fn circle(n: usize) -> Array2<f64> {
+    Array2::from_shape_fn((n, 2), |(i, j)| {
+        let a = 2. * std::f64::consts::PI * i as f64 / n as f64;
+        if j == 0 {
+            a.cos()
+        } else {
+            a.sin()
+        }
+    })
+}
+
+#[test]
+fn test_parametrization() {
+    let points = array![[0., 0.], [3., 4.], [3., 5.], [3., 14.]];
+
+    let parameter = |parametrization| {
+        ParametricSmoothingSpline::new(&points)
+            .with_parametrization(parametrization)
+            .make()
+            .unwrap()
+            .parameter()
+            .unwrap()
+            .to_owned()
+    };
+
+    assert_abs_diff_eq!(
+        parameter(Parametrization::Uniform),
+        array![0., 1. / 3., 2. / 3., 1.],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        parameter(Parametrization::ChordLength),
+        array![0., 5. / 15., 6. / 15., 1.],
+        epsilon = 1e-12
+    );
+
+    let total = 5f64.sqrt() + 1. + 3.;
+    assert_abs_diff_eq!(
+        parameter(Parametrization::Centripetal),
+        array![0., 5f64.sqrt() / total, (5f64.sqrt() + 1.) / total, 1.],
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_interpolation() {
+    let points = array![
+        [0., 0., 0.],
+        [1., 0.5, 0.2],
+        [2., 0.8, 0.9],
+        [2.5, 2., 1.5],
+        [2., 3., 2.]
+    ];
+
+    let s = ParametricSmoothingSpline::new(&points)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let t = s.parameter().unwrap().to_owned();
+    let pi = s.evaluate(&t).unwrap();
+
+    assert_abs_diff_eq!(pi, points, epsilon = 1e-10);
+    assert_eq!(s.spline().unwrap().ndim(), 3);
+}
+
+#[test]
+fn test_closed_curve() {
+    let points = circle(12);
+
+    let s = ParametricSmoothingSpline::new(&points)
+        .with_periodic(true)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    assert!(s.periodic());
+
+    let t = s.parameter().unwrap().to_owned();
+    assert_eq!(t.len(), 12);
+    assert_abs_diff_eq!(s.evaluate(&t).unwrap(), points, epsilon = 1e-8);
+
+    // The curve is closed and smooth at the period ends
+    let ends = s.evaluate(&array![0., 1., 1e-6, 1. - 1e-6]).unwrap();
+    assert_abs_diff_eq!(ends.row(0), ends.row(1), epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        &ends.row(2) - &ends.row(0),
+        &ends.row(0) - &ends.row(3),
+        epsilon = 1e-10
+    );
+
+    // The points of the curve are close to the circle
+    let ti = Array1::linspace(0., 1., 101);
+    let radius = s
+        .evaluate(&ti)
+        .unwrap()
+        .rows()
+        .into_iter()
+        .map(|p| (p[0] * p[0] + p[1] * p[1]).sqrt())
+        .collect::<Array1<f64>>();
+    assert_abs_diff_eq!(radius, Array1::ones(101), epsilon = 1e-3);
+}
+
+#[test]
+fn test_closed_curve_repeated_point() {
+    let mut points = circle(10);
+    let first = points.row(0).to_owned();
+    points.push_row(first.view()).unwrap();
+
+    let s = ParametricSmoothingSpline::new(&points)
+        .with_periodic(true)
+        .with_smooth(0.99)
+        .make()
+        .unwrap();
+
+    assert_eq!(s.parameter().unwrap().len(), 10);
+}
+
+#[test]
+fn test_auto_smooth() {
+    let points = circle(20);
+
+    let s = ParametricSmoothingSpline::new(&points).make().unwrap();
+    let smooth = s.smooth().unwrap();
+
+    assert!(smooth > 0. && smooth < 1.);
+}
+
+#[test]
+fn test_invalid_data() {
+    let points = array![[0., 0.], [1., 1.], [1., 1.], [2., 0.]];
+    assert!(ParametricSmoothingSpline::new(&points).make().is_err());
+    assert!(ParametricSmoothingSpline::new(&points)
+        .with_parametrization(Parametrization::Uniform)
+        .make()
+        .is_ok());
+
+    let points = array![[0., 0.], [1., 1.]];
+    assert!(ParametricSmoothingSpline::new(&points)
+        .with_periodic(true)
+        .make()
+        .is_err());
+
+    let weights = array![1., 1., 1.];
+    assert!(ParametricSmoothingSpline::new(&points)
+        .with_weights(&weights)
+        .make()
+        .is_err());
+
+    let s = ParametricSmoothingSpline::new(&points);
+    assert!(s.evaluate(&array![0.5]).is_err());
+}
@@ -0,0 +1,99 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2};
+
+use csaps::PartiallyLinearModel;
+
+fn covariates(t: &Array1<f64>) -> Array2<f64> {
+    Array2::from_shape_fn((t.len(), 2), |(i, j)| match j {
+        0 => (t[i] * 1.7).cos() * 3.0 + (i % 3) as f64,
+        _ => ((i * 7) % 5) as f64 - 2.0,
+    })
+}
+
+#[test]
+fn test_linear_trend_is_least_squares() {
+    let t = Array1::linspace(0., 5., 30);
+    let x = covariates(&t);
+    let y = t.mapv(|v| 1. + 0.5 * v) + x.column(0).mapv(|v| 3. * v) - x.column(1).mapv(|v| v * 0.2);
+
+    let model = PartiallyLinearModel::new(&t, &x, &y)
+        .with_smooth(0.0)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        model.coefficients().unwrap(),
+        array![3., -0.2],
+        epsilon = 1e-8
+    );
+    assert_abs_diff_eq!(model.residual_df().unwrap(), 26., epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        model.standard_errors().unwrap(),
+        array![0., 0.],
+        epsilon = 1e-6
+    );
+
+    let yi = model.predict(&t, &x).unwrap();
+    assert_abs_diff_eq!(yi, y, epsilon = 1e-8);
+}
+
+#[test]
+fn test_smooth_trend_and_coefficients() {
+    let t = Array1::linspace(0., 10., 80);
+    let x = covariates(&t);
+
+    let trend = t.mapv(|v| (v * 0.6).sin() * 2.);
+    let noise = Array1::from_shape_fn(t.len(), |i| ((i * 37) % 11) as f64 / 50. - 0.1);
+    let y = &trend + &x.column(0).mapv(|v| 1.5 * v) + &x.column(1).mapv(|v| -0.7 * v) + &noise;
+
+    let model = PartiallyLinearModel::new(&t, &x, &y)
+        .with_smooth(0.9)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        model.coefficients().unwrap(),
+        array![1.5, -0.7],
+        epsilon = 0.05
+    );
+
+    let se = model.standard_errors().unwrap();
+    assert!(se.iter().all(|v| v.is_finite() && *v > 0. && *v < 0.05));
+
+    let fi = model.evaluate(&t).unwrap();
+    assert_abs_diff_eq!(fi, trend, epsilon = 0.2);
+}
+
+#[test]
+fn test_auto_smooth() {
+    let t = Array1::linspace(0., 10., 40);
+    let x = covariates(&t);
+    let y = t.mapv(|v| (v * 0.6).sin()) + x.column(0).mapv(|v| 2. * v);
+
+    let model = PartiallyLinearModel::new(&t, &x, &y).make().unwrap();
+
+    let smooth = model.smooth().unwrap();
+    assert!(smooth > 0. && smooth < 1.);
+}
+
+#[test]
+fn test_intercept_covariate_error() {
+    let t = array![0., 1., 2., 3., 4., 5.];
+    let x = Array2::ones((6, 1));
+    let y = array![1., 2., 1., 3., 2., 4.];
+
+    let model = PartiallyLinearModel::new(&t, &x, &y)
+        .with_smooth(0.5)
+        .make();
+
+    assert!(model.is_err());
+}
+
+#[test]
+fn test_covariates_shape_error() {
+    let t = array![0., 1., 2., 3., 4., 5.];
+    let x = Array2::ones((5, 1));
+    let y = array![1., 2., 1., 3., 2., 4.];
+
+    assert!(PartiallyLinearModel::new(&t, &x, &y).make().is_err());
+}
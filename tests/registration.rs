@@ -0,0 +1,161 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2, Axis};
+
+use csaps::{CubicSmoothingSpline, CurveRegistration, NdSpline};
+
+const SHIFTS: [f64; 4] = [-0.6, -0.2, 0.3, 0.5];
+
+fn bumps(x: &Array1<f64>) -> Array2<f64> {
+    Array2::from_shape_fn((SHIFTS.len(), x.len()), |(i, j)| {
+        let t: f64 = x[j] - 5. - SHIFTS[i];
+        (-t * t / 2.).exp()
+    })
+}
+
+fn spread(values: &Array2<f64>) -> f64 {
+    values.var_axis(Axis(0), 0.).sum()
+}
+
+fn is_increasing(spline: &NdSpline<f64>, xi: &Array1<f64>) -> bool {
+    let values = spline.evaluate(xi.view());
+    values.row(0).windows(2).into_iter().all(|w| w[1] > w[0])
+}
+
+#[test]
+fn test_landmark_registration() {
+    let x = Array1::linspace(0., 10., 101);
+    let y = bumps(&x);
+    let landmarks = Array1::from(SHIFTS.to_vec())
+        .mapv(|s| 5. + s)
+        .insert_axis(Axis(1));
+
+    let css = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let registration = CurveRegistration::new(css.spline().unwrap())
+        .with_landmarks(&landmarks)
+        .make()
+        .unwrap();
+
+    let target = landmarks.mean().unwrap();
+    let warps = registration.warps().unwrap();
+    assert_eq!(warps.len(), 4);
+
+    let xi = Array1::linspace(0., 10., 501);
+
+    for (warp, &landmark) in warps.iter().zip(landmarks.iter()) {
+        let ends = warp.evaluate(array![0., target, 10.].view());
+        assert_abs_diff_eq!(ends.row(0), array![0., landmark, 10.], epsilon = 1e-10);
+        assert!(is_increasing(warp, &xi));
+    }
+
+    // The peaks of the aligned curves are at the mean landmark
+    let aligned = registration.registered().unwrap();
+    assert_eq!(aligned.ndim(), 4);
+    assert_abs_diff_eq!(aligned.breaks(), x.view());
+
+    let peaks = aligned.evaluate(array![target].view());
+    assert_abs_diff_eq!(peaks, Array2::ones((4, 1)), epsilon = 1e-3);
+
+    let yi = css.evaluate(&xi).unwrap();
+    assert!(spread(&aligned.evaluate(xi.view())) < 0.05 * spread(&yi));
+}
+
+#[test]
+fn test_continuous_registration() {
+    let x = Array1::linspace(0., 10., 51);
+    let y = bumps(&x);
+
+    let css = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let registration = CurveRegistration::new(css.spline().unwrap())
+        .with_knots(3)
+        .make()
+        .unwrap();
+
+    let xi = Array1::linspace(0., 10., 501);
+
+    for warp in registration.warps().unwrap() {
+        let ends = warp.evaluate(array![0., 10.].view());
+        assert_abs_diff_eq!(ends.row(0), array![0., 10.], epsilon = 1e-10);
+        assert!(is_increasing(warp, &xi));
+    }
+
+    let yi = css.evaluate(&xi).unwrap();
+    let aligned = registration.registered().unwrap().evaluate(xi.view());
+
+    assert!(spread(&aligned) < 0.1 * spread(&yi));
+}
+
+#[test]
+fn test_monotone_warps_with_smoothing() {
+    let x = Array1::linspace(0., 10., 51);
+    let y = bumps(&x);
+    let landmarks = array![
+        [1.0, 1.2, 9.0],
+        [2.0, 8.5, 8.8],
+        [1.5, 5.0, 9.5],
+        [1.2, 3.0, 9.2]
+    ];
+
+    let css = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let registration = CurveRegistration::new(css.spline().unwrap())
+        .with_landmarks(&landmarks)
+        .with_smooth(0.9)
+        .make()
+        .unwrap();
+
+    let xi = Array1::linspace(0., 10., 1001);
+
+    for warp in registration.warps().unwrap() {
+        assert!(is_increasing(warp, &xi));
+    }
+}
+
+#[test]
+fn test_invalid_data() {
+    let x = Array1::linspace(0., 10., 11);
+    let y = bumps(&x);
+
+    let css = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+    let spline = css.spline().unwrap();
+
+    let landmarks = array![[5.], [4.], [6.]];
+    assert!(CurveRegistration::new(spline)
+        .with_landmarks(&landmarks)
+        .make()
+        .is_err());
+
+    let landmarks = array![[5., 4.], [4., 5.], [6., 7.], [3., 4.]];
+    assert!(CurveRegistration::new(spline)
+        .with_landmarks(&landmarks)
+        .make()
+        .is_err());
+
+    let landmarks = array![[5.], [4.], [10.], [3.]];
+    assert!(CurveRegistration::new(spline)
+        .with_landmarks(&landmarks)
+        .make()
+        .is_err());
+
+    assert!(CurveRegistration::new(spline).with_knots(0).make().is_err());
+    assert!(CurveRegistration::new(spline)
+        .with_smooth(1.5)
+        .make()
+        .is_err());
+
+    let single = NdSpline::new(array![0., 1.], array![[1., 0.]]);
+    assert!(CurveRegistration::new(&single).make().is_err());
+}
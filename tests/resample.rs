@@ -0,0 +1,95 @@
+use std::f64::consts::PI;
+
+use approx::assert_abs_diff_eq;
+use ndarray::{array, s, Array1, Axis};
+
+use csaps::{CubicSmoothingSpline, Resampler};
+
+#[test]
+fn test_resample_uniform_grid() {
+    let x = array![0.0, 0.3, 0.5, 1.1, 1.6, 2.0, 2.7, 3.2, 3.6, 4.1];
+    let y = array![0.2, 0.9, 1.1, 0.4, -0.5, -0.9, -0.3, 0.5, 1.0, 0.7];
+
+    let (xi, yi) = Resampler::new(&x, &y, 0.5)
+        .with_smooth(0.9)
+        .resample()
+        .unwrap();
+
+    assert_abs_diff_eq!(xi, Array1::linspace(0., 4., 9), epsilon = 1e-12);
+
+    let expected = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(0.9)
+        .make()
+        .unwrap()
+        .evaluate(&xi)
+        .unwrap();
+
+    assert_abs_diff_eq!(yi, expected, epsilon = 1e-12);
+}
+
+#[test]
+fn test_resample_keeps_axis_layout() {
+    let x = array![0.0, 0.3, 0.5, 1.1, 1.6, 2.0];
+    let y = array![
+        [0.2, 1.0],
+        [0.9, 2.0],
+        [1.1, 3.0],
+        [0.4, 4.0],
+        [-0.5, 5.0],
+        [-0.9, 6.0]
+    ];
+
+    let (xi, yi) = Resampler::new(&x, &y, 0.4)
+        .with_axis(Axis(0))
+        .resample()
+        .unwrap();
+
+    assert_eq!(xi.len(), 6);
+    assert_eq!(yi.shape(), &[6, 2]);
+}
+
+#[test]
+fn test_anti_aliasing_attenuates_high_frequencies() {
+    let x = Array1::linspace(0., 10., 101);
+    let y = x.mapv(|v| (2. * PI * 3. * v).sin());
+
+    // The new Nyquist frequency is 1, the signal frequency is 3
+    let (_, yi) = Resampler::new(&x, &y, 0.5)
+        .with_smooth(1.0)
+        .with_anti_aliasing(true)
+        .resample()
+        .unwrap();
+
+    let interior = yi.slice(s![2..yi.len() - 2]);
+    assert!(interior.iter().all(|v| v.abs() < 0.1));
+
+    let (_, yi) = Resampler::new(&x, &y, 0.55)
+        .with_smooth(1.0)
+        .resample()
+        .unwrap();
+
+    assert!(yi.iter().any(|v| v.abs() > 0.5));
+}
+
+#[test]
+fn test_anti_aliasing_keeps_low_frequencies() {
+    let x = Array1::linspace(0., 10., 101);
+    let y = x.mapv(|v| (2. * PI * 0.1 * v).sin());
+
+    let (xi, yi) = Resampler::new(&x, &y, 0.5)
+        .with_smooth(1.0)
+        .with_anti_aliasing(true)
+        .resample()
+        .unwrap();
+
+    assert_abs_diff_eq!(yi, xi.mapv(|v| (2. * PI * 0.1 * v).sin()), epsilon = 0.01);
+}
+
+#[test]
+fn test_invalid_period_error() {
+    let x = array![0., 1., 2., 3.];
+    let y = array![0., 1., 2., 3.];
+
+    assert!(Resampler::new(&x, &y, 0.).resample().is_err());
+    assert!(Resampler::new(&x, &y, -1.).resample().is_err());
+}
@@ -0,0 +1,92 @@
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+use approx::assert_abs_diff_eq;
+use ndarray::{array, s, Array1};
+
+use csaps::{frequency_response, smooth_from_cutoff, CubicSmoothingSpline};
+
+#[test]
+fn test_response_at_cutoff() {
+    for (cutoff, spacing) in [(1.0, 0.1), (5.0, 0.01), (0.5, 1.0), (12.0, 0.02)] {
+        let smooth = smooth_from_cutoff(cutoff, spacing).unwrap();
+        let response = frequency_response(smooth, spacing, &[cutoff]).unwrap();
+
+        assert_abs_diff_eq!(response[0], FRAC_1_SQRT_2, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn test_response_bounds() {
+    let frequencies = array![0., 0.5, 1., 2., 5.];
+
+    let response = frequency_response(1.0, 0.1, &frequencies).unwrap();
+    assert_abs_diff_eq!(response, Array1::ones(5), epsilon = 1e-12);
+
+    let response = frequency_response(0.0, 0.1, &frequencies).unwrap();
+    assert_abs_diff_eq!(response, array![1., 0., 0., 0., 0.], epsilon = 1e-12);
+
+    let response = frequency_response(0.9, 0.1, &frequencies).unwrap();
+    assert!(response.windows(2).into_iter().all(|w| w[1] < w[0]));
+}
+
+#[test]
+fn test_response_of_smoothing_spline() {
+    let spacing = 0.1;
+    let frequency = 0.8;
+
+    let x = Array1::linspace(0., 100., 1001);
+    let y = x.mapv(|v| (2. * PI * frequency * v).sin());
+
+    let smooth = smooth_from_cutoff(1.0, spacing).unwrap();
+    let expected = frequency_response(smooth, spacing, &[frequency]).unwrap()[0];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(smooth)
+        .make()
+        .unwrap();
+
+    let ys = s.evaluate(&x).unwrap();
+
+    // The ends effects are negligible in the middle of the data
+    let interior = s![300..700];
+    assert_abs_diff_eq!(
+        ys.slice(interior),
+        &y.slice(interior) * expected,
+        epsilon = 1e-3
+    );
+}
+
+#[test]
+fn test_with_cutoff() {
+    let x = Array1::linspace(0., 1., 101);
+    let y = x.mapv(|v: f64| (v * 10.).sin() + 0.1 * (v * 300.).sin());
+
+    let s1 = CubicSmoothingSpline::new(&x, &y)
+        .with_cutoff(5.0, 0.01)
+        .make()
+        .unwrap();
+
+    let s2 = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(smooth_from_cutoff(5.0, 0.01).unwrap())
+        .make()
+        .unwrap();
+
+    assert_eq!(s1.smooth(), s2.smooth());
+    assert_abs_diff_eq!(s1.evaluate(&x).unwrap(), s2.evaluate(&x).unwrap());
+}
+
+#[test]
+fn test_cutoff_out_of_range_error() {
+    assert!(smooth_from_cutoff(6.0, 0.1).is_err());
+    assert!(smooth_from_cutoff(0.0, 0.1).is_err());
+    assert!(smooth_from_cutoff(1.0, 0.0).is_err());
+    assert!(frequency_response(1.5, 0.1, &[1.0]).is_err());
+
+    let x = array![0., 1., 2., 3.];
+    let y = array![0., 1., 2., 3.];
+
+    assert!(CubicSmoothingSpline::new(&x, &y)
+        .with_cutoff(1.0, 1.0)
+        .make()
+        .is_err());
+}
@@ -0,0 +1,222 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, s, Array1, Axis};
+
+use csaps::{CubicSmoothingSpline, TensionSmoothingSpline};
+
+const EPS: f64 = 1e-8;
+
+#[test]
+fn test_zero_tension_is_cubic_smoothing_spline() {
+    let x = array![1., 2.5, 3., 4.2, 5., 7.];
+    let y = array![1.5, 3.5, 2.6, 1.2, 4.4, 2.2];
+    let w = array![1., 0.5, 0.7, 1., 0.6, 1.];
+    let xi = Array1::linspace(1., 7., 25);
+
+    let cs = CubicSmoothingSpline::new(&x, &y)
+        .with_weights(&w)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    let ts = TensionSmoothingSpline::new(&x, &y)
+        .with_weights(&w)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        ts.evaluate(&xi).unwrap(),
+        cs.evaluate(&xi).unwrap(),
+        epsilon = EPS
+    );
+}
+
+#[test]
+fn test_zero_tension_auto_smooth() {
+    let x = array![1., 2., 3., 4., 5.];
+    let y = array![1.5, 3.5, 2.6, 1.2, 4.4];
+
+    let cs = CubicSmoothingSpline::new(&x, &y).make().unwrap();
+    let ts = TensionSmoothingSpline::new(&x, &y).make().unwrap();
+
+    assert_abs_diff_eq!(ts.smooth().unwrap(), cs.smooth().unwrap(), epsilon = EPS);
+    assert_abs_diff_eq!(
+        ts.evaluate(&x).unwrap(),
+        cs.evaluate(&x).unwrap(),
+        epsilon = EPS
+    );
+}
+
+#[test]
+fn test_small_tension_is_continuous() {
+    let x = array![1., 2., 3., 4., 5.];
+    let y = array![1.5, 3.5, 2.6, 1.2, 4.4];
+    let xi = Array1::linspace(1., 5., 17);
+
+    let eval = |tension: f64| {
+        TensionSmoothingSpline::new(&x, &y)
+            .with_smooth(0.9)
+            .with_tension(tension)
+            .make()
+            .unwrap()
+            .evaluate(&xi)
+            .unwrap()
+    };
+
+    assert_abs_diff_eq!(eval(0.019), eval(0.021), epsilon = 1e-4);
+    assert_abs_diff_eq!(eval(1e-4), eval(0.), epsilon = 1e-6);
+}
+
+#[test]
+fn test_high_tension_interpolant_is_piecewise_linear() {
+    let x = array![0., 1., 2., 3., 4.];
+    let y = array![0., 4., 1., 3., 0.];
+
+    let s = TensionSmoothingSpline::new(&x, &y)
+        .with_smooth(1.0)
+        .with_tension(200.)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(s.evaluate(&x).unwrap(), y, epsilon = EPS);
+
+    let xi = array![0.5, 1.5, 2.5, 3.5];
+    let yi_linear = array![2., 2.5, 2., 1.5];
+
+    assert_abs_diff_eq!(s.evaluate(&xi).unwrap(), yi_linear, epsilon = 1e-2);
+}
+
+#[test]
+fn test_high_tension_smoothing_is_piecewise_linear() {
+    let x = Array1::linspace(0., 6., 7);
+    let y = array![1., 7., 2., 6., 3., 5., 4.];
+    let xm = array![0.5, 1.5, 2.5, 3.5, 4.5, 5.5];
+
+    let s = TensionSmoothingSpline::new(&x, &y)
+        .with_smooth(0.99)
+        .with_tension(50.)
+        .make()
+        .unwrap();
+
+    // The tension does not pull the smoothing fit toward the weighted mean
+    let yi = s.evaluate(&x).unwrap();
+    assert!(yi.fold(f64::MIN, |a, &v| a.max(v)) - yi.fold(f64::MAX, |a, &v| a.min(v)) > 5.);
+
+    let yi_linear = (&yi.slice(s![..-1]) + &yi.slice(s![1..])) / 2.;
+    assert_abs_diff_eq!(s.evaluate(&xm).unwrap(), yi_linear, epsilon = 0.1);
+}
+
+#[test]
+fn test_tension_auto_smooth() {
+    let x = array![1., 2., 3., 4., 5.];
+    let y = array![1.5, 3.5, 2.6, 1.2, 4.4];
+
+    let cs = CubicSmoothingSpline::new(&x, &y).make().unwrap();
+    let ts = TensionSmoothingSpline::new(&x, &y)
+        .with_tension(5.)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(ts.smooth().unwrap(), cs.smooth().unwrap(), epsilon = EPS);
+}
+
+#[test]
+fn test_tension_reduces_overshoot() {
+    let x = array![0., 1., 2., 3., 4., 5.];
+    let y = array![0., 0., 1., 1., 0., 0.];
+    let xi = Array1::linspace(0., 5., 101);
+
+    let max_dev = |tension: f64| {
+        let yi = TensionSmoothingSpline::new(&x, &y)
+            .with_smooth(1.0)
+            .with_tension(tension)
+            .make()
+            .unwrap()
+            .evaluate(&xi)
+            .unwrap();
+
+        yi.iter()
+            .map(|&v| (v - v.clamp(0., 1.)).abs())
+            .fold(0., f64::max)
+    };
+
+    assert!(max_dev(10.) < max_dev(1.));
+    assert!(max_dev(1.) < max_dev(0.));
+}
+
+#[test]
+fn test_linear_extrapolation() {
+    let x = array![0., 1., 2., 3.];
+    let y = array![0., 2., 1., 3.];
+
+    let s = TensionSmoothingSpline::new(&x, &y)
+        .with_smooth(0.9)
+        .with_tension(3.)
+        .make()
+        .unwrap();
+
+    let yi = s.evaluate(&array![-2., -1., 0., 3., 4., 5.]).unwrap();
+
+    assert_abs_diff_eq!(yi[1] - yi[0], yi[2] - yi[1], epsilon = EPS);
+    assert_abs_diff_eq!(yi[4] - yi[3], yi[5] - yi[4], epsilon = EPS);
+}
+
+#[test]
+fn test_zero_smooth_is_weighted_mean() {
+    let x = array![0., 1., 2., 3.];
+    let y = array![1., 2., 4., 5.];
+
+    let s = TensionSmoothingSpline::new(&x, &y)
+        .with_smooth(0.)
+        .with_tension(1.)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        s.evaluate(&x).unwrap(),
+        array![3., 3., 3., 3.],
+        epsilon = EPS
+    );
+}
+
+#[test]
+fn test_multivariate_axis() {
+    let x = array![1., 2., 3., 4., 5.];
+    let y = array![[1., 5.], [3., 6.], [2., 9.], [4., 8.], [1., 7.]];
+    let xi = Array1::linspace(0., 6., 13);
+
+    let ys = TensionSmoothingSpline::new(&x, &y)
+        .with_axis(Axis(0))
+        .with_smooth(0.7)
+        .with_tension(2.)
+        .make()
+        .unwrap()
+        .evaluate(&xi)
+        .unwrap();
+
+    assert_eq!(ys.shape(), &[13, 2]);
+
+    for (k, y_col) in y.columns().into_iter().enumerate() {
+        let ys_col = TensionSmoothingSpline::new(&x, &y_col.to_owned())
+            .with_smooth(0.7)
+            .with_tension(2.)
+            .make()
+            .unwrap()
+            .evaluate(&xi)
+            .unwrap();
+
+        assert_abs_diff_eq!(ys.column(k), ys_col, epsilon = EPS);
+    }
+}
+
+#[test]
+#[should_panic(expected = "`tension` value must be non-negative and finite")]
+fn test_negative_tension_error() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![1., 2., 3., 4.];
+
+    TensionSmoothingSpline::new(&x, &y)
+        .with_tension(-1.)
+        .make()
+        .unwrap();
+}
@@ -0,0 +1,200 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Axis};
+
+use csaps::TrendFilter;
+
+#[test]
+fn test_zero_lambda_interpolates() {
+    let x = array![0., 1., 3., 4., 6.];
+    let y = array![1., 3., 2., 5., 4.];
+
+    let s = TrendFilter::new(&x, &y).with_lambda(0.).make().unwrap();
+
+    assert_eq!(s.spline().unwrap().order(), 2);
+    assert_abs_diff_eq!(s.evaluate(&x).unwrap(), y, epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        s.evaluate(&array![0.5, 2., 5.]).unwrap(),
+        array![2., 2.5, 4.5],
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_zero_lambda_interpolates_low_degrees() {
+    let x = array![1., 2., 3., 4., 5., 6.];
+    let y = array![0., 0., 0., 5., 5., 5.];
+
+    for degree in 0..2 {
+        let s = TrendFilter::new(&x, &y)
+            .with_degree(degree)
+            .with_lambda(0.)
+            .make()
+            .unwrap();
+
+        assert_eq!(s.evaluate(&x).unwrap(), y);
+    }
+
+    let x = array![0., 1., 3., 4., 6.];
+    let y = array![1., 3., 2., 5., 4.];
+
+    let s = TrendFilter::new(&x, &y)
+        .with_degree(0)
+        .with_lambda(0.)
+        .make()
+        .unwrap();
+
+    // The constant pieces take the values at their left ends
+    let xl = x.slice(ndarray::s![..4]);
+    assert_abs_diff_eq!(
+        s.evaluate(xl).unwrap(),
+        y.slice(ndarray::s![..4]),
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        s.evaluate(&array![0.5, 2., 5.]).unwrap(),
+        array![1., 3., 5.],
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_quadratic_data_nonuniform() {
+    let x = array![0., 0.5, 1.5, 2., 3.5, 4., 5.];
+    let f = |v: f64| 0.5 * v * v - 2. * v + 1.;
+    let y = x.mapv(f);
+
+    let s = TrendFilter::new(&x, &y)
+        .with_degree(2)
+        .with_lambda(10.)
+        .make()
+        .unwrap();
+
+    assert!(s.converged());
+
+    let xi = Array1::linspace(-1., 6., 29);
+    assert_abs_diff_eq!(s.evaluate(&xi).unwrap(), xi.mapv(f), epsilon = 1e-6);
+}
+
+#[test]
+fn test_large_lambda_is_linear_fit() {
+    let x = array![0., 1., 2., 3., 4., 5.];
+    let y = array![0.1, 1.2, 1.8, 3.3, 3.9, 5.2];
+
+    let s = TrendFilter::new(&x, &y).with_lambda(1000.).make().unwrap();
+
+    assert!(s.converged());
+
+    // Least-squares straight line
+    let slope = 17.55 / 17.5;
+    let intercept = 15.5 / 6. - 2.5 * slope;
+
+    assert_abs_diff_eq!(
+        s.evaluate(&x).unwrap(),
+        x.mapv(|v| intercept + slope * v),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_large_lambda_piecewise_constant_is_mean() {
+    let x = array![0., 1., 2., 3.];
+    let y = array![1., 2., 4., 5.];
+    let w = array![1., 1., 1., 3.];
+
+    let s = TrendFilter::new(&x, &y)
+        .with_degree(0)
+        .with_weights(&w)
+        .with_lambda(100.)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        s.evaluate(&x).unwrap(),
+        Array1::from_elem(4, 22. / 6.),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_keeps_kink() {
+    let x = Array1::linspace(0., 10., 21);
+    let y = x.mapv(|v: f64| if v < 5. { v } else { 10. - v });
+
+    let s = TrendFilter::new(&x, &y).with_lambda(0.01).make().unwrap();
+
+    assert!(s.converged());
+
+    let yi = s.evaluate(&array![2.25, 5., 7.75]).unwrap();
+    assert_abs_diff_eq!(yi, array![2.25, 5., 2.25], epsilon = 0.05);
+}
+
+#[test]
+fn test_multivariate_axis() {
+    let x = array![0., 1., 2., 3.];
+    let y = array![[1., 2.], [2., 4.], [3., 6.], [4., 8.]];
+
+    let ys = TrendFilter::new(&x, &y)
+        .with_axis(Axis(0))
+        .with_lambda(1.)
+        .make()
+        .unwrap()
+        .evaluate(&x)
+        .unwrap();
+
+    assert_abs_diff_eq!(ys, y, epsilon = 1e-6);
+}
+
+#[test]
+fn test_auto_lambda() {
+    let x = array![0., 1., 2., 3., 4., 5.];
+    let y = array![0.1, 1.2, 1.8, 3.3, 3.9, 5.2];
+
+    let s = TrendFilter::new(&x, &y).make().unwrap();
+    let lambda = s.lambda().unwrap();
+
+    assert!(lambda > 0.);
+    assert!(s.converged());
+
+    // The lambda ten times greater than the default gives the least-squares straight line
+    let s = TrendFilter::new(&x, &y)
+        .with_lambda(lambda * 10.)
+        .make()
+        .unwrap();
+
+    let slope = 17.55 / 17.5;
+    let intercept = 15.5 / 6. - 2.5 * slope;
+
+    assert_abs_diff_eq!(
+        s.evaluate(&x).unwrap(),
+        x.mapv(|v| intercept + slope * v),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_auto_lambda_minimal_data() {
+    let x = array![0., 1., 2.];
+    let y = array![0., 2., 1.];
+
+    let s = TrendFilter::new(&x, &y).make().unwrap();
+
+    assert!(s.lambda().unwrap() > 0.);
+    assert!(s.converged());
+}
+
+#[test]
+fn test_invalidate_resets_iterations() {
+    let x = Array1::linspace(0., 10., 21);
+    let y = x.mapv(|v: f64| if v < 5. { v } else { 10. - v });
+
+    let s = TrendFilter::new(&x, &y).with_lambda(0.01).make().unwrap();
+
+    assert!(s.converged());
+    assert!(s.iterations() > 0);
+
+    let s = s.with_lambda(0.1);
+
+    assert!(!s.converged());
+    assert_eq!(s.iterations(), 0);
+    assert!(s.spline().is_none());
+}
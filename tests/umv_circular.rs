@@ -0,0 +1,118 @@
+use std::f64::consts::PI;
+
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Axis};
+
+use csaps::{AngleRange, CubicSmoothingSpline, Transform};
+
+#[test]
+fn test_angle_range_wrap() {
+    assert_abs_diff_eq!(
+        AngleRange::Signed.wrap(PI + 0.5),
+        -PI + 0.5,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        AngleRange::Signed.wrap(-PI - 0.5),
+        PI - 0.5,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        AngleRange::Unsigned.wrap(-0.5),
+        2. * PI - 0.5,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(AngleRange::Unsigned.wrap(5. * PI), PI, epsilon = 1e-12);
+}
+
+#[test]
+fn test_circular_linear_heading_through_wrap() {
+    let x = Array1::linspace(0., 10., 11);
+    let heading = |v: f64| 2.5 + 0.3 * v;
+    let y = x.mapv(|v| AngleRange::Signed.wrap(heading(v)));
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_circular(AngleRange::Signed)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    assert_eq!(s.circular(), Some(AngleRange::Signed));
+
+    let xi = Array1::linspace(0., 10., 101);
+    let yi = s.evaluate(&xi).unwrap();
+
+    assert!(yi.iter().all(|&v| (-PI..PI).contains(&v)));
+    assert_abs_diff_eq!(
+        yi,
+        xi.mapv(|v| AngleRange::Signed.wrap(heading(v))),
+        epsilon = 1e-10
+    );
+
+    let rates = s.evaluate_derivative(&xi, 1).unwrap();
+    assert_abs_diff_eq!(rates, Array1::from_elem(101, 0.3), epsilon = 1e-10);
+}
+
+#[test]
+fn test_circular_unsigned_range() {
+    let x = array![0., 1., 2., 3., 4.];
+    let y = array![6.0, 6.2, 0.1, 0.3, 0.5];
+
+    let yi = CubicSmoothingSpline::new(&x, &y)
+        .with_circular(AngleRange::Unsigned)
+        .with_smooth(0.8)
+        .make()
+        .unwrap()
+        .evaluate(&Array1::linspace(0., 4., 41))
+        .unwrap();
+
+    assert!(yi.iter().all(|&v| (0. ..2. * PI).contains(&v)));
+
+    // The smoothed heading does not go through the opposite direction
+    assert!(yi.iter().all(|&v| !(1.0..5.0).contains(&v)));
+}
+
+#[test]
+fn test_circular_multivariate_axis() {
+    let x = array![0., 1., 2., 3.];
+    let y = array![[3.0, 0.1], [-3.1, 0.2], [-2.9, 0.3], [-2.7, 0.4]];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_axis(Axis(0))
+        .with_circular(AngleRange::Signed)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(s.evaluate(&x).unwrap(), y, epsilon = 1e-10);
+
+    let rates = s.evaluate_derivative(&x, 1).unwrap();
+    assert!(rates.column(0).iter().all(|&r| r > 0. && r < 1.));
+}
+
+#[test]
+fn test_circular_x_transform() {
+    let x = array![1., 2., 4., 8., 16.];
+    let phase = |v: f64| 2.5 + 0.6 * v.ln();
+    let y = x.mapv(|v| AngleRange::Signed.wrap(phase(v)));
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_circular(AngleRange::Signed)
+        .with_x_transform(Transform::Log)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    let xi = Array1::linspace(1., 16., 31);
+    let yi = s.evaluate(&xi).unwrap();
+
+    assert!(yi.iter().all(|&v| (-PI..PI).contains(&v)));
+    assert_abs_diff_eq!(
+        yi,
+        xi.mapv(|v| AngleRange::Signed.wrap(phase(v))),
+        epsilon = 1e-10
+    );
+
+    let rates = s.evaluate_derivative(&xi, 1).unwrap();
+    assert_abs_diff_eq!(rates, xi.mapv(|v| 0.6 / v), epsilon = 1e-10);
+}
@@ -0,0 +1,131 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, s, Array1};
+
+use csaps::CubicSmoothingSpline;
+
+#[test]
+fn test_jump_splits_into_independent_segments() {
+    let x = array![0., 1., 2., 3., 4., 5., 6., 7., 8.];
+    let y = array![0.1, 0.3, 0.0, 0.4, 5.2, 4.8, 5.5, 5.1, 4.9];
+    let jumps = array![3.5];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_jumps(&jumps)
+        .with_smooth(0.7)
+        .make()
+        .unwrap();
+
+    let (x_left, y_left) = (x.slice(s![..4]), y.slice(s![..4]));
+    let (x_right, y_right) = (x.slice(s![4..]), y.slice(s![4..]));
+
+    let s_left = CubicSmoothingSpline::new(x_left, y_left)
+        .with_smooth(0.7)
+        .make()
+        .unwrap();
+
+    let s_right = CubicSmoothingSpline::new(x_right, y_right)
+        .with_smooth(0.7)
+        .make()
+        .unwrap();
+
+    let xi_left = Array1::linspace(0., 3., 13);
+    let xi_right = Array1::linspace(4., 8., 17);
+
+    assert_abs_diff_eq!(
+        s.evaluate(&xi_left).unwrap(),
+        s_left.evaluate(&xi_left).unwrap(),
+        epsilon = 1e-6
+    );
+
+    assert_abs_diff_eq!(
+        s.evaluate(&xi_right).unwrap(),
+        s_right.evaluate(&xi_right).unwrap(),
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn test_jump_left_and_right_limits() {
+    let x = array![0., 1., 2., 3., 4., 5., 6.];
+    let y = array![1., 1., 1., 3., 3., 3., 3.];
+    let jumps = array![3.];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_jumps(&jumps)
+        .with_smooth(0.5)
+        .make()
+        .unwrap();
+
+    let spline = s.spline().unwrap();
+    let xi = array![3.];
+
+    assert_abs_diff_eq!(spline.evaluate_left(xi.view())[[0, 0]], 1., epsilon = 1e-6);
+    assert_abs_diff_eq!(spline.evaluate(xi.view())[[0, 0]], 3., epsilon = 1e-6);
+    assert_abs_diff_eq!(s.evaluate(&xi).unwrap()[0], 3., epsilon = 1e-6);
+}
+
+#[test]
+fn test_kink_reproduces_piecewise_linear() {
+    let x = array![0., 1., 2., 3.5, 4., 5., 6.];
+    let y = x.mapv(|v: f64| (v - 3.).abs());
+    let xi = Array1::linspace(0., 6., 25);
+    let kinks = array![3.];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_kinks(&kinks)
+        .with_smooth(0.5)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        s.evaluate(&xi).unwrap(),
+        xi.mapv(|v: f64| (v - 3.).abs()),
+        epsilon = 1e-6
+    );
+
+    let slopes = s.evaluate_derivative(&array![2.5, 3.5], 1).unwrap();
+    assert_abs_diff_eq!(slopes, array![-1., 1.], epsilon = 1e-6);
+}
+
+#[test]
+fn test_auto_smooth_with_jumps() {
+    let x = array![0., 1., 2., 3., 4., 5., 6., 7.];
+    let y = array![0.1, 0.0, 0.2, 0.1, 5.1, 4.9, 5.0, 5.2];
+    let jumps = array![3.5];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_jumps(&jumps)
+        .make()
+        .unwrap();
+
+    let smooth = s.smooth().unwrap();
+    assert!(smooth > 0. && smooth < 1.);
+}
+
+#[test]
+#[should_panic(expected = "at least 2 data sites")]
+fn test_segment_without_enough_data_error() {
+    let x = array![0., 1., 2., 3., 4.];
+    let y = array![1., 2., 3., 4., 5.];
+    let jumps = array![0.5];
+
+    CubicSmoothingSpline::new(&x, &y)
+        .with_jumps(&jumps)
+        .make()
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "must be different")]
+fn test_jump_and_kink_at_same_location_error() {
+    let x = array![0., 1., 2., 3., 4., 5.];
+    let y = array![1., 2., 3., 4., 5., 6.];
+    let jumps = array![2.5];
+    let kinks = array![2.5];
+
+    CubicSmoothingSpline::new(&x, &y)
+        .with_jumps(&jumps)
+        .with_kinks(&kinks)
+        .make()
+        .unwrap();
+}
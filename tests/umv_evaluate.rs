@@ -1,5 +1,6 @@
+use approx::assert_abs_diff_eq;
 use csaps::CubicSmoothingSpline;
-use ndarray::array;
+use ndarray::{array, Array1, Array2, Axis};
 
 #[test]
 fn test_evaluate_1d() {
@@ -59,3 +60,131 @@ fn test_evaluate_3d() {
 
     assert_eq!(ys, y);
 }
+
+#[test]
+fn test_evaluate_derivative_linear() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![[3., 5., 7., 9.], [1., 1., 1., 1.]];
+    let xi = array![0., 1.5, 2., 3.7, 5.];
+
+    let spline = CubicSmoothingSpline::new(&x, &y).make().unwrap();
+
+    let d1 = spline.evaluate_derivative(&xi, 1).unwrap();
+    let d2 = spline.evaluate_derivative(&xi, 2).unwrap();
+
+    assert_abs_diff_eq!(
+        d1,
+        array![[2., 2., 2., 2., 2.], [0., 0., 0., 0., 0.]],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(d2, Array2::zeros((2, 5)), epsilon = 1e-12);
+}
+
+#[test]
+fn test_evaluate_derivative_finite_differences() {
+    let x = array![1., 2., 3.5, 4., 6.];
+    let y = array![1.5, 3.5, 2.6, 1.2, 4.4];
+    let xi = array![1.2, 2.5, 3.9, 5.1];
+    let h = 1e-6;
+    let xi_l = &xi - h;
+    let xi_r = &xi + h;
+
+    let spline = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    let fd = |nu: usize| {
+        let yl = spline.evaluate_derivative(&xi_l, nu).unwrap();
+        let yr = spline.evaluate_derivative(&xi_r, nu).unwrap();
+        (yr - yl) / (2. * h)
+    };
+
+    assert_abs_diff_eq!(
+        spline.evaluate_derivative(&xi, 0).unwrap(),
+        spline.evaluate(&xi).unwrap()
+    );
+    assert_abs_diff_eq!(
+        spline.evaluate_derivative(&xi, 1).unwrap(),
+        fd(0),
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        spline.evaluate_derivative(&xi, 2).unwrap(),
+        fd(1),
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        spline.evaluate_derivative(&xi, 3).unwrap(),
+        fd(2),
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        spline.evaluate_derivative(&xi, 4).unwrap(),
+        Array1::zeros(4)
+    );
+}
+
+#[test]
+fn test_evaluate_derivative_axis() {
+    let x = array![1., 2., 3.5, 4., 6.];
+    let y = array![[1.5, 0.5], [3.5, 1.2], [2.6, 2.1], [1.2, 3.3], [4.4, 2.8]];
+    let xi = array![1.2, 2.5, 3.9, 5.1];
+
+    let spline = CubicSmoothingSpline::new(&x, &y)
+        .with_axis(Axis(0))
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    let yt = y.t().to_owned();
+    let spline_t = CubicSmoothingSpline::new(&x, &yt)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    for nu in 0..4 {
+        let d = spline.evaluate_derivative(&xi, nu).unwrap();
+        let d_t = spline_t.evaluate_derivative(&xi, nu).unwrap();
+
+        assert_eq!(d.shape(), &[4, 2]);
+        assert_abs_diff_eq!(d, d_t.t(), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_spline_derivative() {
+    let x = array![1., 2., 3.5, 4., 6.];
+    let y = array![[1.5, 3.5, 2.6, 1.2, 4.4], [0.5, 1.2, 2.1, 3.3, 2.8]];
+    let xi = array![1., 1.2, 2.5, 3.9, 5.1, 6.];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+    let spline = s.spline().unwrap();
+
+    for nu in 0..4 {
+        let derivative = spline.derivative(nu);
+
+        assert_eq!(derivative.order(), 4 - nu);
+        assert_eq!(derivative.pieces(), spline.pieces());
+        assert_eq!(derivative.breaks(), spline.breaks());
+        assert_abs_diff_eq!(
+            derivative.evaluate(xi.view()),
+            s.evaluate_derivative(&xi, nu).unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    // The derivative of the derivative
+    assert_abs_diff_eq!(
+        spline.derivative(1).derivative(1).coeffs(),
+        spline.derivative(2).coeffs(),
+        epsilon = 1e-12
+    );
+
+    let zero = spline.derivative(4);
+    assert_eq!(zero.order(), 1);
+    assert_abs_diff_eq!(zero.evaluate(xi.view()), Array2::zeros((2, 6)));
+}
@@ -0,0 +1,148 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, s, Array1, Axis};
+
+use csaps::{CubicSmoothingSpline, GapFill};
+
+#[test]
+fn test_segments_are_fitted_separately() {
+    let x = array![0., 1., 2., 3., 4., 20., 21., 22., 23.];
+    let y = array![1.0, 1.4, 0.9, 1.2, 1.1, 5.0, 5.6, 4.8, 5.3];
+
+    let xi_left = Array1::linspace(-1., 4., 11);
+    let xi_right = Array1::linspace(20., 24., 9);
+    let xi_gap = array![4.5, 12., 19.5];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_gap_threshold(5.)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    assert!(s.spline().is_none());
+
+    let segmented = s.segmented_spline().unwrap();
+    assert_eq!(segmented.segments().len(), 2);
+    assert_eq!(segmented.ranges(), &[(0., 4.), (20., 23.)]);
+
+    for (range, xi) in [(s![..5], &xi_left), (s![5..], &xi_right)] {
+        let segment = CubicSmoothingSpline::new(x.slice(range), y.slice(range))
+            .with_smooth(0.8)
+            .make()
+            .unwrap();
+
+        assert_abs_diff_eq!(
+            s.evaluate(xi).unwrap(),
+            segment.evaluate(xi).unwrap(),
+            epsilon = 1e-10
+        );
+    }
+
+    let yi = s.evaluate(&xi_gap).unwrap();
+    assert!(yi.iter().all(|v: &f64| v.is_nan()));
+}
+
+#[test]
+fn test_auto_smooth_for_every_segment() {
+    let x = array![0., 1., 2., 3., 4., 20., 20.1, 20.2, 20.3];
+    let y = array![1.0, 1.4, 0.9, 1.2, 1.1, 5.0, 5.6, 4.8, 5.3];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_gap_threshold(5.)
+        .make()
+        .unwrap();
+
+    let smooths = s.segmented_spline().unwrap().smooths();
+
+    assert_eq!(smooths.len(), 2);
+    assert!(smooths[0] != smooths[1]);
+    assert!(s.smooth().is_none());
+}
+
+#[test]
+fn test_gap_fill_policies() {
+    let x = array![0., 1., 2., 10., 11., 12.];
+    let y = array![1., 1., 1., 3., 3., 3.];
+    let xi = array![2., 4., 6., 8., 10.];
+
+    let evaluate = |fill| {
+        CubicSmoothingSpline::new(&x, &y)
+            .with_gap_threshold(5.)
+            .with_gap_fill(fill)
+            .make()
+            .unwrap()
+            .evaluate(&xi)
+            .unwrap()
+    };
+
+    assert_abs_diff_eq!(
+        evaluate(GapFill::Value(0.)),
+        array![1., 0., 0., 0., 3.],
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        evaluate(GapFill::Hold),
+        array![1., 1., 1., 1., 3.],
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        evaluate(GapFill::Linear),
+        array![1., 1.5, 2., 2.5, 3.],
+        epsilon = 1e-10
+    );
+}
+
+#[test]
+fn test_single_site_segment_is_constant() {
+    let x = array![0., 1., 2., 10., 20., 21., 22.];
+    let y = array![[1., 2., 1., 7., 3., 4., 3.], [0., 0., 0., -1., 0., 0., 0.]];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_gap_threshold(5.)
+        .with_smooth(0.9)
+        .make()
+        .unwrap();
+
+    assert_eq!(s.segmented_spline().unwrap().segments().len(), 3);
+
+    let xi = array![10.];
+
+    let yi = s.evaluate(&xi).unwrap();
+    assert_abs_diff_eq!(yi.index_axis(Axis(1), 0), array![7., -1.], epsilon = 1e-10);
+
+    let dyi = s.evaluate_derivative(&xi, 1).unwrap();
+    assert_abs_diff_eq!(dyi.index_axis(Axis(1), 0), array![0., 0.], epsilon = 1e-10);
+}
+
+#[test]
+fn test_without_gaps_is_cubic_smoothing_spline() {
+    let x = array![0., 1., 2., 3., 4.];
+    let y = array![1.0, 1.4, 0.9, 1.2, 1.1];
+    let xi = Array1::linspace(-1., 5., 13);
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_gap_threshold(5.)
+        .make()
+        .unwrap();
+
+    let cs = CubicSmoothingSpline::new(&x, &y).make().unwrap();
+
+    assert_abs_diff_eq!(
+        s.evaluate(&xi).unwrap(),
+        cs.evaluate(&xi).unwrap(),
+        epsilon = 1e-10
+    );
+}
+
+#[test]
+#[should_panic(expected = "Gap segmentation cannot be used")]
+fn test_gap_threshold_with_jumps_error() {
+    let x = array![0., 1., 2., 3., 4., 5.];
+    let y = array![1., 2., 3., 4., 5., 6.];
+    let jumps = array![2.5];
+
+    CubicSmoothingSpline::new(&x, &y)
+        .with_gap_threshold(5.)
+        .with_jumps(&jumps)
+        .make()
+        .unwrap();
+}
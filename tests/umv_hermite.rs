@@ -0,0 +1,167 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Axis};
+
+use csaps::CubicSmoothingSpline;
+
+#[test]
+fn test_zero_derivative_weights_is_cubic_smoothing_spline() {
+    let x = array![1., 2., 3.5, 4., 6., 7.];
+    let y = array![1.5, 3.5, 2.6, 1.2, 4.4, 2.2];
+    let w = array![1., 0.5, 0.7, 1., 0.6, 1.];
+    let xd = array![2.5, 5.];
+    let yd = array![10., -10.];
+    let wd = array![0., 0.];
+    let xi = Array1::linspace(0., 8., 33);
+
+    let cs = CubicSmoothingSpline::new(&x, &y)
+        .with_weights(&w)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    let hs = CubicSmoothingSpline::new(&x, &y)
+        .with_weights(&w)
+        .with_smooth(0.8)
+        .with_derivatives(&xd, &yd)
+        .with_derivative_weights(&wd)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        hs.evaluate(&xi).unwrap(),
+        cs.evaluate(&xi).unwrap(),
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn test_auto_smooth_is_computed_from_values() {
+    let x = array![1., 2., 3., 4., 5.];
+    let y = array![1.5, 3.5, 2.6, 1.2, 4.4];
+    let xd = array![2.5];
+    let yd = array![0.];
+
+    let cs = CubicSmoothingSpline::new(&x, &y).make().unwrap();
+    let hs = CubicSmoothingSpline::new(&x, &y)
+        .with_derivatives(&xd, &yd)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(hs.smooth().unwrap(), cs.smooth().unwrap());
+}
+
+#[test]
+fn test_hermite_interpolation_of_cubic() {
+    let x = array![0., 1., 2., 3., 4.];
+    let y = x.mapv(|v: f64| v.powi(3));
+    let dy = x.mapv(|v: f64| 3. * v * v);
+    let xi = Array1::linspace(0., 4., 41);
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_derivatives(&x, &dy)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        s.evaluate(&xi).unwrap(),
+        xi.mapv(|v| v.powi(3)),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_derivative_observations_at_other_sites() {
+    let x = array![0., 1., 2., 3., 4.];
+    let y = array![0.0, 1.1, 3.9, 9.2, 15.8];
+    let xd = array![0.5, 1.5, 2.5, 3.5];
+    let yd = xd.mapv(|v: f64| 2. * v);
+    let wd = Array1::from_elem(4, 1e6);
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_derivatives(&xd, &yd)
+        .with_derivative_weights(&wd)
+        .with_smooth(0.5)
+        .make()
+        .unwrap();
+
+    assert_eq!(s.spline().unwrap().breaks().len(), 9);
+    assert_abs_diff_eq!(s.evaluate_derivative(&xd, 1).unwrap(), yd, epsilon = 1e-3);
+}
+
+#[test]
+fn test_multivariate_axis() {
+    let x = array![0., 1., 2., 3.];
+    let y = array![[0., 1.], [1., 3.], [4., 5.], [9., 7.]];
+    let xd = array![0.5, 2.5];
+    let yd = array![[1., 2.], [5., 2.]];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_axis(Axis(0))
+        .with_derivatives(&xd, &yd)
+        .with_smooth(0.9)
+        .make()
+        .unwrap();
+
+    for k in 0..2 {
+        let y_col = y.column(k).to_owned();
+        let yd_col = yd.column(k).to_owned();
+
+        let s_col = CubicSmoothingSpline::new(&x, &y_col)
+            .with_derivatives(&xd, &yd_col)
+            .with_smooth(0.9)
+            .make()
+            .unwrap();
+
+        assert_abs_diff_eq!(
+            s.evaluate(&x).unwrap().column(k),
+            s_col.evaluate(&x).unwrap(),
+            epsilon = 1e-10
+        );
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "The shape of derivative data [3] is not equal to the expected shape [2]"
+)]
+fn test_derivative_shape_error() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![1., 2., 3., 4.];
+    let xd = array![1.5, 2.5];
+    let yd = array![1., 1., 1.];
+
+    CubicSmoothingSpline::new(&x, &y)
+        .with_derivatives(&xd, &yd)
+        .make()
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Derivative weights values must be non-negative and finite")]
+fn test_negative_derivative_weights_error() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![1., 2., 3., 4.];
+    let xd = array![1.5, 2.5];
+    let yd = array![1., 1.];
+    let wd = array![1., -1.];
+
+    CubicSmoothingSpline::new(&x, &y)
+        .with_derivatives(&xd, &yd)
+        .with_derivative_weights(&wd)
+        .make()
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Derivative weights cannot be used without derivative data")]
+fn test_derivative_weights_without_derivatives_error() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![1., 2., 3., 4.];
+    let wd = array![1., 1.];
+
+    CubicSmoothingSpline::new(&x, &y)
+        .with_derivative_weights(&wd)
+        .make()
+        .unwrap();
+}
@@ -0,0 +1,144 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Axis};
+
+use csaps::{CubicSmoothingSpline, Transform};
+
+#[test]
+fn test_antiderivative() {
+    let x = array![1., 2., 3.5, 4., 6.];
+    let y = array![[1.5, 3.5, 2.6, 1.2, 4.4], [0.5, 1.2, 2.1, 3.3, 2.8]];
+    let xi = array![0.5, 1.2, 2.5, 3.9, 5.1, 6.5];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+    let spline = s.spline().unwrap();
+
+    for nu in 1..3 {
+        let antiderivative = spline.antiderivative(nu);
+
+        assert_eq!(antiderivative.order(), 4 + nu);
+        assert_eq!(antiderivative.breaks(), spline.breaks());
+        assert_abs_diff_eq!(
+            antiderivative.derivative(nu).evaluate(xi.view()),
+            spline.evaluate(xi.view()),
+            epsilon = 1e-12
+        );
+
+        // Zero at the first break and continuous across the breaks
+        assert_abs_diff_eq!(
+            antiderivative.evaluate(array![1.].view()),
+            array![[0.], [0.]],
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(
+            antiderivative.evaluate(x.view()),
+            antiderivative.evaluate_left(x.view()),
+            epsilon = 1e-12
+        );
+    }
+
+    assert_abs_diff_eq!(spline.antiderivative(0).coeffs(), spline.coeffs());
+}
+
+#[test]
+fn test_integrate_linear() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![3., 5., 7., 9.];
+
+    let s = CubicSmoothingSpline::new(&x, &y).make().unwrap();
+    let spline = s.spline().unwrap();
+
+    // The integral of `2x + 1` is `x^2 + x`
+    let primitive = |v: f64| v * v + v;
+
+    assert_abs_diff_eq!(
+        spline.integrate(1.5, 3.2),
+        array![primitive(3.2) - primitive(1.5)],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        spline.integrate(3.2, 1.5),
+        array![primitive(1.5) - primitive(3.2)],
+        epsilon = 1e-12
+    );
+
+    // The extrapolated ends
+    assert_abs_diff_eq!(
+        spline.integrate(-1., 6.),
+        array![primitive(6.) - primitive(-1.)],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(spline.integrate(2., 2.), array![0.]);
+}
+
+#[test]
+fn test_integrate_quadrature() {
+    let x = array![1., 2., 3.5, 4., 6.];
+    let y = array![1.5, 3.5, 2.6, 1.2, 4.4];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    let (a, b) = (1.3, 5.7);
+    let n = 20000;
+    let xi = Array1::linspace(a, b, n + 1);
+    let yi = s.evaluate(&xi).unwrap();
+    let h = (b - a) / n as f64;
+    let trapezoid = h * (yi.sum() - (yi[0] + yi[n]) / 2.);
+
+    let integral = s.integrate(a, b).unwrap();
+
+    assert_eq!(integral.shape(), &[1]);
+    assert_abs_diff_eq!(integral[0], trapezoid, epsilon = 1e-6);
+}
+
+#[test]
+fn test_integrate_axis() {
+    let x = array![1., 2., 3.5, 4., 6.];
+    let y = array![[1.5, 0.5], [3.5, 1.2], [2.6, 2.1], [1.2, 3.3], [4.4, 2.8]];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_axis(Axis(0))
+        .with_smooth(0.8)
+        .make()
+        .unwrap();
+
+    let integral = s.integrate(0.5, 6.5).unwrap();
+    assert_eq!(integral.shape(), &[1, 2]);
+
+    for k in 0..2 {
+        let column = y.column(k).to_owned();
+        let sk = CubicSmoothingSpline::new(&x, &column)
+            .with_smooth(0.8)
+            .make()
+            .unwrap();
+
+        assert_abs_diff_eq!(
+            integral[[0, k]],
+            sk.integrate(0.5, 6.5).unwrap()[0],
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_integrate_errors() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![3., 5., 7., 9.];
+
+    assert!(CubicSmoothingSpline::new(&x, &y).integrate(1., 2.).is_err());
+
+    let s = CubicSmoothingSpline::new(&x, &y).make().unwrap();
+    assert!(s.integrate(f64::NAN, 2.).is_err());
+    assert!(s.integrate(1., f64::INFINITY).is_err());
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_y_transform(Transform::Log)
+        .make()
+        .unwrap();
+    assert!(s.integrate(1., 2.).is_err());
+}
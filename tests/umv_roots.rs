@@ -0,0 +1,106 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1};
+
+use csaps::{CubicSmoothingSpline, NdSpline};
+
+/// The spline on `[0, 4]` with the break at 2 and the components
+/// `(x - 1) * (x - 2) * (x - 3)` and `(x - 2)^2`
+fn spline() -> NdSpline<'static, f64> {
+    NdSpline::new(
+        array![0., 2., 4.],
+        array![
+            [1., 1., -6., 0., 11., -1., -6., 0.],
+            [0., 0., 1., 1., -4., 0., 4., 0.]
+        ],
+    )
+}
+
+#[test]
+fn test_level_crossings() {
+    let spline = spline();
+
+    assert_abs_diff_eq!(
+        spline.level_crossings(0., 0).unwrap(),
+        array![1., 2., 3.],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        spline.level_crossings(-6., 0).unwrap(),
+        array![0.],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        spline.level_crossings(1., 1).unwrap(),
+        array![1., 3.],
+        epsilon = 1e-12
+    );
+    assert!(spline.level_crossings(100., 0).unwrap().is_empty());
+}
+
+#[test]
+fn test_level_crossings_touch_point() {
+    let spline = spline();
+
+    // The double root at the break
+    let roots = spline.level_crossings(0., 1).unwrap();
+    assert_eq!(roots.len(), 1);
+    assert_abs_diff_eq!(roots[0], 2., epsilon = 1e-6);
+
+    // The local maximum of the first component at `x = 2 - 1 / sqrt(3)` touches the level
+    let peak = 2. - 1. / 3f64.sqrt();
+    let level = spline.evaluate(array![peak].view())[[0, 0]];
+
+    let roots = spline.level_crossings(level, 0).unwrap();
+    assert_eq!(roots.len(), 2);
+    assert_abs_diff_eq!(roots[0], peak, epsilon = 1e-6);
+}
+
+#[test]
+fn test_first_crossing() {
+    let spline = spline();
+
+    assert_abs_diff_eq!(
+        spline.first_crossing(0., 0).unwrap().unwrap(),
+        1.,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        spline.first_crossing(2., 1).unwrap().unwrap(),
+        2. - 2f64.sqrt(),
+        epsilon = 1e-12
+    );
+    assert_eq!(spline.first_crossing(-1., 1).unwrap(), None);
+}
+
+#[test]
+fn test_level_crossings_calibration() {
+    let x = Array1::linspace(0., 10., 21);
+    let y = x.mapv(|v: f64| (1. + v).ln());
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_smooth(1.0)
+        .make()
+        .unwrap();
+    let spline = s.spline().unwrap();
+
+    for level in [0.5, 1.0, 2.0] {
+        let roots = spline.level_crossings(level, 0).unwrap();
+
+        assert_eq!(roots.len(), 1);
+        assert_abs_diff_eq!(
+            spline.evaluate(roots.view()),
+            array![[level]],
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(roots[0], level.exp() - 1., epsilon = 1e-2);
+    }
+}
+
+#[test]
+fn test_level_crossings_errors() {
+    let spline = spline();
+
+    assert!(spline.level_crossings(0., 2).is_err());
+    assert!(spline.level_crossings(f64::NAN, 0).is_err());
+    assert!(spline.first_crossing(f64::INFINITY, 0).is_err());
+}
@@ -0,0 +1,179 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1};
+
+use csaps::{AngleRange, CubicSmoothingSpline, Transform};
+
+#[test]
+fn test_log_log_power_law() {
+    let x = array![1., 10., 100., 1e3, 1e4, 1e5, 1e6];
+    let y = x.mapv(|v: f64| 3. * v * v);
+    let xi = array![2., 50., 7e2, 3e5];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_x_transform(Transform::Log)
+        .with_y_transform(Transform::Log)
+        .with_smooth(0.5)
+        .make()
+        .unwrap();
+
+    let rel = |a: Array1<f64>, b: Array1<f64>| (a / b - 1.).mapv(f64::abs);
+
+    let yi = s.evaluate(&xi).unwrap();
+    let d1 = s.evaluate_derivative(&xi, 1).unwrap();
+    let d2 = s.evaluate_derivative(&xi, 2).unwrap();
+
+    assert_abs_diff_eq!(
+        rel(yi, xi.mapv(|v| 3. * v * v)),
+        Array1::zeros(4),
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        rel(d1, xi.mapv(|v| 6. * v)),
+        Array1::zeros(4),
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        rel(d2, Array1::from_elem(4, 6.)),
+        Array1::zeros(4),
+        epsilon = 1e-10
+    );
+}
+
+#[test]
+fn test_box_cox_y_transform() {
+    // The Box-Cox transform with lambda 0.5 of `(1 + x/2)^2` is `x`
+    let x = array![0., 1., 2., 3., 4., 5.];
+    let y = x.mapv(|v: f64| (1. + 0.5 * v).powi(2));
+    let xi = array![0.5, 2.2, 4.9];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_y_transform(Transform::BoxCox(0.5))
+        .with_smooth(0.3)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        s.evaluate(&xi).unwrap(),
+        xi.mapv(|v| (1. + 0.5 * v).powi(2)),
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        s.evaluate_derivative(&xi, 1).unwrap(),
+        xi.mapv(|v| 1. + 0.5 * v),
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        s.evaluate_derivative(&xi, 2).unwrap(),
+        Array1::from_elem(3, 0.5),
+        epsilon = 1e-10
+    );
+}
+
+#[test]
+fn test_log1p_y_transform() {
+    let x = array![0., 1., 2., 3.];
+    let y = x.mapv(|v: f64| (0.7 * v).exp_m1());
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_y_transform(Transform::Log1p)
+        .make()
+        .unwrap();
+
+    let xi = array![0.3, 2.5];
+
+    assert_abs_diff_eq!(
+        s.evaluate(&xi).unwrap(),
+        xi.mapv(|v| (0.7 * v).exp_m1()),
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        s.evaluate_derivative(&xi, 1).unwrap(),
+        xi.mapv(|v| 0.7 * (0.7 * v).exp()),
+        epsilon = 1e-10
+    );
+}
+
+#[test]
+fn test_custom_transform_chain_rule() {
+    // The custom transform `v^3` of `cbrt(2x + 1)` is linear
+    let x = array![0., 1., 2., 3., 4.];
+    let y = x.mapv(|v: f64| (2. * v + 1.).cbrt());
+    let xi = array![0.5, 1.7, 3.2];
+
+    let s = CubicSmoothingSpline::new(&x, &y)
+        .with_y_transform(Transform::custom(|v: f64| v.powi(3), f64::cbrt))
+        .with_smooth(0.5)
+        .make()
+        .unwrap();
+
+    assert_abs_diff_eq!(
+        s.evaluate(&xi).unwrap(),
+        xi.mapv(|v| (2. * v + 1.).cbrt()),
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        s.evaluate_derivative(&xi, 1).unwrap(),
+        xi.mapv(|v| 2. / 3. * (2. * v + 1.).powf(-2. / 3.)),
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        s.evaluate_derivative(&xi, 2).unwrap(),
+        xi.mapv(|v| -8. / 9. * (2. * v + 1.).powf(-5. / 3.)),
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+#[should_panic(expected = "`x` values are out of the `x` transform domain")]
+fn test_x_transform_domain_error() {
+    let x = array![0., 1., 2., 3.];
+    let y = array![1., 2., 3., 4.];
+
+    CubicSmoothingSpline::new(&x, &y)
+        .with_x_transform(Transform::Log)
+        .make()
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "`xi` values are out of the `x` transform domain")]
+fn test_xi_transform_domain_error() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![1., 2., 3., 4.];
+
+    CubicSmoothingSpline::new(&x, &y)
+        .with_x_transform(Transform::Log)
+        .make()
+        .unwrap()
+        .evaluate(&array![0.])
+        .unwrap();
+}
+
+#[test]
+#[should_panic(
+    expected = "The derivative order must be less or equal to 2 with `x` or `y` transform"
+)]
+fn test_derivative_order_error() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![1., 2., 3., 4.];
+
+    CubicSmoothingSpline::new(&x, &y)
+        .with_y_transform(Transform::Log)
+        .make()
+        .unwrap()
+        .evaluate_derivative(&x, 3)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "`y` transform cannot be used with circular-valued data")]
+fn test_circular_transform_error() {
+    let x = array![1., 2., 3., 4.];
+    let y = array![1., 2., 3., 4.];
+
+    CubicSmoothingSpline::new(&x, &y)
+        .with_circular(AngleRange::Signed)
+        .with_y_transform(Transform::Log)
+        .make()
+        .unwrap();
+}